@@ -124,7 +124,7 @@ pub trait App {
         true
     }
 
-    /// Controls whether or not the egui memory (window positions etc) will be
+    /// Controls whether or not the egui memory (window positions, named layouts etc) will be
     /// persisted (only if the "persistence" feature is enabled).
     fn persist_egui_memory(&self) -> bool {
         true
@@ -190,6 +190,7 @@ impl EpiIntegration {
         let egui_ctx = egui::Context::default();
 
         *egui_ctx.memory() = load_egui_memory(storage.as_deref()).unwrap_or_default();
+        egui_ctx.memory().options.follow_system_theme = system_theme.is_some();
 
         let frame = epi::Frame {
             info: epi::IntegrationInfo {
@@ -287,6 +288,11 @@ impl EpiIntegration {
         full_output
     }
 
+    /// Add an event for the next frame, e.g. [`egui::Event::Screenshot`].
+    pub fn push_event(&mut self, event: egui::Event) {
+        self.egui_winit.egui_input_mut().events.push(event);
+    }
+
     pub fn post_rendering(&mut self, app: &mut dyn epi::App, window: &winit::window::Window) {
         let inner_size = window.inner_size();
         let window_size_px = [inner_size.width, inner_size.height];
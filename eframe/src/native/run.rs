@@ -121,10 +121,13 @@ pub fn run_glow(
             let egui::FullOutput {
                 platform_output,
                 needs_repaint,
+                repaint_after,
                 textures_delta,
                 shapes,
+                frame_timings: _,
             } = integration.update(app.as_mut(), window);
 
+            let screenshot_requested = platform_output.screenshot_requested;
             integration.handle_platform_output(window, platform_output);
 
             let clipped_primitives = {
@@ -139,6 +142,15 @@ pub fn run_glow(
                 &textures_delta,
             );
 
+            // Send the screenshot back to egui next frame:
+            let needs_repaint = if screenshot_requested {
+                let screenshot = painter.read_screen_rgba(screen_size_in_pixels);
+                integration.push_event(egui::Event::Screenshot(std::sync::Arc::new(screenshot)));
+                true
+            } else {
+                needs_repaint
+            };
+
             integration.post_rendering(app.as_mut(), window);
 
             {
@@ -151,6 +163,9 @@ pub fn run_glow(
             } else if needs_repaint {
                 window.request_redraw();
                 winit::event_loop::ControlFlow::Poll
+            } else if let Some(repaint_at) = std::time::Instant::now().checked_add(repaint_after) {
+                // `repaint_after` is `Duration::MAX` (which we can't add) if no repaint was requested.
+                winit::event_loop::ControlFlow::WaitUntil(repaint_at)
             } else {
                 winit::event_loop::ControlFlow::Wait
             };
@@ -208,7 +223,10 @@ pub fn run_glow(
                 app.on_exit(Some(&gl));
                 painter.destroy();
             }
-            winit::event::Event::UserEvent(RequestRepaintEvent) => window.request_redraw(),
+            winit::event::Event::UserEvent(RequestRepaintEvent)
+            | winit::event::Event::NewEvents(winit::event::StartCause::ResumeTimeReached {
+                ..
+            }) => window.request_redraw(),
             _ => (),
         }
     });
@@ -299,10 +317,15 @@ pub fn run_wgpu(
             let egui::FullOutput {
                 platform_output,
                 needs_repaint,
+                repaint_after,
                 textures_delta,
                 shapes,
+                frame_timings: _,
             } = integration.update(app.as_mut(), window);
 
+            if platform_output.screenshot_requested {
+                tracing::warn!("Screenshots are not supported by the wgpu renderer. Use the glow renderer instead.");
+            }
             integration.handle_platform_output(window, platform_output);
 
             let clipped_primitives = {
@@ -322,6 +345,9 @@ pub fn run_wgpu(
             } else if needs_repaint {
                 window.request_redraw();
                 winit::event_loop::ControlFlow::Poll
+            } else if let Some(repaint_at) = std::time::Instant::now().checked_add(repaint_after) {
+                // `repaint_after` is `Duration::MAX` (which we can't add) if no repaint was requested.
+                winit::event_loop::ControlFlow::WaitUntil(repaint_at)
             } else {
                 winit::event_loop::ControlFlow::Wait
             };
@@ -394,7 +420,10 @@ pub fn run_wgpu(
 
                 painter.destroy();
             }
-            winit::event::Event::UserEvent(RequestRepaintEvent) => window.request_redraw(),
+            winit::event::Event::UserEvent(RequestRepaintEvent)
+            | winit::event::Event::NewEvents(winit::event::StartCause::ResumeTimeReached {
+                ..
+            }) => window.request_redraw(),
             _ => (),
         }
     });
@@ -20,13 +20,24 @@ pub struct WebInput {
 }
 
 impl WebInput {
-    pub fn new_frame(&mut self, canvas_size: egui::Vec2) -> egui::RawInput {
-        egui::RawInput {
-            screen_rect: Some(egui::Rect::from_min_size(Default::default(), canvas_size)),
+    /// `canvas_size` is in native points, i.e. CSS pixels, like the positions of our events.
+    /// We convert them to egui points, which differ if egui is zoomed (see [`egui::Context::zoom_factor`]).
+    pub fn new_frame(&mut self, canvas_size: egui::Vec2, zoom_factor: f32) -> egui::RawInput {
+        let mut raw_input = egui::RawInput {
+            screen_rect: Some(egui::Rect::from_min_size(
+                Default::default(),
+                canvas_size / zoom_factor,
+            )),
             pixels_per_point: Some(native_pixels_per_point()), // We ALWAYS use the native pixels-per-point
             time: Some(now_sec()),
             ..self.raw.take()
+        };
+        if zoom_factor != 1.0 {
+            for event in &mut raw_input.events {
+                unzoom_event(event, zoom_factor);
+            }
         }
+        raw_input
     }
 }
 
@@ -132,6 +143,8 @@ pub struct AppRunner {
     pub(crate) input: WebInput,
     app: Box<dyn epi::App>,
     pub(crate) needs_repaint: std::sync::Arc<NeedRepaint>,
+    /// Repaint at this time (in [`now_sec`]) even if there are no new events.
+    pub(crate) repaint_time: f64,
     last_save_time: f64,
     screen_reader: super::screen_reader::ScreenReader,
     pub(crate) text_cursor_pos: Option<egui::Pos2>,
@@ -166,6 +179,9 @@ impl AppRunner {
 
         let egui_ctx = egui::Context::default();
         load_memory(&egui_ctx);
+        egui_ctx.memory().options.follow_system_theme = system_theme.is_some();
+        // The browser already zooms with the same shortcuts, by changing `native_pixels_per_point`:
+        egui_ctx.memory().options.zoom_with_keyboard = false;
 
         let theme = system_theme.unwrap_or(web_options.default_theme);
         egui_ctx.set_visuals(theme.egui_visuals());
@@ -205,6 +221,7 @@ impl AppRunner {
             input: Default::default(),
             app,
             needs_repaint,
+            repaint_time: f64::INFINITY,
             last_save_time: now_sec(),
             screen_reader: Default::default(),
             text_cursor_pos: None,
@@ -259,7 +276,9 @@ impl AppRunner {
 
         resize_canvas_to_screen_size(self.canvas_id(), self.app.max_size_points());
         let canvas_size = canvas_size_in_points(self.canvas_id());
-        let raw_input = self.input.new_frame(canvas_size);
+        let raw_input = self
+            .input
+            .new_frame(canvas_size, self.egui_ctx.zoom_factor());
 
         let full_output = self.egui_ctx.run(raw_input, |egui_ctx| {
             self.app.update(egui_ctx, &mut self.frame);
@@ -267,10 +286,14 @@ impl AppRunner {
         let egui::FullOutput {
             platform_output,
             needs_repaint,
+            repaint_after,
             textures_delta,
             shapes,
+            frame_timings: _,
         } = full_output;
 
+        self.repaint_time = frame_start + repaint_after.as_secs_f64();
+
         self.handle_platform_output(platform_output);
         self.textures_delta.append(textures_delta);
         let clipped_primitives = self.egui_ctx.tessellate(shapes);
@@ -320,10 +343,17 @@ impl AppRunner {
             open_url,
             copied_text,
             events: _, // already handled
+            accessibility_nodes: _,
             mutable_text_under_cursor,
             text_cursor_pos,
+            input_latency: _,
+            screenshot_requested,
         } = platform_output;
 
+        if screenshot_requested {
+            tracing::warn!("Screenshots are not supported on the web");
+        }
+
         set_cursor_icon(cursor_icon);
         if let Some(open) = open_url {
             super::open_url(&open.url, open.new_tab);
@@ -340,7 +370,11 @@ impl AppRunner {
         self.mutable_text_under_cursor = mutable_text_under_cursor;
 
         if self.text_cursor_pos != text_cursor_pos {
-            text_agent::move_text_cursor(text_cursor_pos, self.canvas_id());
+            let zoom_factor = self.egui_ctx.zoom_factor();
+            text_agent::move_text_cursor(
+                text_cursor_pos.map(|pos| (pos.to_vec2() * zoom_factor).to_pos2()),
+                self.canvas_id(),
+            );
             self.text_cursor_pos = text_cursor_pos;
         }
     }
@@ -381,6 +415,14 @@ impl AppRunnerContainer {
                     let event = event.unchecked_into::<E>();
 
                     closure(event, runner_ref.lock());
+
+                    // Remember when the oldest event of the coming frame arrived,
+                    // so egui can report the input latency:
+                    let mut runner_lock = runner_ref.lock();
+                    let raw = &mut runner_lock.input.raw;
+                    if raw.oldest_event_time.is_none() && !raw.events.is_empty() {
+                        raw.oldest_event_time = Some(now_sec());
+                    }
                 }
             }) as Box<dyn FnMut(_)>
         });
@@ -420,6 +462,7 @@ fn start_runner(app_runner: AppRunner) -> Result<AppRunnerRef, JsValue> {
     super::events::install_canvas_events(&runner_container)?;
     super::events::install_document_events(&runner_container)?;
     text_agent::install_text_agent(&runner_container)?;
+    super::events::install_color_scheme_change_event(&runner_container)?;
     super::events::repaint_every_ms(&runner_container, 1000)?; // just in case. TODO(emilk): make it a parameter
 
     super::events::paint_and_schedule(&runner_container.runner, runner_container.panicked.clone())?;
@@ -7,7 +7,8 @@ pub fn paint_and_schedule(
 ) -> Result<(), JsValue> {
     fn paint_if_needed(runner_ref: &AppRunnerRef) -> Result<(), JsValue> {
         let mut runner_lock = runner_ref.lock();
-        if runner_lock.needs_repaint.fetch_and_clear() {
+        let is_time_to_repaint = now_sec() >= runner_lock.repaint_time;
+        if runner_lock.needs_repaint.fetch_and_clear() || is_time_to_repaint {
             runner_lock.clear_color_buffer();
             let (needs_repaint, clipped_primitives) = runner_lock.logic()?;
             runner_lock.paint(&clipped_primitives)?;
@@ -221,6 +222,26 @@ pub fn repaint_every_ms(
     Ok(())
 }
 
+/// Tell egui when the user switches between dark and light mode.
+pub fn install_color_scheme_change_event(
+    runner_container: &AppRunnerContainer,
+) -> Result<(), JsValue> {
+    let window = web_sys::window().unwrap();
+
+    if let Some(media_query_list) = window.match_media("(prefers-color-scheme: dark)")? {
+        runner_container.add_event_listener(
+            &media_query_list,
+            "change",
+            |event: web_sys::MediaQueryListEvent, mut runner_lock| {
+                runner_lock.input.raw.system_dark_mode = Some(event.matches());
+                runner_lock.needs_repaint.set_true();
+            },
+        )?;
+    }
+
+    Ok(())
+}
+
 pub fn install_canvas_events(runner_container: &AppRunnerContainer) -> Result<(), JsValue> {
     use wasm_bindgen::JsCast;
     let canvas = canvas_element(runner_container.runner.lock().canvas_id()).unwrap();
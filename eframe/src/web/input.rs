@@ -74,6 +74,21 @@ pub fn push_touches(runner: &mut AppRunner, phase: egui::TouchPhase, event: &web
     }
 }
 
+/// Convert the positions in an event from native points (CSS pixels) to egui points.
+pub fn unzoom_event(event: &mut egui::Event, zoom_factor: f32) {
+    match event {
+        egui::Event::PointerMoved(pos)
+        | egui::Event::PointerButton { pos, .. }
+        | egui::Event::Touch { pos, .. } => {
+            *pos = (pos.to_vec2() / zoom_factor).to_pos2();
+        }
+        egui::Event::Scroll(delta) => {
+            *delta = *delta / zoom_factor;
+        }
+        _ => {}
+    }
+}
+
 /// Web sends all keys as strings, so it is up to us to figure out if it is
 /// a real text input or the name of a key.
 pub fn should_ignore_key(key: &str) -> bool {
@@ -132,6 +147,9 @@ pub fn translate_key(key: &str) -> Option<egui::Key> {
         "PageUp" => Some(egui::Key::PageUp),
         "PageDown" => Some(egui::Key::PageDown),
 
+        "-" => Some(egui::Key::Minus),
+        "+" | "=" => Some(egui::Key::PlusEquals),
+
         "0" => Some(egui::Key::Num0),
         "1" => Some(egui::Key::Num1),
         "2" => Some(egui::Key::Num2),
@@ -788,31 +788,13 @@ fn calculate_pixel_rect(
     pixels_per_point: f32,
     target_size: [u32; 2],
 ) -> PixelRect {
-    // Transform clip rect to physical pixels.
-    let clip_min_x = pixels_per_point * clip_rect.min.x;
-    let clip_min_y = pixels_per_point * clip_rect.min.y;
-    let clip_max_x = pixels_per_point * clip_rect.max.x;
-    let clip_max_y = pixels_per_point * clip_rect.max.y;
-
-    // Make sure clip rect can fit within an `u32`.
-    let clip_min_x = clip_min_x.clamp(0.0, target_size[0] as f32);
-    let clip_min_y = clip_min_y.clamp(0.0, target_size[1] as f32);
-    let clip_max_x = clip_max_x.clamp(clip_min_x, target_size[0] as f32);
-    let clip_max_y = clip_max_y.clamp(clip_min_y, target_size[1] as f32);
-
-    let clip_min_x = clip_min_x.round() as u32;
-    let clip_min_y = clip_min_y.round() as u32;
-    let clip_max_x = clip_max_x.round() as u32;
-    let clip_max_y = clip_max_y.round() as u32;
-
-    let width = (clip_max_x - clip_min_x).max(1);
-    let height = (clip_max_y - clip_min_y).max(1);
+    let pixel_rect = egui::PixelRect::from_points(*clip_rect, pixels_per_point, target_size);
 
     // Clip scissor rectangle to target size.
-    let x = clip_min_x.min(target_size[0]);
-    let y = clip_min_y.min(target_size[1]);
-    let width = width.min(target_size[0] - x);
-    let height = height.min(target_size[1] - y);
+    let x = pixel_rect.min_x;
+    let y = pixel_rect.min_y;
+    let width = pixel_rect.width().max(1).min(target_size[0] - x);
+    let height = pixel_rect.height().max(1).min(target_size[1] - y);
 
     PixelRect {
         x,
@@ -51,6 +51,9 @@ pub struct State {
 
     clipboard: clipboard::Clipboard,
     screen_reader: screen_reader::ScreenReader,
+    /// The innermost labelled container (e.g. a window) of the focused widget,
+    /// so we can tell the screen reader user when the focus moves to another one.
+    focused_container: Option<egui::Id>,
 
     /// If `true`, mouse inputs will be treated as touches.
     /// Useful for debugging touch support in egui.
@@ -80,6 +83,7 @@ impl State {
 
             clipboard: clipboard::Clipboard::new(wayland_display),
             screen_reader: screen_reader::ScreenReader::default(),
+            focused_container: None,
 
             simulate_touch_screen: false,
             pointer_touch_id: None,
@@ -119,6 +123,12 @@ impl State {
         &self.egui_input
     }
 
+    /// The current input state, e.g. for adding events of your own.
+    #[inline]
+    pub fn egui_input_mut(&mut self) -> &mut egui::RawInput {
+        &mut self.egui_input
+    }
+
     /// Prepare for a new frame by extracting the accumulated input,
     /// as well as setting [the time](egui::RawInput::time) and [screen rectangle](egui::RawInput::screen_rect).
     pub fn take_egui_input(&mut self, window: &winit::window::Window) -> egui::RawInput {
@@ -160,11 +170,11 @@ impl State {
         event: &winit::event::WindowEvent<'_>,
     ) -> bool {
         use winit::event::WindowEvent;
-        match event {
+        let wants_event = match event {
             WindowEvent::ScaleFactorChanged { scale_factor, .. } => {
-                let pixels_per_point = *scale_factor as f32;
-                self.egui_input.pixels_per_point = Some(pixels_per_point);
-                self.current_pixels_per_point = pixels_per_point;
+                let native_pixels_per_point = *scale_factor as f32;
+                self.egui_input.pixels_per_point = Some(native_pixels_per_point);
+                self.current_pixels_per_point = native_pixels_per_point * egui_ctx.zoom_factor();
                 false
             }
             WindowEvent::MouseInput { state, button, .. } => {
@@ -179,6 +189,10 @@ impl State {
                 self.on_cursor_moved(*position);
                 egui_ctx.is_using_pointer()
             }
+            WindowEvent::ThemeChanged(theme) => {
+                self.egui_input.system_dark_mode = Some(*theme == winit::window::Theme::Dark);
+                false
+            }
             WindowEvent::CursorLeft { .. } => {
                 self.pointer_pos_in_points = None;
                 self.egui_input.events.push(egui::Event::PointerGone);
@@ -255,7 +269,13 @@ impl State {
                 // dbg!(event);
                 false
             }
+        };
+
+        if self.egui_input.oldest_event_time.is_none() && !self.egui_input.events.is_empty() {
+            self.egui_input.oldest_event_time = Some(self.start_time.elapsed().as_secs_f64());
         }
+
+        wants_event
     }
 
     fn on_mouse_button_input(
@@ -303,11 +323,24 @@ impl State {
         }
     }
 
-    fn on_cursor_moved(&mut self, pos_in_pixels: winit::dpi::PhysicalPosition<f64>) {
-        let pos_in_points = egui::pos2(
+    /// Convert a position in physical pixels, as reported by winit, to egui points.
+    fn pos_in_points(&self, pos_in_pixels: winit::dpi::PhysicalPosition<f64>) -> egui::Pos2 {
+        egui::pos2(
             pos_in_pixels.x as f32 / self.pixels_per_point(),
             pos_in_pixels.y as f32 / self.pixels_per_point(),
-        );
+        )
+    }
+
+    /// Convert a position in egui points to physical pixels, for winit.
+    fn pos_in_pixels(&self, pos_in_points: egui::Pos2) -> winit::dpi::PhysicalPosition<f32> {
+        winit::dpi::PhysicalPosition::new(
+            pos_in_points.x * self.pixels_per_point(),
+            pos_in_points.y * self.pixels_per_point(),
+        )
+    }
+
+    fn on_cursor_moved(&mut self, pos_in_pixels: winit::dpi::PhysicalPosition<f64>) {
+        let pos_in_points = self.pos_in_points(pos_in_pixels);
         self.pointer_pos_in_points = Some(pos_in_points);
 
         if self.simulate_touch_screen {
@@ -342,10 +375,7 @@ impl State {
                 winit::event::TouchPhase::Ended => egui::TouchPhase::End,
                 winit::event::TouchPhase::Cancelled => egui::TouchPhase::Cancel,
             },
-            pos: egui::pos2(
-                touch.location.x as f32 / self.pixels_per_point(),
-                touch.location.y as f32 / self.pixels_per_point(),
-            ),
+            pos: self.pos_in_points(touch.location),
             force: match touch.force {
                 Some(winit::event::Force::Normalized(force)) => force as f32,
                 Some(winit::event::Force::Calibrated {
@@ -400,7 +430,7 @@ impl State {
                 egui::vec2(x, y) * points_per_scroll_line
             }
             winit::event::MouseScrollDelta::PixelDelta(delta) => {
-                egui::vec2(delta.x as f32, delta.y as f32) / self.pixels_per_point()
+                self.pos_in_points(delta).to_vec2()
             }
         };
 
@@ -459,7 +489,6 @@ impl State {
     /// * copy text to the clipboard
     /// * open any clicked urls
     /// * update the IME
-    /// *
     pub fn handle_platform_output(
         &mut self,
         window: &winit::window::Window,
@@ -467,17 +496,39 @@ impl State {
         platform_output: egui::PlatformOutput,
     ) {
         if egui_ctx.options().screen_reader {
-            self.screen_reader
-                .speak(&platform_output.events_description());
+            let mut description = platform_output.events_description();
+
+            // Only filled in if `egui::Options::accessibility_tree` is set:
+            let focused_container = platform_output
+                .accessibility_nodes
+                .iter()
+                .find(|node| node.focused)
+                .and_then(|focused| {
+                    platform_output
+                        .accessibility_ancestors(focused.id)
+                        .find(|container| container.info.label.is_some())
+                });
+            if let Some(container) = focused_container {
+                if self.focused_container != Some(container.id) && !description.is_empty() {
+                    let label = container.info.label.as_deref().unwrap_or_default();
+                    description = format!("{}: {}", label, description);
+                }
+            }
+            self.focused_container = focused_container.map(|container| container.id);
+
+            self.screen_reader.speak(&description);
         }
 
         let egui::PlatformOutput {
             cursor_icon,
             open_url,
             copied_text,
-            events: _,                    // handled above
+            events: _, // handled above
+            accessibility_nodes: _,
             mutable_text_under_cursor: _, // only used in eframe web
             text_cursor_pos,
+            input_latency: _,
+            screenshot_requested: _, // handled by the integration
         } = platform_output;
 
         self.current_pixels_per_point = egui_ctx.pixels_per_point(); // someone can have changed it to scale the UI
@@ -492,8 +543,9 @@ impl State {
             self.clipboard.set(copied_text);
         }
 
-        if let Some(egui::Pos2 { x, y }) = text_cursor_pos {
-            window.set_ime_position(winit::dpi::LogicalPosition { x, y });
+        if let Some(text_cursor_pos) = text_cursor_pos {
+            // egui points only match winit's logical pixels if the zoom factor is 1:
+            window.set_ime_position(self.pos_in_pixels(text_cursor_pos));
         }
     }
 
@@ -596,6 +648,11 @@ fn translate_virtual_key_code(key: winit::event::VirtualKeyCode) -> Option<egui:
         VirtualKeyCode::PageUp => Key::PageUp,
         VirtualKeyCode::PageDown => Key::PageDown,
 
+        VirtualKeyCode::Minus | VirtualKeyCode::NumpadSubtract => Key::Minus,
+        VirtualKeyCode::Equals | VirtualKeyCode::Plus | VirtualKeyCode::NumpadAdd => {
+            Key::PlusEquals
+        }
+
         VirtualKeyCode::Key0 | VirtualKeyCode::Numpad0 => Key::Num0,
         VirtualKeyCode::Key1 | VirtualKeyCode::Numpad1 => Key::Num1,
         VirtualKeyCode::Key2 | VirtualKeyCode::Numpad2 => Key::Num2,
@@ -109,3 +109,67 @@ impl AnimationManager {
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn animate_value() {
+        let mut manager = AnimationManager::default();
+        let id = Id::new("value");
+        let mut input = InputState::default();
+        input.predicted_dt = 0.0;
+
+        assert_eq!(manager.animate_value(&input, 1.0, id, 10.0), 10.0);
+
+        // Start animating towards 20:
+        assert_eq!(manager.animate_value(&input, 1.0, id, 20.0), 10.0);
+        input.time = 0.5;
+        assert_eq!(manager.animate_value(&input, 1.0, id, 20.0), 15.0);
+        input.time = 2.0;
+        assert_eq!(manager.animate_value(&input, 1.0, id, 20.0), 20.0);
+    }
+
+    #[test]
+    fn animate_bool() {
+        let mut manager = AnimationManager::default();
+        let id = Id::new("bool");
+        let mut input = InputState::default();
+        input.predicted_dt = 0.0;
+
+        assert_eq!(manager.animate_bool(&input, 1.0, id, false), 0.0);
+        assert_eq!(manager.animate_bool(&input, 1.0, id, true), 0.0);
+        input.time = 0.25;
+        assert_eq!(manager.animate_bool(&input, 1.0, id, true), 0.25);
+        input.time = 1.5;
+        assert_eq!(manager.animate_bool(&input, 1.0, id, true), 1.0);
+    }
+
+    #[test]
+    fn animations_only_use_input_time() {
+        use crate::Harness;
+
+        // No time passes between frames unless we say so:
+        let mut harness = Harness::new().with_dt(0.0);
+        let id = Id::new("animation");
+        let animation_time = harness.ctx().style().animation_time;
+
+        assert_eq!(harness.run(|ctx| ctx.animate_bool(id, false)), 0.0);
+        for _ in 0..10 {
+            let value = harness.run(|ctx| ctx.animate_bool(id, true));
+            assert!(value < 0.01, "{}", value);
+        }
+
+        harness.advance_time(animation_time as f64 / 2.0);
+        let value = harness.run(|ctx| ctx.animate_bool(id, true));
+        assert!((value - 0.5).abs() < 0.01, "{}", value);
+        assert!(
+            harness.output().needs_repaint,
+            "The animation is still running"
+        );
+
+        harness.advance_time(animation_time as f64);
+        assert_eq!(harness.run(|ctx| ctx.animate_bool(id, true)), 1.0);
+    }
+}
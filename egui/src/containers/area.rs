@@ -50,6 +50,7 @@ pub struct Area {
     enabled: bool,
     order: Order,
     default_pos: Option<Pos2>,
+    pivot: Align2,
     anchor: Option<(Align2, Vec2)>,
     new_pos: Option<Pos2>,
     drag_bounds: Option<Rect>,
@@ -64,6 +65,7 @@ impl Area {
             enabled: true,
             order: Order::Middle,
             default_pos: None,
+            pivot: Align2::LEFT_TOP,
             new_pos: None,
             anchor: None,
             drag_bounds: None,
@@ -135,6 +137,28 @@ impl Area {
         self
     }
 
+    /// Which point of the area is placed at the position given by
+    /// [`Self::default_pos`], [`Self::fixed_pos`] or [`Self::current_pos`].
+    ///
+    /// For instance, `pivot(Align2::CENTER_CENTER)` centers the area on the position,
+    /// and `pivot(Align2::RIGHT_BOTTOM)` puts its right-bottom corner there.
+    ///
+    /// Default: [`Align2::LEFT_TOP`]. Ignored if [`Self::anchor`] is set.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// let screen_center = ctx.input().screen_rect().center();
+    /// egui::Area::new("centered")
+    ///     .pivot(egui::Align2::CENTER_CENTER)
+    ///     .fixed_pos(screen_center)
+    ///     .show(ctx, |ui| ui.label("In the middle of the screen"));
+    /// # });
+    /// ```
+    pub fn pivot(mut self, pivot: Align2) -> Self {
+        self.pivot = pivot;
+        self
+    }
+
     /// Set anchor and distance.
     ///
     /// An anchor of `Align2::RIGHT_TOP` means "put the right-top corner of the window
@@ -143,9 +167,19 @@ impl Area {
     /// The offset is added to the position, so e.g. an offset of `[-5.0, 5.0]`
     /// would move the window left and down from the given anchor.
     ///
+    /// The area stays anchored when the screen is resized, or when the size of the area changes.
+    ///
     /// Anchoring also makes the window immovable.
     ///
     /// It is an error to set both an anchor and a position.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// egui::Area::new("status")
+    ///     .anchor(egui::Align2::RIGHT_BOTTOM, [-8.0, -8.0])
+    ///     .show(ctx, |ui| ui.label("Always in the bottom right corner"));
+    /// # });
+    /// ```
     pub fn anchor(mut self, align: Align2, offset: impl Into<Vec2>) -> Self {
         self.anchor = Some((align, offset.into()));
         self.movable(false)
@@ -161,7 +195,7 @@ impl Area {
         if let Some((pivot, _)) = self.anchor {
             pivot
         } else {
-            Align2::LEFT_TOP
+            self.pivot
         }
     }
 }
@@ -172,6 +206,9 @@ pub(crate) struct Prepared {
     pub(crate) movable: bool,
     enabled: bool,
     drag_bounds: Option<Rect>,
+
+    /// Put this point of the area at this position.
+    pivot_pos: Option<(Align2, Pos2)>,
 }
 
 impl Area {
@@ -180,10 +217,13 @@ impl Area {
         ctx: &Context,
         add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> InnerResponse<R> {
+        let id = self.id;
         let prepared = self.begin(ctx);
+        ctx.begin_accessibility_container(id, || WidgetInfo::new(WidgetType::Other));
         let mut content_ui = prepared.content_ui(ctx);
         let inner = add_contents(&mut content_ui);
         let response = prepared.end(ctx, content_ui);
+        ctx.end_accessibility_container(id, response.rect);
         InnerResponse { inner, response }
     }
 
@@ -195,6 +235,7 @@ impl Area {
             interactable,
             enabled,
             default_pos,
+            pivot,
             new_pos,
             anchor,
             drag_bounds,
@@ -208,31 +249,42 @@ impl Area {
             ctx.request_repaint(); // if we don't know the previous size we are likely drawing the area in the wrong place
         }
         let mut state = state.unwrap_or_else(|| State {
-            pos: default_pos.unwrap_or_else(|| automatic_area_position(ctx)),
+            pos: automatic_area_position(ctx),
             size: Vec2::ZERO,
             interactable,
         });
-        state.pos = new_pos.unwrap_or(state.pos);
         state.interactable = interactable;
 
-        if let Some((anchor, offset)) = anchor {
-            if is_new {
-                // unknown size
-                ctx.request_repaint();
-            } else {
-                let screen = ctx.available_rect();
-                state.pos = anchor.align_size_within_rect(state.size, screen).min + offset;
-            }
+        let pivot_pos = if let Some((anchor, offset)) = anchor {
+            let screen = ctx.available_rect();
+            Some((anchor, anchor.pos_in_rect(&screen) + offset))
+        } else if let Some(new_pos) = new_pos {
+            Some((pivot, new_pos))
+        } else if is_new {
+            default_pos.map(|default_pos| (pivot, default_pos))
+        } else {
+            None
+        };
+
+        if let Some((pivot, pos)) = pivot_pos {
+            // On the first frame we don't know the size yet, so this is corrected in `end`.
+            state.pos = pivot_to_left_top(pivot, pos, state.size);
         }
 
         state.pos = ctx.round_pos_to_pixels(state.pos);
 
+        if order != Order::Tooltip {
+            // Tooltips shown on the same widget (e.g. chained `on_hover_text`) share one area on purpose.
+            ctx.check_for_area_id_clash(id, state.rect());
+        }
+
         Prepared {
             layer_id,
             state,
             movable,
             enabled,
             drag_bounds,
+            pivot_pos,
         }
     }
 
@@ -326,10 +378,20 @@ impl Prepared {
             movable,
             enabled,
             drag_bounds,
+            pivot_pos,
         } = self;
 
         state.size = content_ui.min_rect().size();
 
+        if let Some((pivot, pos)) = pivot_pos {
+            // Now that we know the size, make sure the pivot ends up in the right place:
+            let left_top = ctx.round_pos_to_pixels(pivot_to_left_top(pivot, pos, state.size));
+            if left_top.distance(state.pos) > 0.5 {
+                state.pos = left_top;
+                ctx.request_repaint();
+            }
+        }
+
         let interact_id = layer_id.id.with("move");
         let sense = if movable {
             Sense::click_and_drag()
@@ -348,7 +410,7 @@ impl Prepared {
         );
 
         if move_response.dragged() && movable {
-            state.pos += ctx.input().pointer.delta();
+            state.pos += move_response.drag_delta();
         }
 
         // Important check - don't try to move e.g. a combobox popup!
@@ -371,6 +433,11 @@ impl Prepared {
     }
 }
 
+/// Where the left-top corner of an area of the given size must be for its `pivot` to be at `pos`.
+fn pivot_to_left_top(pivot: Align2, pos: Pos2, size: Vec2) -> Pos2 {
+    pivot.anchor_rect(Rect::from_min_size(pos, size)).min
+}
+
 fn pointer_pressed_on_area(ctx: &Context, layer_id: LayerId) -> bool {
     if let Some(pointer_pos) = ctx.pointer_interact_pos() {
         let any_pressed = ctx.input().pointer.any_pressed();
@@ -449,3 +516,36 @@ fn automatic_area_position(ctx: &Context) -> Pos2 {
     }
     best_pos
 }
+
+#[cfg(test)]
+#[test]
+fn area_pivot_and_anchor() {
+    let mut harness = Harness::new().with_screen_size(vec2(400.0, 300.0));
+    let run = |harness: &mut Harness| {
+        harness.run(|ctx| {
+            let centered = Area::new("centered")
+                .pivot(Align2::CENTER_CENTER)
+                .fixed_pos(pos2(200.0, 150.0))
+                .show(ctx, |ui| ui.label("Centered"))
+                .response
+                .rect;
+            let anchored = Area::new("anchored")
+                .anchor(Align2::RIGHT_BOTTOM, vec2(-10.0, -10.0))
+                .show(ctx, |ui| ui.label("Anchored"))
+                .response
+                .rect;
+            (centered, anchored)
+        })
+    };
+
+    // The size is known after the first frame:
+    run(&mut harness);
+    let (centered, anchored) = run(&mut harness);
+    assert!(centered.center().distance(pos2(200.0, 150.0)) < 1.0);
+    assert!(anchored.right_bottom().distance(pos2(390.0, 290.0)) < 1.0);
+
+    // Anchored areas follow the screen:
+    let mut harness = harness.with_screen_size(vec2(600.0, 500.0));
+    let (_, anchored) = run(&mut harness);
+    assert!(anchored.right_bottom().distance(pos2(590.0, 490.0)) < 1.0);
+}
@@ -377,6 +377,8 @@ impl CollapsingHeader {
     /// Calling `.open(Some(false))` will make the collapsing header close this frame (or stay closed).
     ///
     /// Calling `.open(None)` has no effect (default).
+    ///
+    /// See also [`Ui::set_collapsing_open`] to open or close all headers in a [`Ui`] at once.
     pub fn open(mut self, open: Option<bool>) -> Self {
         self.open = open;
         self
@@ -513,7 +515,7 @@ impl CollapsingHeader {
         );
 
         let mut state = CollapsingState::load_with_default_open(ui.ctx(), id, default_open);
-        if let Some(open) = open {
+        if let Some(open) = open.or_else(|| ui.collapsing_open()) {
             if open != state.is_open() {
                 state.toggle(ui);
                 header_response.mark_changed();
@@ -649,3 +651,53 @@ impl<R> CollapsingResponse<R> {
         self.openness >= 1.0
     }
 }
+
+#[cfg(test)]
+#[test]
+fn collapsing_body_opens_over_animation_time() {
+    let mut harness = Harness::new().with_dt(0.25);
+    let mut style = (*harness.ctx().style()).clone();
+    style.animation_time = 1.0;
+    harness.ctx().set_style(style);
+    let show = |ui: &mut Ui| CollapsingHeader::new("Header").show(ui, |ui| ui.label("Body"));
+
+    let response = harness.run_ui(show);
+    assert!(response.fully_closed());
+
+    harness.click(response.header_response.rect.center());
+    let openness: Vec<f32> = (0..6).map(|_| harness.run_ui(show).openness).collect();
+    assert!(openness.windows(2).all(|w| w[0] <= w[1]), "{:?}", openness);
+    assert!(0.0 < openness[0] && openness[2] < 1.0, "{:?}", openness);
+    assert_eq!(openness[5], 1.0);
+}
+
+#[cfg(test)]
+#[test]
+fn expand_and_collapse_all() {
+    let mut harness = Harness::new();
+    let mut run = |open_all: Option<bool>| {
+        harness.run_ui(|ui| {
+            ui.set_collapsing_open(open_all);
+            let outer = ui.collapsing("Outer", |ui| {
+                ui.collapsing("Inner", |ui| ui.label("Hello"))
+                    .header_response
+                    .id
+            });
+            let inner_id = outer.body_returned;
+            let is_open = |id| {
+                collapsing_header::CollapsingState::load(ui.ctx(), id)
+                    .map_or(false, |state| state.is_open())
+            };
+            (is_open(outer.header_response.id), inner_id.map(is_open))
+        })
+    };
+
+    assert_eq!(run(None), (false, None));
+    assert_eq!(
+        run(Some(true)),
+        (true, Some(true)),
+        "Nested headers open at once"
+    );
+    assert_eq!(run(None), (true, Some(true)), "They stay open");
+    assert_eq!(run(Some(false)), (false, Some(false)));
+}
@@ -7,6 +7,7 @@ pub mod collapsing_header;
 mod combo_box;
 pub(crate) mod frame;
 pub mod panel;
+pub(crate) mod pie_menu;
 pub mod popup;
 pub(crate) mod resize;
 pub(crate) mod scroll_area;
@@ -18,6 +19,7 @@ pub use {
     combo_box::*,
     frame::Frame,
     panel::{CentralPanel, SidePanel, TopBottomPanel},
+    pie_menu::PieMenu,
     popup::*,
     resize::Resize,
     scroll_area::ScrollArea,
@@ -240,6 +240,8 @@ impl SidePanel {
         let mut panel_ui = ui.child_ui_with_id_source(panel_rect, Layout::top_down(Align::Min), id);
         panel_ui.expand_to_include_rect(panel_rect);
         let frame = frame.unwrap_or_else(|| Frame::side_top_panel(ui.style()));
+        ui.ctx()
+            .begin_accessibility_container(id, || WidgetInfo::new(WidgetType::Other));
         let inner_response = frame.show(&mut panel_ui, |ui| {
             ui.set_min_height(ui.max_rect().height()); // Make sure the frame fills the full height
             ui.set_min_width(*width_range.start());
@@ -247,6 +249,7 @@ impl SidePanel {
         });
 
         let rect = inner_response.response.rect;
+        ui.ctx().end_accessibility_container(id, rect);
 
         {
             let mut cursor = ui.cursor();
@@ -315,6 +318,78 @@ impl SidePanel {
         }
         inner_response
     }
+
+    /// Show the panel if `is_expanded` is `true`,
+    /// otherwise don't show it, but with a nice animation between collapsed and expanded.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// # let mut show_tools = true;
+    /// egui::SidePanel::left("tools").show_animated(ctx, show_tools, |ui| {
+    ///     ui.label("Tools");
+    /// });
+    /// # });
+    /// ```
+    pub fn show_animated<R>(
+        self,
+        ctx: &Context,
+        is_expanded: bool,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<InnerResponse<R>> {
+        let how_expanded = ctx.animate_bool(self.id.with("animation"), is_expanded);
+
+        if how_expanded <= 0.0 {
+            None
+        } else if how_expanded < 1.0 {
+            // Show an empty panel of the in-between width while animating:
+            self.animating_panel(ctx, how_expanded).show(ctx, |_ui| {});
+            None
+        } else {
+            Some(self.show(ctx, add_contents))
+        }
+    }
+
+    /// Like [`Self::show_animated`], but the panel is shown inside a [`Ui`].
+    pub fn show_animated_inside<R>(
+        self,
+        ui: &mut Ui,
+        is_expanded: bool,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<InnerResponse<R>> {
+        let how_expanded = ui
+            .ctx()
+            .animate_bool(self.id.with("animation"), is_expanded);
+
+        if how_expanded <= 0.0 {
+            None
+        } else if how_expanded < 1.0 {
+            self.animating_panel(ui.ctx(), how_expanded)
+                .show_inside(ui, |_ui| {});
+            None
+        } else {
+            Some(self.show_inside(ui, add_contents))
+        }
+    }
+
+    /// An empty, non-resizable stand-in for this panel, `how_expanded` as wide.
+    fn animating_panel(self, ctx: &Context, how_expanded: f32) -> Self {
+        let expanded_width =
+            PanelState::load(ctx, self.id).map_or(self.default_width, |state| state.rect.width());
+        let width = how_expanded * expanded_width;
+        // No margins, so the panel is exactly as wide as we say:
+        let mut frame = self
+            .frame
+            .unwrap_or_else(|| Frame::side_top_panel(&ctx.style()));
+        frame.inner_margin = style::Margin::same(0.0);
+        Self {
+            id: self.id.with("animating_panel"),
+            frame: Some(frame),
+            resizable: false,
+            default_width: width,
+            width_range: width..=width,
+            ..self
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -525,6 +600,8 @@ impl TopBottomPanel {
         let mut panel_ui = ui.child_ui_with_id_source(panel_rect, Layout::top_down(Align::Min), id);
         panel_ui.expand_to_include_rect(panel_rect);
         let frame = frame.unwrap_or_else(|| Frame::side_top_panel(ui.style()));
+        ui.ctx()
+            .begin_accessibility_container(id, || WidgetInfo::new(WidgetType::Other));
         let inner_response = frame.show(&mut panel_ui, |ui| {
             ui.set_min_width(ui.max_rect().width()); // Make the frame fill full width
             ui.set_min_height(*height_range.start());
@@ -532,6 +609,7 @@ impl TopBottomPanel {
         });
 
         let rect = inner_response.response.rect;
+        ui.ctx().end_accessibility_container(id, rect);
 
         {
             let mut cursor = ui.cursor();
@@ -604,6 +682,80 @@ impl TopBottomPanel {
 
         inner_response
     }
+
+    /// Show the panel if `is_expanded` is `true`,
+    /// otherwise don't show it, but with a nice animation between collapsed and expanded.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// # let mut show_status = true;
+    /// egui::TopBottomPanel::bottom("status").show_animated(ctx, show_status, |ui| {
+    ///     ui.label("Ready");
+    /// });
+    /// # });
+    /// ```
+    pub fn show_animated<R>(
+        self,
+        ctx: &Context,
+        is_expanded: bool,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<InnerResponse<R>> {
+        let how_expanded = ctx.animate_bool(self.id.with("animation"), is_expanded);
+
+        if how_expanded <= 0.0 {
+            None
+        } else if how_expanded < 1.0 {
+            // Show an empty panel of the in-between height while animating:
+            self.animating_panel(ctx, how_expanded).show(ctx, |_ui| {});
+            None
+        } else {
+            Some(self.show(ctx, add_contents))
+        }
+    }
+
+    /// Like [`Self::show_animated`], but the panel is shown inside a [`Ui`].
+    pub fn show_animated_inside<R>(
+        self,
+        ui: &mut Ui,
+        is_expanded: bool,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<InnerResponse<R>> {
+        let how_expanded = ui
+            .ctx()
+            .animate_bool(self.id.with("animation"), is_expanded);
+
+        if how_expanded <= 0.0 {
+            None
+        } else if how_expanded < 1.0 {
+            self.animating_panel(ui.ctx(), how_expanded)
+                .show_inside(ui, |_ui| {});
+            None
+        } else {
+            Some(self.show_inside(ui, add_contents))
+        }
+    }
+
+    /// An empty, non-resizable stand-in for this panel, `how_expanded` as high.
+    fn animating_panel(self, ctx: &Context, how_expanded: f32) -> Self {
+        let expanded_height = PanelState::load(ctx, self.id)
+            .map(|state| state.rect.height())
+            .or(self.default_height)
+            .unwrap_or_else(|| ctx.style().spacing.interact_size.y);
+        let height = how_expanded * expanded_height;
+        // No margins, so the panel is exactly as high as we say:
+        let mut frame = self
+            .frame
+            .unwrap_or_else(|| Frame::side_top_panel(&ctx.style()));
+        frame.inner_margin = style::Margin::same(0.0);
+        Self {
+            id: self.id.with("animating_panel"),
+            frame: Some(frame),
+            resizable: false,
+            default_height: Some(height),
+            height_range: height..=height,
+            ..self
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -659,11 +811,17 @@ impl CentralPanel {
         let panel_rect = ui.available_rect_before_wrap();
         let mut panel_ui = ui.child_ui(panel_rect, Layout::top_down(Align::Min));
 
+        let id = panel_ui.id();
         let frame = frame.unwrap_or_else(|| Frame::central_panel(ui.style()));
-        frame.show(&mut panel_ui, |ui| {
+        ui.ctx()
+            .begin_accessibility_container(id, || WidgetInfo::new(WidgetType::Other));
+        let inner_response = frame.show(&mut panel_ui, |ui| {
             ui.expand_to_include_rect(ui.max_rect()); // Expand frame to include it all
             add_contents(ui)
-        })
+        });
+        ui.ctx()
+            .end_accessibility_container(id, inner_response.response.rect);
+        inner_response
     }
 
     /// Show the panel at the top level.
@@ -704,3 +862,39 @@ fn clamp_to_range(x: f32, range: RangeInclusive<f32>) -> f32 {
         range.start().max(*range.end()),
     )
 }
+
+#[cfg(test)]
+#[test]
+fn animated_side_panel() {
+    let mut harness = Harness::new().with_dt(0.025);
+    let animation_time = harness.ctx().style().animation_time;
+    let mut run = |is_expanded: bool| {
+        harness.run(|ctx| {
+            let shown = SidePanel::left("panel")
+                .default_width(300.0)
+                .show_animated(ctx, is_expanded, |ui| ui.label("Hello"))
+                .is_some();
+            (shown, ctx.available_rect().left())
+        })
+    };
+
+    let (shown, left) = run(true);
+    assert!(shown);
+    let expanded_left = left;
+
+    // While collapsing, an empty panel shrinks:
+    let (shown, left) = run(false);
+    assert!(!shown);
+    assert!(0.0 < left && left < expanded_left, "left: {}", left);
+
+    for _ in 0..(animation_time / 0.025) as usize + 1 {
+        run(false);
+    }
+    assert_eq!(run(false), (false, 0.0));
+
+    // Expanding again goes back to the same width:
+    for _ in 0..(animation_time / 0.025) as usize + 1 {
+        run(true);
+    }
+    assert_eq!(run(true), (true, expanded_left));
+}
@@ -0,0 +1,275 @@
+//! A radial context menu, see [`PieMenu`].
+
+use std::f32::consts::TAU;
+
+use crate::*;
+
+#[derive(Clone, Copy, Debug)]
+struct State {
+    /// Where the menu was opened.
+    center: Pos2,
+
+    /// Has the pointer button that opened the menu been released yet?
+    released_once: bool,
+}
+
+/// A radial menu that opens at the pointer, with its items laid out as slices around it.
+///
+/// The user can either press the trigger button, drag to a slice and release it there,
+/// or click the trigger button and then click a slice.
+/// Pressing escape, or clicking outside the slices, closes the menu.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let response = ui.allocate_response(egui::vec2(200.0, 200.0), egui::Sense::click());
+/// let items = ["✂ Cut", "🗐 Copy", "📋 Paste"];
+/// if let Some(index) = egui::PieMenu::new("canvas_menu").show(&response, items) {
+///     println!("Selected {}", items[index]);
+/// }
+/// # });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct PieMenu {
+    id_source: Id,
+    trigger: PointerButton,
+    radius: f32,
+}
+
+impl PieMenu {
+    /// At most this many items are shown.
+    pub const MAX_ITEMS: usize = 8;
+
+    pub fn new(id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            trigger: PointerButton::Secondary,
+            radius: 80.0,
+        }
+    }
+
+    /// Which pointer button opens the menu. Default: [`PointerButton::Secondary`].
+    pub fn trigger(mut self, trigger: PointerButton) -> Self {
+        self.trigger = trigger;
+        self
+    }
+
+    /// The outer radius of the menu. Default: `80.0`.
+    pub fn radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// Show the menu if the user opened it by pressing the trigger button on `response`.
+    ///
+    /// Returns the index of the item the user selected this frame, if any.
+    /// The first item is at the top, and the rest follow clockwise.
+    pub fn show(
+        self,
+        response: &Response,
+        items: impl IntoIterator<Item = impl Into<WidgetText>>,
+    ) -> Option<usize> {
+        let Self {
+            id_source,
+            trigger,
+            radius,
+        } = self;
+        let ctx = &response.ctx;
+        let id = response.id.with(id_source);
+
+        let mut state = ctx.data().get_temp::<State>(id);
+        if state.is_none() && response.hovered() {
+            let input = ctx.input();
+            if input.pointer.button_pressed(trigger) {
+                state = input.pointer.interact_pos().map(|center| State {
+                    center,
+                    released_once: false,
+                });
+            }
+        }
+        let mut state = state?;
+
+        let items: Vec<WidgetText> = items
+            .into_iter()
+            .take(Self::MAX_ITEMS)
+            .map(Into::into)
+            .collect();
+        let slices = Slices::new(state.center, radius, items.len());
+
+        let hovered_item = ctx
+            .input()
+            .pointer
+            .interact_pos()
+            .and_then(|pos| slices.item_at(pos));
+
+        let (released, escape) = {
+            let input = ctx.input();
+            (input.pointer.any_released(), input.key_pressed(Key::Escape))
+        };
+
+        let mut selected = None;
+        let mut close = escape || items.is_empty();
+        if released {
+            if hovered_item.is_some() {
+                selected = hovered_item;
+                close = true;
+            } else if state.released_once {
+                close = true; // clicked outside the slices
+            }
+            state.released_once = true;
+        }
+
+        if close {
+            ctx.data().remove::<State>(id);
+            return selected;
+        }
+        ctx.data().insert_temp(id, state);
+
+        Area::new(id)
+            .order(Order::Foreground)
+            .fixed_pos(state.center - Vec2::splat(radius))
+            .show(ctx, |ui| {
+                ui.allocate_exact_size(Vec2::splat(2.0 * radius), Sense::hover());
+                slices.paint(ui, items, hovered_item);
+            });
+
+        None
+    }
+}
+
+/// The geometry of the slices of a [`PieMenu`].
+struct Slices {
+    center: Pos2,
+    radius: f32,
+    inner_radius: f32,
+    count: usize,
+}
+
+impl Slices {
+    fn new(center: Pos2, radius: f32, count: usize) -> Self {
+        Self {
+            center,
+            radius,
+            inner_radius: 0.3 * radius,
+            count,
+        }
+    }
+
+    fn slice_angle(&self) -> f32 {
+        TAU / self.count as f32
+    }
+
+    /// The angle at the start of slice `i`. The first slice is centered straight up.
+    fn start_angle(&self, i: usize) -> f32 {
+        -TAU / 4.0 + (i as f32 - 0.5) * self.slice_angle()
+    }
+
+    fn hit_shape(&self, i: usize) -> HitShape {
+        if self.count == 1 {
+            return HitShape::circle(self.center, self.radius);
+        }
+
+        let segments = (32 / self.count).max(2);
+        let mut points = vec![self.center];
+        for s in 0..=segments {
+            let angle = self.start_angle(i) + self.slice_angle() * s as f32 / segments as f32;
+            points.push(self.center + self.radius * Vec2::angled(angle));
+        }
+        HitShape::convex_polygon(points)
+    }
+
+    fn item_at(&self, pos: Pos2) -> Option<usize> {
+        if pos.distance(self.center) < self.inner_radius {
+            return None; // dead zone in the middle
+        }
+        (0..self.count).find(|&i| self.hit_shape(i).contains(pos))
+    }
+
+    fn paint(&self, ui: &Ui, items: Vec<WidgetText>, hovered_item: Option<usize>) {
+        let painter = ui.painter();
+        let visuals = ui.visuals();
+
+        for (i, item) in items.into_iter().enumerate() {
+            let widget_visuals = if hovered_item == Some(i) {
+                &visuals.widgets.hovered
+            } else {
+                &visuals.widgets.inactive
+            };
+
+            match self.hit_shape(i) {
+                HitShape::ConvexPolygon(points) => {
+                    painter.add(Shape::convex_polygon(
+                        points,
+                        widget_visuals.bg_fill,
+                        visuals.window_stroke(),
+                    ));
+                }
+                _ => {
+                    painter.circle(
+                        self.center,
+                        self.radius,
+                        widget_visuals.bg_fill,
+                        visuals.window_stroke(),
+                    );
+                }
+            }
+
+            let mid_angle = self.start_angle(i) + 0.5 * self.slice_angle();
+            let label_pos =
+                self.center + 0.5 * (self.inner_radius + self.radius) * Vec2::angled(mid_angle);
+            let galley = item.into_galley(ui, Some(false), f32::INFINITY, TextStyle::Button);
+            let text_pos = label_pos - 0.5 * galley.size();
+            galley.paint_with_visuals(painter, text_pos, widget_visuals);
+        }
+
+        painter.circle(
+            self.center,
+            self.inner_radius,
+            visuals.window_fill(),
+            visuals.window_stroke(),
+        );
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn pie_menu_drag_release() {
+    let mut harness = Harness::new();
+    let center = pos2(200.0, 200.0);
+    let button = |pos, pressed| Event::PointerButton {
+        pos,
+        button: PointerButton::Secondary,
+        pressed,
+        modifiers: Default::default(),
+    };
+
+    // Returns the selected item, if any:
+    let run = |harness: &mut Harness| {
+        harness.run_ui(|ui| {
+            let response = ui.allocate_response(ui.available_size(), Sense::click());
+            PieMenu::new("menu").show(&response, ["Up", "Right", "Down", "Left"])
+        })
+    };
+
+    harness.move_mouse(center);
+    let _ = run(&mut harness);
+    harness.push_event(button(center, true));
+    assert_eq!(run(&mut harness), None);
+    let right = center + vec2(50.0, 0.0);
+    harness.move_mouse(right);
+    assert_eq!(run(&mut harness), None);
+    harness.push_event(button(right, false));
+    assert_eq!(run(&mut harness), Some(1));
+
+    // Click to open, then click an item:
+    harness.move_mouse(center);
+    let _ = run(&mut harness);
+    harness.push_event(button(center, true));
+    harness.push_event(button(center, false));
+    assert_eq!(run(&mut harness), None);
+    let down = center + vec2(0.0, 50.0);
+    harness.move_mouse(down);
+    assert_eq!(run(&mut harness), None);
+    harness.push_event(button(down, true));
+    harness.push_event(button(down, false));
+    assert_eq!(run(&mut harness), Some(2));
+}
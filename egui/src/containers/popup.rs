@@ -7,8 +7,8 @@ use crate::*;
 /// Same state for all tooltips.
 #[derive(Clone, Debug, Default)]
 pub(crate) struct MonoState {
-    last_id: Option<Id>,
-    last_size: Vec<Vec2>,
+    /// When was the last tooltip shown?
+    last_shown_time: Option<f64>,
 }
 
 impl MonoState {
@@ -20,30 +20,33 @@ impl MonoState {
         ctx.data().insert_temp(Id::null(), self);
     }
 
-    fn tooltip_size(&self, id: Id, index: usize) -> Option<Vec2> {
-        if self.last_id == Some(id) {
-            self.last_size.get(index).cloned()
-        } else {
-            None
-        }
+    /// How many seconds ago was the last tooltip shown?
+    pub(crate) fn seconds_since_last_tooltip(ctx: &Context) -> f64 {
+        let last_shown_time = Self::load(ctx).and_then(|state| state.last_shown_time);
+        last_shown_time.map_or(f64::INFINITY, |time| ctx.input().time - time)
     }
+}
 
-    fn set_tooltip_size(&mut self, id: Id, index: usize, size: Vec2) {
-        if self.last_id == Some(id) {
-            if let Some(stored_size) = self.last_size.get_mut(index) {
-                *stored_size = size;
-            } else {
-                self.last_size
-                    .extend((0..index - self.last_size.len()).map(|_| Vec2::ZERO));
-                self.last_size.push(size);
-            }
-            return;
-        }
+/// The sizes of the tooltips stacked under the same id last frame.
+///
+/// Stored per id, so that a tooltip shown inside another tooltip
+/// doesn't make the outer one forget its size (and jump around).
+#[derive(Clone, Debug, Default)]
+struct TooltipSizes(Vec<Vec2>);
 
-        self.last_id = Some(id);
-        self.last_size.clear();
-        self.last_size.extend((0..index).map(|_| Vec2::ZERO));
-        self.last_size.push(size);
+impl TooltipSizes {
+    fn tooltip_size(ctx: &Context, id: Id, index: usize) -> Option<Vec2> {
+        let sizes: Option<Self> = ctx.data().get_temp(id);
+        sizes.and_then(|sizes| sizes.0.get(index).copied())
+    }
+
+    fn set_tooltip_size(ctx: &Context, id: Id, index: usize, size: Vec2) {
+        let mut data = ctx.data();
+        let sizes = &mut data.get_temp_mut_or_default::<Self>(id).0;
+        if sizes.len() <= index {
+            sizes.resize(index + 1, Vec2::ZERO);
+        }
+        sizes[index] = size;
     }
 }
 
@@ -181,8 +184,7 @@ fn show_tooltip_at_avoid_dyn<'c, R>(
         return None; // No good place for a tooltip :(
     };
 
-    let mut state = MonoState::load(ctx).unwrap_or_default();
-    let expected_size = state.tooltip_size(id, count);
+    let expected_size = TooltipSizes::tooltip_size(ctx, id, count);
     let expected_size = expected_size.unwrap_or_else(|| vec2(64.0, 32.0));
 
     if above {
@@ -209,14 +211,37 @@ fn show_tooltip_at_avoid_dyn<'c, R>(
 
     let position = position.at_least(ctx.input().screen_rect().min);
 
+    // Tooltips shown inside this one are placed next to their own widgets,
+    // instead of being stacked under this one:
+    let first_nested = {
+        let mut frame_state = ctx.frame_state();
+        frame_state.tooltip_rect = None;
+        frame_state.tooltip_rects.len()
+    };
+
     let InnerResponse { inner, response } = show_tooltip_area_dyn(ctx, id, position, add_contents);
 
-    state.set_tooltip_size(id, count, response.rect.size());
+    TooltipSizes::set_tooltip_size(ctx, id, count, response.rect.size());
+    let mut state = MonoState::load(ctx).unwrap_or_default();
+    state.last_shown_time = Some(ctx.input().time);
     state.store(ctx);
 
-    ctx.frame_state().tooltip_rect = Some(crate::frame_state::TooltipRect {
+    let tooltip_rect = tooltip_rect.union(response.rect);
+    let mut frame_state = ctx.frame_state();
+    let rect_with_nested = frame_state.tooltip_rects[first_nested..]
+        .iter()
+        .fold(tooltip_rect, |rect, nested| rect.union(nested.rect));
+    frame_state.tooltip_rects.retain(|stored| stored.id != id);
+    frame_state
+        .tooltip_rects
+        .push(crate::frame_state::TooltipRect {
+            id,
+            rect: rect_with_nested,
+            count: count + 1,
+        });
+    frame_state.tooltip_rect = Some(crate::frame_state::TooltipRect {
         id,
-        rect: tooltip_rect.union(response.rect),
+        rect: tooltip_rect,
         count: count + 1,
     });
     Some(inner)
@@ -254,7 +279,7 @@ fn show_tooltip_area_dyn<'c, R>(
     Area::new(id)
         .order(Order::Tooltip)
         .fixed_pos(window_pos)
-        .interactable(false)
+        .interactable(ctx.style().interaction.interactive_tooltips)
         .drag_bounds(Rect::EVERYTHING) // disable clip rect
         .show(ctx, |ui| {
             Frame::popup(&ctx.style())
@@ -323,3 +348,154 @@ pub fn popup_below_widget<R>(
         None
     }
 }
+
+#[cfg(test)]
+#[test]
+fn interactive_tooltip_stays_open() {
+    let mut harness = Harness::new();
+    let mut style = (*harness.ctx().style()).clone();
+    style.interaction.interactive_tooltips = true;
+    harness.ctx().set_style(style);
+
+    // Returns the rectangle of the tooltip, if shown:
+    let run = |harness: &mut Harness| {
+        harness.run_ui(|ui| {
+            let mut tooltip_rect = None;
+            ui.button("Hover me").on_hover_ui(|ui| {
+                tooltip_rect = Some(ui.button("Click me").rect);
+            });
+            tooltip_rect
+        })
+    };
+
+    let _ = run(&mut harness);
+    harness.move_mouse(pos2(20.0, 15.0));
+    let tooltip_rect = run(&mut harness).expect("hovering should show the tooltip");
+    harness.move_mouse(tooltip_rect.center());
+    assert!(run(&mut harness).is_some());
+    harness.move_mouse(pos2(500.0, 500.0));
+    assert!(run(&mut harness).is_none());
+}
+
+#[cfg(test)]
+#[test]
+fn tooltip_delay_and_grace_time() {
+    // No time passes between frames unless we say so:
+    let mut harness = Harness::new().with_dt(0.0);
+    let mut style = (*harness.ctx().style()).clone();
+    style.interaction.tooltip_delay = 0.5;
+    style.interaction.tooltip_grace_time = 0.2;
+    harness.ctx().set_style(style);
+
+    // Returns the rectangles of the two buttons, and which tooltips were shown:
+    let mut hover_at = |time: f64, pos: Pos2| {
+        harness.advance_time(time - harness.time());
+        harness.move_mouse(pos);
+        harness.run_ui(|ui| {
+            let mut rects = [Rect::NOTHING; 2];
+            let mut shown = [false; 2];
+            ui.horizontal(|ui| {
+                for i in 0..2 {
+                    let response = ui.button("Hover me").on_hover_ui(|ui| {
+                        ui.label("Tooltip");
+                        shown[i] = true;
+                    });
+                    rects[i] = response.rect;
+                }
+            });
+            (rects, shown)
+        })
+    };
+
+    let (rects, _) = hover_at(0.0, pos2(500.0, 500.0));
+    let [a, b] = [rects[0].center(), rects[1].center()];
+
+    assert_eq!(hover_at(0.1, a).1, [false, false]);
+    assert_eq!(hover_at(0.4, a).1, [false, false]);
+    assert_eq!(hover_at(0.7, a).1, [true, false]);
+
+    // Moving to an adjacent widget shows its tooltip right away:
+    assert_eq!(hover_at(0.8, b).1, [false, true]);
+
+    // …but not once the grace time has passed:
+    let _ = hover_at(0.9, pos2(500.0, 500.0));
+    assert_eq!(hover_at(1.5, a).1, [false, false]);
+    assert_eq!(hover_at(2.1, a).1, [true, false]);
+}
+
+#[cfg(test)]
+#[test]
+fn stacked_tooltips_are_not_id_clashes() {
+    let mut harness = Harness::new();
+    let mut style = (*harness.ctx().style()).clone();
+    style.interaction.tooltip_delay = 0.0;
+    harness.ctx().set_style(style);
+
+    let button_rect = harness.run_ui(|ui| ui.button("Hover me").rect);
+    harness.move_mouse(button_rect.center());
+    for _ in 0..2 {
+        harness.run_ui(|ui| {
+            ui.button("Hover me")
+                .on_hover_text("first")
+                .on_hover_text("second");
+        });
+    }
+
+    assert!(
+        harness.ctx().frame_state().tooltip_rect.is_some(),
+        "Tooltips are shown"
+    );
+    assert!(harness.ctx().id_clashes().is_empty());
+}
+
+#[cfg(test)]
+#[test]
+fn nested_tooltips() {
+    let mut harness = Harness::new();
+    let mut style = (*harness.ctx().style()).clone();
+    style.interaction.interactive_tooltips = true;
+    harness.ctx().set_style(style);
+
+    // Returns the rectangles of the outer tooltip label, the button in it, and the nested tooltip:
+    let run = |harness: &mut Harness| {
+        let mut rects = (None, None, None);
+        harness.run_ui(|ui| {
+            ui.button("Hover me").on_hover_ui(|ui| {
+                rects.0 = Some(ui.colored_label(Color32::RED, "Outer").rect);
+                let response = ui.button("More").on_hover_ui(|ui| {
+                    rects.2 = Some(ui.label("Nested").rect);
+                });
+                rects.1 = Some(response.rect);
+            });
+        });
+        rects
+    };
+
+    harness.move_mouse(pos2(20.0, 15.0));
+    let (outer, button, nested) = run(&mut harness);
+    let (outer, button) = (outer.unwrap(), button.unwrap());
+    assert!(nested.is_none());
+
+    harness.move_mouse(button.center());
+    let _ = run(&mut harness);
+    let (outer_again, _, nested) = run(&mut harness);
+    assert_eq!(
+        outer_again,
+        Some(outer),
+        "The outer tooltip should stay in place"
+    );
+    let nested = nested.expect("hovering the button in the tooltip should show its tooltip");
+    assert!(
+        nested.min.y >= button.max.y,
+        "Placed next to its own widget"
+    );
+
+    // Both stay open while the pointer is on the nested tooltip:
+    harness.move_mouse(nested.center());
+    let (outer_again, _, nested_again) = run(&mut harness);
+    assert_eq!(outer_again, Some(outer));
+    assert_eq!(nested_again, Some(nested));
+
+    harness.move_mouse(pos2(500.0, 500.0));
+    assert_eq!(run(&mut harness), (None, None, None));
+}
@@ -301,10 +301,7 @@ impl Resize {
 
         if let Some(corner_response) = corner_response {
             paint_resize_corner(ui, &corner_response);
-
-            if corner_response.hovered() || corner_response.dragged() {
-                ui.ctx().output().cursor_icon = CursorIcon::ResizeNwSe;
-            }
+            corner_response.on_hover_and_drag_cursor(CursorIcon::ResizeNwSe);
         }
 
         state.store(ui.ctx(), id);
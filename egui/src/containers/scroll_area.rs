@@ -41,6 +41,10 @@ impl Default for State {
     }
 }
 
+/// The scroll offset shared by all scroll areas in a [`ScrollArea::scroll_group`].
+#[derive(Clone, Copy, Debug, Default)]
+struct ScrollGroupOffset(Vec2);
+
 impl State {
     pub fn load(ctx: &Context, id: Id) -> Option<Self> {
         ctx.data().get_persisted(id)
@@ -51,6 +55,26 @@ impl State {
     }
 }
 
+/// What [`ScrollArea::show`] and friends return.
+///
+/// Useful for e.g. showing how much more there is to scroll to,
+/// or for keeping two scroll areas in sync.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let output = egui::ScrollArea::vertical()
+///     .max_height(100.0)
+///     .show(ui, |ui| {
+///         for i in 0..100 {
+///             ui.label(format!("Item {}", i));
+///         }
+///     });
+/// let hidden_below = output.content_size.y - output.inner_rect.height() - output.state.offset.y;
+/// if hidden_below > 0.0 {
+///     ui.label(format!("{:.0} points more below", hidden_below));
+/// }
+/// # });
+/// ```
 pub struct ScrollAreaOutput<R> {
     /// What the user closure returned.
     pub inner: R,
@@ -58,10 +82,15 @@ pub struct ScrollAreaOutput<R> {
     /// [`Id`] of the [`ScrollArea`].
     pub id: Id,
 
-    /// The current state of the scroll area.
+    /// The current state of the scroll area,
+    /// including the scroll [`State::offset`] at the end of the frame.
     pub state: State,
 
-    /// Where on the screen the content is (excludes scroll bars).
+    /// The size of the content, which may be larger than [`Self::inner_rect`].
+    pub content_size: Vec2,
+
+    /// Where on the screen the content is shown (excludes scroll bars),
+    /// after shrinking to fit the content.
     pub inner_rect: Rect,
 }
 
@@ -86,6 +115,7 @@ pub struct ScrollArea {
     min_scrolled_size: Vec2,
     always_show_scroll: bool,
     id_source: Option<Id>,
+    scroll_group: Option<Id>,
     offset_x: Option<f32>,
     offset_y: Option<f32>,
     /// If false, we ignore scroll events.
@@ -129,6 +159,7 @@ impl ScrollArea {
             min_scrolled_size: Vec2::splat(64.0),
             always_show_scroll: false,
             id_source: None,
+            scroll_group: None,
             offset_x: None,
             offset_y: None,
             scrolling_enabled: true,
@@ -191,6 +222,35 @@ impl ScrollArea {
         self
     }
 
+    /// Link this scroll area to all other scroll areas in the same group,
+    /// e.g. `.scroll_group("diff_view")`.
+    ///
+    /// When one of them is scrolled, the others follow (on the next frame),
+    /// along the axes they can all scroll in.
+    /// This is useful for side-by-side diff views,
+    /// or for a table header that should follow the horizontal scrolling of the table body.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.columns(2, |columns| {
+    ///     for (i, column) in columns.iter_mut().enumerate() {
+    ///         egui::ScrollArea::vertical()
+    ///             .id_source(i)
+    ///             .scroll_group("diff_view")
+    ///             .show(column, |ui| {
+    ///                 for line in 0..100 {
+    ///                     ui.label(format!("Line {}", line));
+    ///                 }
+    ///             });
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    pub fn scroll_group(mut self, group: impl std::hash::Hash) -> Self {
+        self.scroll_group = Some(Id::new(group));
+        self
+    }
+
     /// Set the horizontal and vertical scroll offset position.
     ///
     /// See also: [`Self::vertical_scroll_offset`], [`Self::horizontal_scroll_offset`],
@@ -292,6 +352,9 @@ impl ScrollArea {
 struct Prepared {
     id: Id,
     state: State,
+    scroll_group: Option<Id>,
+    /// The offset we started the frame with, to know if we were scrolled this frame.
+    start_offset: Vec2,
     has_bar: [bool; 2],
     auto_shrink: [bool; 2],
     /// How much horizontal and vertical space are used up by the
@@ -317,6 +380,7 @@ impl ScrollArea {
             min_scrolled_size,
             always_show_scroll,
             id_source,
+            scroll_group,
             offset_x,
             offset_y,
             scrolling_enabled,
@@ -334,6 +398,18 @@ impl ScrollArea {
         );
         let mut state = State::load(&ctx, id).unwrap_or_default();
 
+        if let Some(group) = scroll_group {
+            let group_offset = ctx.data().get_temp::<ScrollGroupOffset>(group);
+            if let Some(ScrollGroupOffset(group_offset)) = group_offset {
+                for d in 0..2 {
+                    if has_bar[d] {
+                        state.offset[d] = group_offset[d];
+                    }
+                }
+            }
+        }
+        let start_offset = state.offset;
+
         state.offset.x = offset_x.unwrap_or(state.offset.x);
         state.offset.y = offset_y.unwrap_or(state.offset.y);
 
@@ -409,6 +485,8 @@ impl ScrollArea {
         Prepared {
             id,
             state,
+            scroll_group,
+            start_offset,
             has_bar,
             auto_shrink,
             current_bar_use,
@@ -424,6 +502,8 @@ impl ScrollArea {
     /// Show the [`ScrollArea`], and add the contents to the viewport.
     ///
     /// If the inner area can be very long, consider using [`Self::show_rows`] instead.
+    /// For rows of different heights, you can skip building the contents of rows that are
+    /// scrolled out of view using [`Ui::is_rect_visible`].
     pub fn show<R>(
         self,
         ui: &mut Ui,
@@ -496,23 +576,29 @@ impl ScrollArea {
     ) -> ScrollAreaOutput<R> {
         let mut prepared = self.begin(ui);
         let id = prepared.id;
-        let inner_rect = prepared.inner_rect;
+        ui.ctx()
+            .begin_accessibility_container(id, || WidgetInfo::new(WidgetType::Other));
         let inner = add_contents(&mut prepared.content_ui, prepared.viewport);
-        let state = prepared.end(ui);
+        let (content_size, inner_rect, state) = prepared.end(ui);
+        ui.ctx().end_accessibility_container(id, inner_rect);
         ScrollAreaOutput {
             inner,
             id,
             state,
+            content_size,
             inner_rect,
         }
     }
 }
 
 impl Prepared {
-    fn end(self, ui: &mut Ui) -> State {
+    /// Returns the content size, the final inner rect, and the state.
+    fn end(self, ui: &mut Ui) -> (Vec2, Rect, State) {
         let Prepared {
             id,
             mut state,
+            scroll_group,
+            start_offset,
             inner_rect,
             has_bar,
             auto_shrink,
@@ -607,24 +693,42 @@ impl Prepared {
         ];
 
         if content_is_too_small[0] || content_is_too_small[1] {
-            // Drag contents to scroll (for touch screens mostly):
+            // Drag contents to scroll (for touch screens mostly).
+            // Keyboard users scroll with the (focusable) scroll bars instead.
             let sense = if self.scrolling_enabled {
-                Sense::drag()
+                Sense {
+                    focusable: false,
+                    ..Sense::drag()
+                }
             } else {
                 Sense::hover()
             };
             let content_response = ui.interact(inner_rect, id.with("area"), sense);
 
             if content_response.dragged() {
+                let drag_delta = content_response.drag_delta();
                 for d in 0..2 {
                     if has_bar[d] {
-                        state.offset[d] -= ui.input().pointer.delta()[d];
+                        state.offset[d] -= drag_delta[d];
                         state.vel[d] = ui.input().pointer.velocity()[d];
                         state.scroll_stuck_to_end[d] = false;
                     } else {
                         state.vel[d] = 0.0;
                     }
                 }
+            } else if content_response.drag_released() {
+                // Fling with the (smoothed) velocity the pointer had when it was let go:
+                let velocity = {
+                    let input = ui.input();
+                    if ui.style().interaction.drag_axis_lock && input.modifiers.shift {
+                        input.pointer.dominant_axis_velocity()
+                    } else {
+                        input.pointer.velocity()
+                    }
+                };
+                for d in 0..2 {
+                    state.vel[d] = if has_bar[d] { velocity[d] } else { 0.0 };
+                }
             } else {
                 let stop_speed = 20.0; // Pixels per second.
                 let friction_coeff = 1000.0; // Pixels per second squared.
@@ -686,7 +790,7 @@ impl Prepared {
             }
 
             // margin between contents and scroll bar
-            let margin = animation_t * ui.spacing().item_spacing.x;
+            let margin = animation_t * ui.spacing().scroll_bar_inner_margin;
             let min_cross = inner_rect.max[1 - d] + margin; // left of vertical scroll (d == 1)
             let max_cross = outer_rect.max[1 - d]; // right of vertical scroll (d == 1)
             let min_main = inner_rect.min[d]; // top of vertical scroll (d == 1)
@@ -709,23 +813,34 @@ impl Prepared {
                 state.offset[d] = content_size[d] - inner_rect.size()[d];
             }
 
-            let from_content =
-                |content| remap_clamp(content, 0.0..=content_size[d], min_main..=max_main);
-
-            let handle_rect = if d == 0 {
-                Rect::from_min_max(
-                    pos2(from_content(state.offset.x), min_cross),
-                    pos2(from_content(state.offset.x + inner_rect.width()), max_cross),
-                )
-            } else {
-                Rect::from_min_max(
-                    pos2(min_cross, from_content(state.offset.y)),
-                    pos2(
-                        max_cross,
-                        from_content(state.offset.y + inner_rect.height()),
-                    ),
-                )
+            // The handle is never shorter than `scroll_handle_min_length`, so that it can always be grabbed.
+            // It moves along the rest of the track:
+            let track_length = max_main - min_main;
+            let handle_length = (track_length * inner_rect.size()[d] / content_size[d])
+                .at_least(ui.spacing().scroll_handle_min_length)
+                .at_most(track_length);
+            let handle_travel = min_main..=max_main - handle_length;
+            let offset_range = 0.0..=max_offset[d];
+            let can_travel = max_offset[d] > 0.0 && handle_length < track_length;
+
+            let handle_rect_at = |offset: f32| {
+                let handle_top = if can_travel {
+                    remap_clamp(offset, offset_range.clone(), handle_travel.clone())
+                } else {
+                    min_main
+                };
+                let main_range = handle_top..=handle_top + handle_length;
+                if d == 0 {
+                    Rect::from_x_y_ranges(main_range, min_cross..=max_cross)
+                } else {
+                    Rect::from_x_y_ranges(min_cross..=max_cross, main_range)
+                }
             };
+            let handle_rect = handle_rect_at(state.offset[d]);
+
+            // Make the scroll bar a bit easier to hit, by extending it into the margin:
+            let mut interact_rect = outer_scroll_rect;
+            interact_rect.min[1 - d] -= margin.at_most(4.0);
 
             let interact_id = id.with(d);
             let sense = if self.scrolling_enabled {
@@ -733,24 +848,26 @@ impl Prepared {
             } else {
                 Sense::hover()
             };
-            let response = ui.interact(outer_scroll_rect, interact_id, sense);
+            let response = ui.interact(interact_rect, interact_id, sense);
 
             if let Some(pointer_pos) = response.interact_pointer_pos() {
                 let scroll_start_offset_from_top_left = state.scroll_start_offset_from_top_left[d]
                     .get_or_insert_with(|| {
-                        if handle_rect.contains(pointer_pos) {
+                        if (handle_rect.min[d]..=handle_rect.max[d]).contains(&pointer_pos[d]) {
                             pointer_pos[d] - handle_rect.min[d]
                         } else {
-                            let handle_top_pos_at_bottom = max_main - handle_rect.size()[d];
                             // Calculate the new handle top position, centering the handle on the mouse.
-                            let new_handle_top_pos = (pointer_pos[d] - handle_rect.size()[d] / 2.0)
-                                .clamp(min_main, handle_top_pos_at_bottom);
+                            let new_handle_top_pos = (pointer_pos[d] - handle_length / 2.0)
+                                .clamp(min_main, *handle_travel.end());
                             pointer_pos[d] - new_handle_top_pos
                         }
                     });
 
-                let new_handle_top = pointer_pos[d] - *scroll_start_offset_from_top_left;
-                state.offset[d] = remap(new_handle_top, min_main..=max_main, 0.0..=content_size[d]);
+                if can_travel {
+                    let new_handle_top = pointer_pos[d] - *scroll_start_offset_from_top_left;
+                    state.offset[d] =
+                        remap(new_handle_top, handle_travel.clone(), offset_range.clone());
+                }
 
                 // some manual action taken, scroll not stuck
                 state.scroll_stuck_to_end[d] = false;
@@ -758,6 +875,37 @@ impl Prepared {
                 state.scroll_start_offset_from_top_left[d] = None;
             }
 
+            response.widget_info(|| WidgetInfo {
+                value: Some(state.offset[d] as f64),
+                ..WidgetInfo::new(WidgetType::ScrollBar)
+            });
+
+            if response.has_focus() {
+                // Nudge the scroll bar with the keyboard:
+                ui.memory().capture_arrow_keys(interact_id, d == 0, d == 1);
+
+                let (dec_key, inc_key) = if d == 0 {
+                    (Key::ArrowLeft, Key::ArrowRight)
+                } else {
+                    (Key::ArrowUp, Key::ArrowDown)
+                };
+                let (line_steps, page_steps) = {
+                    let input = ui.input();
+                    (
+                        input.num_presses(inc_key) as f32 - input.num_presses(dec_key) as f32,
+                        input.num_presses(Key::PageDown) as f32
+                            - input.num_presses(Key::PageUp) as f32,
+                    )
+                };
+                let line_height = ui.text_style_height(&TextStyle::Body);
+                let delta = line_steps * line_height + page_steps * inner_rect.size()[d];
+                if delta != 0.0 {
+                    state.offset[d] += delta;
+                    state.vel[d] = 0.0;
+                    state.scroll_stuck_to_end[d] = false;
+                }
+            }
+
             let unbounded_offset = state.offset[d];
             state.offset[d] = state.offset[d].max(0.0);
             state.offset[d] = state.offset[d].min(max_offset[d]);
@@ -766,33 +914,19 @@ impl Prepared {
                 state.vel[d] = 0.0;
             }
 
+            // Grow a bit while hovered, for easier targeting:
+            let hover_t = ui.ctx().animate_bool(
+                interact_id.with("hover"),
+                scrolling_enabled && (response.hovered() || response.dragged()),
+            );
+
             if ui.is_rect_visible(outer_scroll_rect) {
                 // Avoid frame-delay by calculating a new handle rect:
-                let mut handle_rect = if d == 0 {
-                    Rect::from_min_max(
-                        pos2(from_content(state.offset.x), min_cross),
-                        pos2(from_content(state.offset.x + inner_rect.width()), max_cross),
-                    )
-                } else {
-                    Rect::from_min_max(
-                        pos2(min_cross, from_content(state.offset.y)),
-                        pos2(
-                            max_cross,
-                            from_content(state.offset.y + inner_rect.height()),
-                        ),
-                    )
-                };
-                let min_handle_size = ui.spacing().scroll_bar_width;
-                if handle_rect.size()[d] < min_handle_size {
-                    handle_rect = Rect::from_center_size(
-                        handle_rect.center(),
-                        if d == 0 {
-                            vec2(min_handle_size, handle_rect.size().y)
-                        } else {
-                            vec2(handle_rect.size().x, min_handle_size)
-                        },
-                    );
-                }
+                let mut handle_rect = handle_rect_at(state.offset[d]);
+                let mut track_rect = outer_scroll_rect;
+                let grow = hover_t * (0.5 * ui.spacing().scroll_bar_width).at_most(margin);
+                handle_rect.min[1 - d] -= grow;
+                track_rect.min[1 - d] -= grow;
 
                 let visuals = if scrolling_enabled {
                     ui.style().interact(&response)
@@ -801,7 +935,7 @@ impl Prepared {
                 };
 
                 ui.painter().add(epaint::Shape::rect_filled(
-                    outer_scroll_rect,
+                    track_rect,
                     visuals.rounding,
                     ui.visuals().extreme_bg_color,
                 ));
@@ -824,6 +958,14 @@ impl Prepared {
         state.offset = state.offset.min(available_offset);
         state.offset = state.offset.max(Vec2::ZERO);
 
+        // Compare with the clamped start offset, so that a scroll area with less content
+        // doesn't drag the rest of its group back:
+        let start_offset = start_offset.min(available_offset).max(Vec2::ZERO);
+        let scrolled = [
+            state.offset.x != start_offset.x,
+            state.offset.y != start_offset.y,
+        ];
+
         // Is scroll handle at end of content, or is there no scrollbar
         // yet (not enough content), but sticking is requested? If so, enter sticky mode.
         // Only has an effect if stick_to_end is enabled but we save in
@@ -840,11 +982,270 @@ impl Prepared {
 
         state.store(ui.ctx(), id);
 
-        state
+        if let Some(group) = scroll_group {
+            let mut group_changed = false;
+            {
+                let mut data = ui.ctx().data();
+                let group_offset = &mut data
+                    .get_temp_mut_or_insert_with(group, || ScrollGroupOffset(state.offset))
+                    .0;
+                for d in 0..2 {
+                    if has_bar[d] && scrolled[d] && group_offset[d] != state.offset[d] {
+                        group_offset[d] = state.offset[d];
+                        group_changed = true;
+                    }
+                }
+            }
+            if group_changed {
+                ui.ctx().request_repaint(); // so the rest of the group can follow
+            }
+        }
+
+        (content_size, inner_rect, state)
     }
 }
 
 /// Width of a vertical scrollbar, or height of a horizontal scroll bar
 fn max_scroll_bar_width_with_margin(ui: &Ui) -> f32 {
-    ui.spacing().item_spacing.x + ui.spacing().scroll_bar_width
+    ui.spacing().scroll_bar_inner_margin + ui.spacing().scroll_bar_width
+}
+
+#[cfg(test)]
+#[test]
+fn drag_contents_to_scroll() {
+    let mut harness = Harness::new();
+    let show = |ui: &mut Ui| {
+        let output = ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
+            for i in 0..100 {
+                ui.label(format!("Item {}", i));
+            }
+        });
+        (output.id, output.state.offset.y)
+    };
+
+    harness.run_ui(show);
+    harness.press(pos2(20.0, 150.0));
+    harness.run_ui(show);
+    harness.move_mouse(pos2(20.0, 100.0));
+    let (id, offset) = harness.run_ui(show);
+    assert_eq!(offset, 50.0);
+
+    // The contents can be dragged, but tab skips past them to the scroll bar:
+    harness.release(pos2(20.0, 100.0));
+    harness.run_ui(show);
+    harness.key_press(Key::Tab);
+    harness.run_ui(show);
+    assert_eq!(harness.ctx().memory().focus(), Some(id.with(1_usize)));
+}
+
+#[cfg(test)]
+#[test]
+fn fling_after_drag() {
+    let mut harness = Harness::new();
+    let show = |ui: &mut Ui| {
+        ScrollArea::vertical()
+            .max_height(200.0)
+            .show(ui, |ui| {
+                for i in 0..100 {
+                    ui.label(format!("Item {}", i));
+                }
+            })
+            .state
+            .offset
+            .y
+    };
+
+    harness.run_ui(show);
+    harness.press(pos2(20.0, 150.0));
+    harness.run_ui(show);
+
+    // Drag the contents up quickly, then let go:
+    for i in 1..=5 {
+        harness.move_mouse(pos2(20.0, 150.0 - 20.0 * i as f32));
+        harness.run_ui(show);
+    }
+    harness.release(pos2(20.0, 50.0));
+    let released = harness.run_ui(show);
+    assert_eq!(released, 100.0);
+
+    // The contents keep on moving for a while:
+    let flung = harness.run_ui(show);
+    assert!(flung > released);
+}
+
+#[cfg(test)]
+#[test]
+fn scroll_bar_appears_over_animation_time() {
+    let mut harness = Harness::new().with_dt(0.25);
+    let mut style = (*harness.ctx().style()).clone();
+    style.animation_time = 1.0;
+    harness.ctx().set_style(style);
+    let mut show = |num_items: usize| {
+        harness.run_ui(|ui| {
+            ScrollArea::vertical()
+                .max_height(100.0)
+                .auto_shrink([false; 2])
+                .show(ui, |ui| {
+                    for i in 0..num_items {
+                        ui.label(format!("Item {}", i));
+                    }
+                })
+                .inner_rect
+                .width()
+        })
+    };
+
+    let full_width = show(1);
+    let widths: Vec<f32> = (0..6).map(|_| show(100)).collect();
+    assert!(
+        widths.windows(2).all(|w| w[1] <= w[0]),
+        "The scroll bar grows: {:?}",
+        widths
+    );
+    assert!(full_width > widths[2], "{:?}", widths);
+    assert!(widths[2] > widths[5], "{:?}", widths);
+}
+
+#[cfg(test)]
+#[test]
+fn scroll_area_output() {
+    let mut harness = Harness::new();
+    let show = |ui: &mut Ui| {
+        ScrollArea::vertical().max_height(100.0).show(ui, |ui| {
+            for i in 0..50 {
+                ui.label(format!("Item {}", i));
+            }
+        })
+    };
+
+    let output = harness.run_ui(show);
+    assert_eq!(output.inner_rect.height(), 100.0);
+    assert!(output.content_size.y > 500.0);
+    assert_eq!(output.state.offset, Vec2::ZERO);
+
+    // Small content shrinks the scroll area:
+    let output = harness.run_ui(|ui| {
+        ScrollArea::vertical()
+            .id_source("small")
+            .show(ui, |ui| ui.label("Only item"))
+    });
+    assert_eq!(output.inner_rect.size(), output.content_size);
+
+    harness.move_mouse(pos2(20.0, 50.0));
+    harness.push_event(Event::Scroll(vec2(0.0, -30.0)));
+    let output = harness.run_ui(show);
+    assert_eq!(output.state.offset.y, 30.0);
+}
+
+#[cfg(test)]
+#[test]
+fn scroll_group() {
+    let mut harness = Harness::new();
+
+    // Returns the offsets of the two scroll areas, the second of which has less content:
+    let run = |harness: &mut Harness| {
+        harness.run_ui(|ui| {
+            let mut offsets = [0.0; 2];
+            ui.columns(2, |columns| {
+                for (i, column) in columns.iter_mut().enumerate() {
+                    let output = ScrollArea::vertical()
+                        .id_source(i)
+                        .max_height(100.0)
+                        .scroll_group("group")
+                        .show(column, |ui| {
+                            for line in 0..(50 - 40 * i) {
+                                ui.label(format!("Line {}", line));
+                            }
+                        });
+                    offsets[i] = output.state.offset.y;
+                }
+            });
+            offsets
+        })
+    };
+
+    assert_eq!(run(&mut harness), [0.0, 0.0]);
+
+    // Scroll the second one:
+    harness.move_mouse(pos2(700.0, 50.0));
+    harness.push_event(Event::Scroll(vec2(0.0, -30.0)));
+    assert_eq!(run(&mut harness), [0.0, 30.0]);
+    assert_eq!(
+        run(&mut harness),
+        [30.0, 30.0],
+        "the first follows the next frame"
+    );
+
+    // Scroll the first one further than the second one can go:
+    harness.move_mouse(pos2(200.0, 50.0));
+    harness.push_event(Event::Scroll(vec2(0.0, -300.0)));
+    let [first, _] = run(&mut harness);
+    assert_eq!(first, 330.0);
+    let [first_again, second] = run(&mut harness);
+    assert_eq!(first_again, first, "the second doesn't hold the first back");
+    assert!(second < first);
+}
+
+#[cfg(test)]
+#[test]
+fn scroll_bar_handle() {
+    let mut harness = Harness::new();
+    let run = |harness: &mut Harness| {
+        let output = harness.run_ui(|ui| {
+            let row_height = ui.text_style_height(&TextStyle::Body);
+            ScrollArea::vertical().max_height(200.0).show_rows(
+                ui,
+                row_height,
+                1_000_000,
+                |ui, rows| {
+                    for row in rows {
+                        ui.label(format!("Row {}", row));
+                    }
+                },
+            )
+        });
+        let track = Rect::from_x_y_ranges(
+            output.inner_rect.right()..=f32::INFINITY,
+            output.inner_rect.y_range(),
+        );
+        // The handle is the last rectangle painted in the scroll bar:
+        let handle = harness
+            .output()
+            .shapes
+            .iter()
+            .rev()
+            .find_map(|clipped| match &clipped.1 {
+                Shape::Rect(shape) if track.contains_rect(shape.rect) => Some(shape.rect),
+                _ => None,
+            })
+            .unwrap();
+        (
+            output.state.offset.y,
+            output.content_size.y - output.inner_rect.height(),
+            handle,
+        )
+    };
+
+    let (_, _, handle) = run(&mut harness);
+    assert_eq!(
+        handle.height(),
+        harness.ctx().style().spacing.scroll_handle_min_length
+    );
+
+    // Hovering just next to the scroll bar makes it grow:
+    harness.move_mouse(handle.left_center() - vec2(2.0, 0.0));
+    for _ in 0..10 {
+        run(&mut harness);
+    }
+    let (_, _, hovered_handle) = run(&mut harness);
+    assert!(hovered_handle.width() > handle.width());
+
+    // Drag the handle all the way down:
+    harness.press(hovered_handle.center());
+    run(&mut harness);
+    harness.release(hovered_handle.center() + vec2(0.0, 500.0));
+    run(&mut harness);
+    let (offset, max_offset, handle) = run(&mut harness);
+    assert_eq!(offset, max_offset);
+    assert_eq!(handle.bottom(), hovered_handle.top() + 200.0);
 }
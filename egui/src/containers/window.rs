@@ -61,6 +61,25 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Like [`Self::id`], but hashes the given value into an [`Id`].
+    ///
+    /// Two windows with the same title share the same [`Id`], and therefore position, size and collapsed state.
+    /// Give them different `id_source`s to keep them apart:
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// for i in 0..2 {
+    ///     egui::Window::new("Settings")
+    ///         .id_source(("settings", i))
+    ///         .show(ctx, |ui| ui.label(format!("Settings #{}", i)));
+    /// }
+    /// assert!(ctx.id_clashes().is_empty());
+    /// # });
+    /// ```
+    pub fn id_source(self, id_source: impl std::hash::Hash) -> Self {
+        self.id(Id::new(id_source))
+    }
+
     /// Call this to add a close-button to the window title bar.
     ///
     /// * If `*open == false`, the window will not be visible.
@@ -137,6 +156,15 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Which point of the window is placed at the position given by
+    /// [`Self::default_pos`], [`Self::fixed_pos`] or [`Self::current_pos`].
+    ///
+    /// See [`Area::pivot`].
+    pub fn pivot(mut self, pivot: Align2) -> Self {
+        self.area = self.area.pivot(pivot);
+        self
+    }
+
     /// Set initial size of the window.
     pub fn default_size(mut self, default_size: impl Into<Vec2>) -> Self {
         self.resize = self.resize.default_size(default_size);
@@ -281,6 +309,9 @@ impl<'open> Window<'open> {
         let mut resize = resize.id(resize_id);
 
         let mut area = area.begin(ctx);
+        ctx.begin_accessibility_container(area_id, || {
+            WidgetInfo::labeled(WidgetType::Other, title.text())
+        });
 
         let title_content_spacing = 2.0 * ctx.style().spacing.item_spacing.y;
 
@@ -401,6 +432,7 @@ impl<'open> Window<'open> {
             .min;
 
         let full_response = area.end(ctx, area_content_ui);
+        ctx.end_accessibility_container(area_id, full_response.rect);
 
         let inner_response = InnerResponse {
             inner: content_inner,
@@ -927,3 +959,28 @@ fn close_button(ui: &mut Ui, rect: Rect) -> Response {
         .line_segment([rect.right_top(), rect.left_bottom()], stroke);
     response
 }
+
+#[cfg(test)]
+#[test]
+fn windows_with_same_title() {
+    let mut harness = Harness::new();
+    let run = |harness: &mut Harness, id_sources: bool| {
+        harness.run(|ctx| {
+            for i in 0..2 {
+                let mut window = Window::new("Same title");
+                if id_sources {
+                    window = window.id_source(i);
+                }
+                window.show(ctx, |ui| ui.label("Hello"));
+            }
+        });
+        harness.ctx().id_clashes()
+    };
+
+    let clashes = run(&mut harness, false);
+    assert_eq!(clashes.len(), 1);
+    assert_eq!(clashes[0].id, Id::new("Same title"));
+    assert_eq!(clashes[0].what, "Area");
+
+    assert!(run(&mut harness, true).is_empty());
+}
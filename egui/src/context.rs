@@ -3,7 +3,8 @@ use std::sync::Arc;
 
 use crate::{
     animation_manager::AnimationManager, data::output::PlatformOutput, frame_state::FrameState,
-    input_state::*, layers::GraphicLayers, memory::Options, output::FullOutput, TextureHandle, *,
+    input_state::*, layers::GraphicLayers, memory::Options, output::FullOutput,
+    tessellation_cache::TessellationCache, TextureHandle, *,
 };
 use epaint::{mutex::*, stats::*, text::Fonts, textures::TextureFilter, TessellationOptions, *};
 
@@ -29,6 +30,52 @@ impl Default for WrappedTextureManager {
 
 // ----------------------------------------------------------------------------
 
+/// Measures the [`FrameTimings`].
+struct FrameTimer {
+    /// Timings of the last finished frame.
+    last_frame: FrameTimings,
+
+    /// Timings of the frame in progress.
+    current: FrameTimings,
+
+    /// When we started building the UI, i.e. the end of [`Context::begin_frame`].
+    ui_start: Option<std::time::Instant>,
+
+    /// Timings of recent frames, for [`Context::frame_timings_ui`].
+    history: crate::util::History<FrameTimings>,
+}
+
+impl Default for FrameTimer {
+    fn default() -> Self {
+        Self {
+            last_frame: Default::default(),
+            current: Default::default(),
+            ui_start: None,
+            history: crate::util::History::new(0..300, 2.0),
+        }
+    }
+}
+
+impl FrameTimer {
+    /// There is no [`std::time::Instant`] on the web, so there we don't measure anything.
+    fn now() -> Option<std::time::Instant> {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            Some(std::time::Instant::now())
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            None
+        }
+    }
+
+    fn seconds_since(start: Option<std::time::Instant>) -> f32 {
+        start.map_or(0.0, |start| start.elapsed().as_secs_f32())
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 #[derive(Default)]
 struct ContextImpl {
     /// `None` until the start of the first frame.
@@ -46,25 +93,67 @@ struct ContextImpl {
     graphics: GraphicLayers,
     output: PlatformOutput,
 
+    /// Which layer each run of the last drained shapes belongs to, see [`GraphicLayers::drain`].
+    layer_lengths: Vec<(LayerId, usize)>,
+    tessellation_cache: TessellationCache,
+
     paint_stats: PaintStats,
+    frame_timer: FrameTimer,
 
     /// While positive, keep requesting repaints. Decrement at the end of each frame.
     repaint_requests: u32,
     request_repaint_callbacks: Option<Box<dyn Fn() + Send + Sync>>,
     requested_repaint_last_frame: bool,
+
+    /// Shortest delay requested with [`Context::request_repaint_after`] this frame.
+    repaint_after: Option<std::time::Duration>,
 }
 
 impl ContextImpl {
     fn begin_frame_mut(&mut self, new_raw_input: RawInput) {
         self.memory.begin_frame(&self.input, &new_raw_input);
 
-        self.input = std::mem::take(&mut self.input)
-            .begin_frame(new_raw_input, self.requested_repaint_last_frame);
+        if let Some(native_pixels_per_point) = new_raw_input.pixels_per_point {
+            self.memory.native_pixels_per_point = Some(native_pixels_per_point);
+        }
+
+        if self.memory.options.follow_system_theme {
+            if let Some(dark_mode) = new_raw_input.system_dark_mode {
+                let options = &mut self.memory.options;
+                if options.style.visuals.dark_mode != dark_mode {
+                    let style = std::sync::Arc::make_mut(&mut options.style);
+                    // Remember the current visuals, then switch to the stored ones:
+                    let (old_visuals, new_visuals) = if dark_mode {
+                        (&mut options.light_visuals, &options.dark_visuals)
+                    } else {
+                        (&mut options.dark_visuals, &options.light_visuals)
+                    };
+                    *old_visuals = std::mem::replace(&mut style.visuals, new_visuals.clone());
+                }
+            }
+        }
+
+        self.input = std::mem::take(&mut self.input).begin_frame_with_double_click_delay(
+            new_raw_input,
+            self.requested_repaint_last_frame,
+            self.memory.options.style.interaction.max_double_click_delay,
+        );
 
         if let Some(new_pixels_per_point) = self.memory.new_pixels_per_point.take() {
-            self.input.pixels_per_point = new_pixels_per_point;
+            self.memory.native_pixels_per_point =
+                Some(new_pixels_per_point / self.memory.options.zoom_factor);
         }
 
+        if self.memory.options.zoom_with_keyboard {
+            self.zoom_with_keyboard();
+        }
+
+        let native_pixels_per_point = *self
+            .memory
+            .native_pixels_per_point
+            .get_or_insert(self.input.pixels_per_point);
+        self.input.pixels_per_point = native_pixels_per_point * self.memory.options.zoom_factor;
+
         self.frame_state.begin_frame(&self.input);
 
         self.update_fonts_mut();
@@ -81,6 +170,30 @@ impl ContextImpl {
         );
     }
 
+    /// Handle `Ctrl/Cmd` + `=`/`+`, `-` and `0`.
+    fn zoom_with_keyboard(&mut self) {
+        let mut zoom_factor = self.memory.options.zoom_factor;
+        for modifiers in [Modifiers::COMMAND, Modifiers::COMMAND | Modifiers::SHIFT] {
+            if self.input.consume_key(modifiers, Key::PlusEquals) {
+                zoom_factor += 0.1;
+            }
+            if self.input.consume_key(modifiers, Key::Minus) {
+                zoom_factor -= 0.1;
+            }
+        }
+        if self.input.consume_key(Modifiers::COMMAND, Key::Num0) {
+            zoom_factor = 1.0;
+        }
+
+        // Round to avoid accumulating errors:
+        let zoom_factor = ((zoom_factor * 10.0).round() / 10.0).clamp(0.2, 5.0);
+        if zoom_factor != self.memory.options.zoom_factor {
+            self.memory.options.zoom_factor = zoom_factor;
+            // The integration still used the old scale for this frame:
+            self.repaint_requests = 2;
+        }
+    }
+
     /// Load fonts unless already loaded.
     fn update_fonts_mut(&mut self) {
         let pixels_per_point = self.input.pixels_per_point();
@@ -222,13 +335,21 @@ impl Context {
     /// // handle full_output
     /// ```
     pub fn begin_frame(&self, new_input: RawInput) {
-        self.write().begin_frame_mut(new_input);
+        let start = FrameTimer::now();
+        let ctx = &mut *self.write();
+        ctx.begin_frame_mut(new_input);
+        ctx.frame_timer.current = FrameTimings {
+            begin_frame: FrameTimer::seconds_since(start),
+            ..Default::default()
+        };
+        ctx.frame_timer.ui_start = FrameTimer::now();
     }
 
     // ---------------------------------------------------------------------
 
     /// If the given [`Id`] has been used previously the same frame at at different position,
-    /// then an error will be printed on screen.
+    /// then it is added to [`Self::id_clashes`], and an error will be printed on screen
+    /// (if [`crate::style::DebugOptions::warn_on_id_clash`] is set, which it is by default in debug builds).
     ///
     /// This function is already called for all widgets that do any interaction,
     /// but you can call this from widgets that store state but that does not interact.
@@ -247,41 +368,150 @@ impl Context {
                 return;
             }
 
-            let show_error = |pos: Pos2, text: String| {
-                let painter = self.debug_painter();
-                let rect = painter.error(pos, text);
-                if let Some(pointer_pos) = self.pointer_hover_pos() {
-                    if rect.contains(pointer_pos) {
-                        painter.error(
-                            rect.left_bottom() + vec2(2.0, 4.0),
-                            "ID clashes happens when things like Windows or CollapsingHeaders share names,\n\
-                             or when things like Plot and Grid:s aren't given unique id_source:s.\n\n\
-                             Sometimes the solution is to use ui.push_id.",
-                        );
-                    }
-                }
-            };
+            self.report_id_clash(id, what, prev_rect, new_rect);
+        }
+    }
 
-            let id_str = id.short_debug_format();
+    /// Two [`Area`]s (e.g. two [`Window`]s with the same title) using the same [`Id`] in the same frame
+    /// will share position, size and collapsed state, which is very confusing.
+    ///
+    /// Unlike widgets they are reported even if they are at the same position,
+    /// since that is exactly what happens when they share state.
+    pub(crate) fn check_for_area_id_clash(&self, id: Id, rect: Rect) {
+        let prev_rect = self.frame_state().used_area_ids.insert(id, rect);
+        if let Some(prev_rect) = prev_rect {
+            self.report_id_clash(id, "Area", prev_rect, rect);
+        }
+    }
 
-            if prev_rect.min.distance(new_rect.min) < 4.0 {
-                show_error(
-                    new_rect.min,
-                    format!("Double use of {} ID {}", what, id_str),
-                );
-            } else {
-                show_error(
-                    prev_rect.min,
-                    format!("First use of {} ID {}", what, id_str),
-                );
-                show_error(
-                    new_rect.min,
-                    format!("Second use of {} ID {}", what, id_str),
-                );
+    /// Adds a node to [`PlatformOutput::accessibility_nodes`],
+    /// as a child of the innermost open [accessibility container](Self::begin_accessibility_container).
+    pub(crate) fn push_accessibility_node(&self, mut node: AccessibilityNode) {
+        node.parent = self.frame_state().accessibility_parents.last().copied();
+
+        let mut output = self.output();
+        if let Some(parent) = node.parent {
+            if let Some(parent_node) = output
+                .accessibility_nodes
+                .iter_mut()
+                .rev()
+                .find(|parent_node| parent_node.id == parent)
+            {
+                parent_node.children.push(node.id);
+            }
+        }
+        output.accessibility_nodes.push(node);
+    }
+
+    /// Describe a container, such as a window or a panel, to assistive technologies.
+    ///
+    /// Everything described until the matching [`Self::end_accessibility_container`] becomes its children.
+    ///
+    /// Does nothing unless [`crate::Options::accessibility_tree`] is set.
+    pub(crate) fn begin_accessibility_container(
+        &self,
+        id: Id,
+        make_info: impl FnOnce() -> WidgetInfo,
+    ) {
+        if self.options().accessibility_tree {
+            self.push_accessibility_node(AccessibilityNode {
+                id,
+                rect: Rect::NOTHING, // We don't know the size until `end_accessibility_container`
+                info: make_info(),
+                focused: false,
+                labelled_by: None,
+                parent: None,
+                children: Vec::new(),
+            });
+            self.frame_state().accessibility_parents.push(id);
+        }
+    }
+
+    /// Close the container opened with [`Self::begin_accessibility_container`], now that we know where it is.
+    pub(crate) fn end_accessibility_container(&self, id: Id, rect: Rect) {
+        let is_open = {
+            let accessibility_parents = &mut self.frame_state().accessibility_parents;
+            // Not open if `accessibility_tree` was turned on within the container.
+            let is_open = accessibility_parents.last() == Some(&id);
+            if is_open {
+                accessibility_parents.pop();
+            }
+            is_open
+        };
+
+        if is_open {
+            if let Some(node) = self
+                .output()
+                .accessibility_nodes
+                .iter_mut()
+                .rev()
+                .find(|node| node.id == id)
+            {
+                node.rect = rect;
+            }
+        }
+    }
+
+    fn report_id_clash(&self, id: Id, what: &str, prev_rect: Rect, new_rect: Rect) {
+        self.frame_state().id_clashes.push(IdClash {
+            id,
+            what: what.to_owned(),
+            first_rect: prev_rect,
+            second_rect: new_rect,
+        });
+
+        if !self.style().debug.warn_on_id_clash {
+            return;
+        }
+
+        let show_error = |pos: Pos2, text: String| {
+            let painter = self.debug_painter();
+            let rect = painter.error(pos, text);
+            if let Some(pointer_pos) = self.pointer_hover_pos() {
+                if rect.contains(pointer_pos) {
+                    painter.error(
+                        rect.left_bottom() + vec2(2.0, 4.0),
+                        "ID clashes happens when things like Windows or CollapsingHeaders share names,\n\
+                         or when things like Plot and Grid:s aren't given unique id_source:s.\n\n\
+                         Sometimes the solution is to use ui.push_id,\n\
+                         or Window::id_source for windows with the same title.",
+                    );
+                }
             }
+        };
+
+        let id_str = id.short_debug_format();
+
+        // Outline both uses in the same color, so it is easy to see which ones clash:
+        let painter = self.debug_painter();
+        painter.rect_stroke(prev_rect, 0.0, (1.0, id.color()));
+        painter.rect_stroke(new_rect, 0.0, (1.0, id.color()));
+
+        if prev_rect.min.distance(new_rect.min) < 4.0 {
+            show_error(
+                new_rect.min,
+                format!("Double use of {} ID {}", what, id_str),
+            );
+        } else {
+            show_error(
+                prev_rect.min,
+                format!("First use of {} ID {}", what, id_str),
+            );
+            show_error(
+                new_rect.min,
+                format!("Second use of {} ID {}", what, id_str),
+            );
         }
     }
 
+    /// All the [`Id`] clashes found so far this frame, see [`Self::check_for_id_clash`].
+    ///
+    /// After [`Self::run`] (or [`Self::end_frame`]) this contains all the clashes of that frame,
+    /// so you can e.g. assert that there are none in your tests.
+    pub fn id_clashes(&self) -> Vec<IdClash> {
+        self.frame_state().id_clashes.clone()
+    }
+
     // ---------------------------------------------------------------------
 
     /// Use `ui.interact` instead
@@ -305,7 +535,120 @@ impl Context {
                 .at_most(Vec2::splat(5.0)),
         ); // make it easier to click
         let hovered = self.rect_contains_pointer(layer_id, clip_rect.intersect(interact_rect));
-        self.interact_with_hovered(layer_id, id, rect, sense, enabled, hovered)
+        if hovered {
+            self.inspect_widget(clip_rect, layer_id, id, rect);
+        }
+        let response = self.interact_with_hovered(layer_id, id, rect, sense, enabled, hovered);
+        self.show_keyboard_focus(clip_rect, &response);
+        response
+    }
+
+    /// Use `ui.interact_with_hit_shape` instead
+    pub(crate) fn interact_with_hit_shape(
+        &self,
+        clip_rect: Rect,
+        layer_id: LayerId,
+        id: Id,
+        hit_shape: &HitShape,
+        sense: Sense,
+        enabled: bool,
+    ) -> Response {
+        let rect = hit_shape.bounding_rect();
+        let hovered = self.rect_contains_pointer(layer_id, clip_rect.intersect(rect))
+            && self
+                .input()
+                .pointer
+                .interact_pos()
+                .map_or(false, |pos| hit_shape.contains(pos));
+        if hovered {
+            self.inspect_widget(clip_rect, layer_id, id, rect);
+        }
+        let response = self.interact_with_hovered(layer_id, id, rect, sense, enabled, hovered);
+        self.show_keyboard_focus(clip_rect, &response);
+        response
+    }
+
+    /// Remember a hovered widget for the inspection overlay, if [`crate::style::DebugOptions::inspect_on_hover`] is active.
+    fn inspect_widget(&self, clip_rect: Rect, layer_id: LayerId, id: Id, rect: Rect) {
+        let inspect_on_hover = self.options().style.debug.inspect_on_hover;
+        if let Some(modifiers) = inspect_on_hover {
+            if self.input().modifiers.matches(modifiers) {
+                self.frame_state()
+                    .inspected_widgets
+                    .push(crate::frame_state::InspectedWidget {
+                        id,
+                        rect,
+                        layer_id,
+                        clip_rect,
+                    });
+            }
+        }
+    }
+
+    /// Paint the id, rect, layer and containers of the innermost hovered widget.
+    fn inspection_overlay(&self) {
+        use epaint::util::FloatOrd as _;
+
+        let widgets = std::mem::take(&mut self.frame_state().inspected_widgets);
+        let innermost = widgets.iter().min_by_key(|w| w.rect.area().ord());
+        let widget = if let Some(widget) = innermost {
+            *widget
+        } else {
+            return;
+        };
+
+        // The containers are the other hovered widgets in the same layer, outermost first:
+        let mut containers: Vec<_> = widgets
+            .iter()
+            .filter(|w| {
+                w.id != widget.id
+                    && w.layer_id == widget.layer_id
+                    && w.rect.contains_rect(widget.rect)
+            })
+            .collect();
+        containers.sort_by_key(|w| std::cmp::Reverse(w.rect.area().ord()));
+
+        let mut text = format!(
+            "id:    {}\nrect:  {:?}\nlayer: {}\nclip:  {:?}",
+            widget.id.short_debug_format(),
+            widget.rect,
+            widget.layer_id.short_debug_format(),
+            widget.clip_rect,
+        );
+        for (depth, container) in containers.iter().enumerate() {
+            text += &format!(
+                "\n{}└ {} {:?}",
+                "  ".repeat(depth),
+                container.id.short_debug_format(),
+                container.rect
+            );
+        }
+
+        let painter = self.debug_painter();
+        painter.rect_stroke(widget.clip_rect, 0.0, (1.0, Color32::DARK_GREEN));
+        painter.rect_stroke(widget.rect, 0.0, (1.0, Color32::LIGHT_BLUE));
+        painter.debug_text(
+            widget.rect.left_bottom() + vec2(0.0, 4.0),
+            Align2::LEFT_TOP,
+            Color32::WHITE,
+            text,
+        );
+    }
+
+    fn show_keyboard_focus(&self, clip_rect: Rect, response: &Response) {
+        if response.sense.interactive() && self.memory().has_keyboard_focus(response.id) {
+            // Show the user where the keyboard focus went:
+            let visuals = &self.style().visuals;
+            Painter::new(self.clone(), response.layer_id, clip_rect).rect_stroke(
+                response.rect.expand(2.0),
+                visuals.widgets.active.rounding,
+                visuals.selection.stroke,
+            );
+
+            if self.memory().gained_focus(response.id) {
+                response.scroll_to_me(None);
+            }
+        }
     }
 
     /// You specify if a thing is hovered, and the function gives a [`Response`].
@@ -338,7 +681,7 @@ impl Context {
             changed: false, // must be set by the widget itself
         };
 
-        if !enabled || !sense.focusable || !layer_id.allow_interaction() {
+        if !enabled || !(sense.interactive() || sense.focusable) || !layer_id.allow_interaction() {
             // Not interested or allowed input:
             self.memory().surrender_focus(id);
             return response;
@@ -353,10 +696,10 @@ impl Context {
 
         // We only want to focus labels if the screen reader is on.
         let interested_in_focus =
-            sense.interactive() || sense.focusable && memory.options.screen_reader;
+            sense.focusable && (sense.interactive() || memory.options.screen_reader);
 
         if interested_in_focus {
-            memory.interested_in_focus(id);
+            memory.interested_in_focus(id, rect);
         }
 
         if sense.click
@@ -404,7 +747,7 @@ impl Context {
                             }
                         }
                     }
-                    PointerEvent::Released(click) => {
+                    PointerEvent::Released { click, button: _ } => {
                         response.drag_released = response.dragged;
                         response.dragged = false;
 
@@ -473,11 +816,76 @@ impl Context {
     }
 
     /// Stores superficial widget state.
+    ///
+    /// Custom widgets can keep their own state here, keyed by their [`Id`] and the type of the state,
+    /// the same way the built-in widgets do:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// #[derive(Clone, Default)]
+    /// struct KnobState {
+    ///     turns: u32,
+    /// }
+    ///
+    /// let id = ui.make_persistent_id("my_knob");
+    /// let mut state: KnobState = ui.data().get_temp(id).unwrap_or_default();
+    /// if ui.button(format!("Turned {} times", state.turns)).clicked() {
+    ///     state.turns += 1;
+    /// }
+    /// ui.data().insert_temp(id, state);
+    /// # });
+    /// ```
+    ///
+    /// Use [`crate::util::IdTypeMap::insert_persisted`] for state that should be saved
+    /// with the `persistence` feature.
     #[inline]
     pub fn data(&self) -> RwLockWriteGuard<'_, crate::util::IdTypeMap> {
         RwLockWriteGuard::map(self.write(), |c| &mut c.memory.data)
     }
 
+    /// Serialize the parts of [`Memory`] that should survive a restart of the app to RON,
+    /// e.g. window positions, which collapsing headers are open and scroll offsets.
+    ///
+    /// Transient state, like keyboard focus, open popups, ongoing drags and caches, is not included.
+    ///
+    /// Call this when your app shuts down, and [`Self::load_memory`] when it starts up again.
+    #[cfg(feature = "persistence")]
+    pub fn save_memory(&self) -> Result<String, String> {
+        ron::ser::to_string(&*self.memory()).map_err(|err| err.to_string())
+    }
+
+    /// Restore the state saved with [`Self::save_memory`].
+    ///
+    /// The current [`Style`] is kept, since it is not part of the saved state.
+    #[cfg(feature = "persistence")]
+    pub fn load_memory(&self, ron: &str) -> Result<(), String> {
+        let mut loaded: Memory = ron::from_str(ron).map_err(|err| err.to_string())?;
+        let mut memory = self.memory();
+        loaded.options.style = memory.options.style.clone();
+        *memory = loaded;
+        Ok(())
+    }
+
+    /// Capture the layout state of all windows and widgets,
+    /// e.g. window positions, scroll offsets and which collapsing headers are open.
+    ///
+    /// Use [`Self::restore`] to go back to it later.
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// let snapshot = ctx.snapshot();
+    /// // … the user moves some windows around …
+    /// ctx.restore(snapshot);
+    /// ```
+    pub fn snapshot(&self) -> crate::UiSnapshot {
+        self.memory().snapshot()
+    }
+
+    /// Go back to the state captured by [`Self::snapshot`].
+    pub fn restore(&self, snapshot: crate::UiSnapshot) {
+        self.memory().restore(snapshot);
+    }
+
     #[inline]
     pub(crate) fn graphics(&self) -> RwLockWriteGuard<'_, GraphicLayers> {
         RwLockWriteGuard::map(self.write(), |c| &mut c.graphics)
@@ -494,6 +902,59 @@ impl Context {
         RwLockWriteGuard::map(self.write(), |c| &mut c.output)
     }
 
+    /// Put the given text in the system clipboard at the end of the frame.
+    ///
+    /// Shortcut for `ctx.output().copied_text = text`.
+    /// The clipboard contents are sent back to egui as [`Event::Paste`] when the user pastes.
+    pub fn copy_text(&self, text: impl Into<String>) {
+        self.output().copied_text = text.into();
+    }
+
+    /// Ask the backend to capture what is shown on screen at the end of this frame.
+    ///
+    /// The backend will send the result back as an [`Event::Screenshot`] next frame.
+    ///
+    /// Not all backends support this. It works in `eframe` when running natively with the `glow` renderer,
+    /// in `egui_glow::EguiGlow`, `egui_glow::EguiSdl2` and in `egui::Harness`.
+    /// `eframe` with the `wgpu` renderer, `eframe` on the web and `egui_glium` log a warning instead,
+    /// and you will never get an [`Event::Screenshot`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// if ui.button("📷 Take screenshot").clicked() {
+    ///     ui.ctx().request_screenshot();
+    /// }
+    /// for event in &ui.input().events {
+    ///     if let egui::Event::Screenshot(image) = event {
+    ///         // Save or show `image`…
+    ///     }
+    /// }
+    /// # });
+    /// ```
+    pub fn request_screenshot(&self) {
+        self.output().screenshot_requested = true;
+    }
+
+    /// Tell the backend where the text cursor of a widget taking text input is,
+    /// so it can place the IME candidate window (e.g. for Chinese or Japanese input) next to it.
+    ///
+    /// Call this each frame the widget has keyboard focus and can be edited.
+    /// [`crate::TextEdit`] does this for you.
+    ///
+    /// Sets [`PlatformOutput::text_cursor_pos`] and [`Self::text_input_owner`].
+    pub fn set_text_cursor(&self, owner: Id, cursor_pos: Pos2) {
+        self.frame_state().text_input_owner = Some(owner);
+        self.output().text_cursor_pos = Some(cursor_pos);
+    }
+
+    /// The widget that takes text input this frame (e.g. a focused [`crate::TextEdit`]), if any.
+    ///
+    /// This is set by [`Self::set_text_cursor`] while the widget is shown,
+    /// so it is only known for sure once the frame has ended.
+    pub fn text_input_owner(&self) -> Option<Id> {
+        self.frame_state().text_input_owner
+    }
+
     #[inline]
     pub(crate) fn frame_state(&self) -> RwLockWriteGuard<'_, FrameState> {
         RwLockWriteGuard::map(self.write(), |c| &mut c.frame_state)
@@ -550,6 +1011,16 @@ impl Context {
     }
 
     /// Change the options used by the tessellator.
+    ///
+    /// This is where you can turn off anti-aliasing (feathering) on low-end targets,
+    /// or snap rectangles to the pixel grid to get crisp one pixel wide lines:
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// let mut options = ctx.tessellation_options();
+    /// options.feathering = false;
+    /// options.round_rects_to_pixels = true;
+    /// ```
     #[inline]
     pub fn tessellation_options(&self) -> RwLockWriteGuard<'_, TessellationOptions> {
         RwLockWriteGuard::map(self.write(), |c| &mut c.memory.options.tessellation_options)
@@ -574,6 +1045,27 @@ impl Context {
         }
     }
 
+    /// Request a repaint at most after the given duration, unless something else causes a repaint sooner.
+    ///
+    /// This is useful for e.g. a clock or a "last saved 5 minutes ago" label,
+    /// which needs to be updated now and then but not every frame.
+    /// The backend can then sleep until the next input event or until the duration has passed,
+    /// whichever comes first (see [`crate::FullOutput::repaint_after`]).
+    ///
+    /// If called several times in the same frame, the shortest duration is used.
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// ctx.request_repaint_after(std::time::Duration::from_secs(1));
+    /// ```
+    pub fn request_repaint_after(&self, duration: std::time::Duration) {
+        let mut ctx = self.write();
+        ctx.repaint_after = Some(match ctx.repaint_after {
+            Some(repaint_after) => repaint_after.min(duration),
+            None => duration,
+        });
+    }
+
     /// For integrations: this callback will be called when an egui user calls [`Self::request_repaint`].
     ///
     /// This lets you wake up a sleeping UI thread.
@@ -643,6 +1135,8 @@ impl Context {
     ///
     /// Note that this may be overwritten by input from the integration via [`RawInput::pixels_per_point`].
     /// For instance, when using `eframe` on web, the browsers native zoom level will always be used.
+    ///
+    /// To let the user scale the UI, prefer [`Self::set_zoom_factor`].
     pub fn set_pixels_per_point(&self, pixels_per_point: f32) {
         if pixels_per_point != self.pixels_per_point() {
             self.request_repaint();
@@ -651,6 +1145,28 @@ impl Context {
         self.memory().new_pixels_per_point = Some(pixels_per_point);
     }
 
+    /// The user zoom factor on top of the native scale of the screen, see [`Self::set_zoom_factor`].
+    #[inline]
+    pub fn zoom_factor(&self) -> f32 {
+        self.options().zoom_factor
+    }
+
+    /// Scale the whole UI by this factor, on top of the native scale of the screen.
+    /// Will become active at the start of the next frame.
+    ///
+    /// Unlike [`Self::set_pixels_per_point`] this is kept when the integration reports
+    /// a new native scale, e.g. when the window is moved to another monitor.
+    ///
+    /// By default the user can also change this with `Ctrl/Cmd` + `=`/`+`, `-` and `0`,
+    /// see `ctx.memory().options.zoom_with_keyboard`.
+    pub fn set_zoom_factor(&self, zoom_factor: f32) {
+        if zoom_factor != self.zoom_factor() {
+            self.request_repaint();
+        }
+
+        self.options().zoom_factor = zoom_factor;
+    }
+
     /// Useful for pixel-perfect rendering
     pub(crate) fn round_to_pixel(&self, point: f32) -> f32 {
         let pixels_per_point = self.pixels_per_point();
@@ -780,6 +1296,15 @@ impl Context {
     /// Call at the end of each frame.
     #[must_use]
     pub fn end_frame(&self) -> FullOutput {
+        self.shortcut_help_overlay();
+        self.inspection_overlay();
+
+        let start = FrameTimer::now();
+        {
+            let frame_timer = &mut self.write().frame_timer;
+            frame_timer.current.ui = FrameTimer::seconds_since(frame_timer.ui_start.take());
+        }
+
         if self.input().wants_repaint() {
             self.request_repaint();
         }
@@ -803,7 +1328,8 @@ impl Context {
             textures_delta = ctx_impl.tex_manager.0.write().take_delta();
         };
 
-        let platform_output: PlatformOutput = std::mem::take(&mut self.output());
+        let mut platform_output: PlatformOutput = std::mem::take(&mut self.output());
+        platform_output.input_latency = self.input().input_latency;
 
         let needs_repaint = if self.read().repaint_requests > 0 {
             self.write().repaint_requests -= 1;
@@ -813,48 +1339,121 @@ impl Context {
         };
         self.write().requested_repaint_last_frame = needs_repaint;
 
+        let repaint_after = self.write().repaint_after.take();
+        let repaint_after = if needs_repaint {
+            std::time::Duration::ZERO
+        } else {
+            repaint_after.unwrap_or(std::time::Duration::MAX)
+        };
+
         let shapes = self.drain_paint_lists();
 
+        let text_layout = self.fonts().layout_time().as_secs_f32();
+        let time = self.input().time;
+        let frame_timings = {
+            let frame_timer = &mut self.write().frame_timer;
+            let previous_frame = frame_timer.last_frame;
+            frame_timer.current.text_layout = text_layout;
+            frame_timer.current.end_frame = FrameTimer::seconds_since(start);
+            frame_timer.last_frame = frame_timer.current;
+            frame_timer.history.add(time, frame_timer.current);
+            previous_frame
+        };
+
         FullOutput {
             platform_output,
             needs_repaint,
+            repaint_after,
             textures_delta,
             shapes,
+            frame_timings,
         }
     }
 
     fn drain_paint_lists(&self) -> Vec<ClippedShape> {
         let ctx_impl = &mut *self.write();
+        let mut order = ctx_impl.memory.areas.order().to_vec();
+        // In reverse, so that later sublayers of the same parent end up on top:
+        for &(parent, child) in ctx_impl.frame_state.sublayers.iter().rev() {
+            if let Some(index) = order.iter().position(|&layer_id| layer_id == parent) {
+                order.insert(index + 1, child);
+            }
+        }
+        ctx_impl.layer_lengths.clear();
         ctx_impl
             .graphics
-            .drain(ctx_impl.memory.areas.order())
+            .drain(&order, &mut ctx_impl.layer_lengths)
             .collect()
     }
 
     /// Tessellate the given shapes into triangle meshes.
+    ///
+    /// If [`Options::cache_tessellation`] is set, layers (e.g. windows) whose shapes are
+    /// the same as last frame reuse last frame's tessellation.
+    /// This only works when `shapes` are the unmodified [`FullOutput::shapes`] of the last frame.
+    ///
+    /// To tessellate shapes without a [`Context`], see [`epaint::tessellate`].
     pub fn tessellate(&self, shapes: Vec<ClippedShape>) -> Vec<ClippedPrimitive> {
-        // A tempting optimization is to reuse the tessellation from last frame if the
-        // shapes are the same, but just comparing the shapes takes about 50% of the time
-        // it takes to tessellate them, so it is not a worth optimization.
+        // Comparing the shapes of a layer takes about 50% of the time it takes to tessellate them,
+        // so caching is only a win when most layers stay the same, which is why it is opt-in.
 
         let pixels_per_point = self.pixels_per_point();
         let tessellation_options = *self.tessellation_options();
+        let cache_tessellation = self.options().cache_tessellation;
         let texture_atlas = self.fonts().texture_atlas();
         let font_tex_size = texture_atlas.lock().size();
         let prepared_discs = texture_atlas.lock().prepared_discs();
 
+        let start = FrameTimer::now();
         let paint_stats = PaintStats::from_shapes(&shapes);
-        let clipped_primitives = tessellator::tessellate_shapes(
-            pixels_per_point,
-            tessellation_options,
-            font_tex_size,
-            prepared_discs,
-            shapes,
-        );
-        self.write().paint_stats = paint_stats.with_clipped_primitives(&clipped_primitives);
+        let clipped_primitives = if cache_tessellation {
+            let (mut cache, layer_lengths) = {
+                let ctx = &mut *self.write();
+                (
+                    std::mem::take(&mut ctx.tessellation_cache),
+                    std::mem::take(&mut ctx.layer_lengths),
+                )
+            };
+            let clipped_primitives = cache.tessellate(
+                pixels_per_point,
+                tessellation_options,
+                font_tex_size,
+                prepared_discs,
+                &layer_lengths,
+                shapes,
+            );
+            self.write().tessellation_cache = cache;
+            clipped_primitives
+        } else {
+            self.write().tessellation_cache = Default::default();
+            tessellator::tessellate_shapes(
+                pixels_per_point,
+                tessellation_options,
+                font_tex_size,
+                prepared_discs,
+                shapes,
+            )
+        };
+
+        let ctx = &mut *self.write();
+        ctx.paint_stats = paint_stats.with_clipped_primitives(&clipped_primitives);
+
+        let tessellation = FrameTimer::seconds_since(start);
+        ctx.frame_timer.last_frame.tessellation = tessellation;
+        if let Some(latest) = ctx.frame_timer.history.latest_mut() {
+            latest.tessellation = tessellation;
+        }
+
         clipped_primitives
     }
 
+    /// How long the different stages of the last frame took.
+    ///
+    /// See also [`Self::frame_timings_ui`].
+    pub fn frame_timings(&self) -> FrameTimings {
+        self.read().frame_timer.last_frame
+    }
+
     // ---------------------------------------------------------------------
 
     /// How much space is used by panels and windows.
@@ -911,6 +1510,105 @@ impl Context {
     pub fn wants_keyboard_input(&self) -> bool {
         self.memory().interaction.focus.focused().is_some()
     }
+
+    /// List a keyboard shortcut in the shortcut help overlay.
+    ///
+    /// Call this every frame the shortcut is available.
+    /// If any shortcuts are registered, pressing `?` (when no widget has keyboard focus)
+    /// toggles an overlay listing all of them, grouped by `category`.
+    ///
+    /// This does not check if the shortcut is pressed; use [`InputState::consume_shortcut`] for that.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// use egui::{Key, KeyboardShortcut, Modifiers};
+    /// const SAVE: KeyboardShortcut = KeyboardShortcut::new(Modifiers::COMMAND, Key::S);
+    /// ctx.register_shortcut("File", SAVE, "Save the document");
+    /// if ctx.input_mut().consume_shortcut(&SAVE) {
+    ///     // save…
+    /// }
+    /// # });
+    /// ```
+    pub fn register_shortcut(
+        &self,
+        category: impl Into<String>,
+        shortcut: KeyboardShortcut,
+        description: impl Into<String>,
+    ) {
+        let registered = crate::frame_state::RegisteredShortcut {
+            category: category.into(),
+            shortcut,
+            description: description.into(),
+        };
+        let mut frame_state = self.frame_state();
+        if !frame_state.shortcuts.contains(&registered) {
+            frame_state.shortcuts.push(registered);
+        }
+    }
+
+    /// Show the shortcut help overlay if the user toggled it with `?`.
+    fn shortcut_help_overlay(&self) {
+        let shortcuts = std::mem::take(&mut self.frame_state().shortcuts);
+        if shortcuts.is_empty() {
+            return;
+        }
+
+        let id = Id::new("__shortcut_help");
+        let mut open = self.data().get_temp::<bool>(id).unwrap_or(false);
+        if !self.wants_keyboard_input() {
+            let toggled = self
+                .input()
+                .events
+                .iter()
+                .any(|event| matches!(event, Event::Text(text) if text == "?"));
+            if toggled {
+                open = !open;
+            } else if open && self.input().key_pressed(Key::Escape) {
+                open = false;
+            }
+        }
+
+        if open {
+            // Group by category, in the order they were first registered:
+            let mut categories: Vec<(&str, Vec<&crate::frame_state::RegisteredShortcut>)> = vec![];
+            for shortcut in &shortcuts {
+                if let Some((_, group)) = categories
+                    .iter_mut()
+                    .find(|(category, _)| *category == shortcut.category)
+                {
+                    group.push(shortcut);
+                } else {
+                    categories.push((&shortcut.category, vec![shortcut]));
+                }
+            }
+
+            let is_mac = cfg!(target_os = "macos");
+            crate::Window::new("⌨ Keyboard shortcuts")
+                .id(id)
+                .open(&mut open)
+                .collapsible(false)
+                .resizable(false)
+                .anchor(Align2::CENTER_CENTER, Vec2::ZERO)
+                .show(self, |ui| {
+                    for (category, group) in categories {
+                        ui.strong(category);
+                        crate::Grid::new(id.with(category))
+                            .striped(true)
+                            .show(ui, |ui| {
+                                for registered in group {
+                                    ui.monospace(registered.shortcut.format(is_mac));
+                                    ui.label(&registered.description);
+                                    ui.end_row();
+                                }
+                            });
+                        ui.add_space(8.0);
+                    }
+                    ui.weak("Press ? to close");
+                });
+        }
+
+        self.data().insert_temp(id, open);
+    }
 }
 
 // Ergonomic methods to forward some calls often used in 'if let' without holding the borrow
@@ -965,13 +1663,50 @@ impl Context {
         self.memory().areas.move_to_top(layer_id);
     }
 
+    /// Paint the `child` layer right on top of the `parent` layer this frame.
+    ///
+    /// Widgets in the child layer can be interacted with wherever the parent layer can.
+    pub(crate) fn set_sublayer(&self, parent: LayerId, child: LayerId) {
+        self.frame_state().sublayers.push((parent, child));
+    }
+
+    /// The pinned contents of a [`crate::Ui::sticky`] cover `rect` in the `parent` layer this frame.
+    pub(crate) fn set_pinned_sticky_rect(&self, parent: LayerId, rect: Rect) {
+        self.frame_state().pinned_sticky_rects.push((parent, rect));
+    }
+
     pub(crate) fn rect_contains_pointer(&self, layer_id: LayerId, rect: Rect) -> bool {
-        let pointer_pos = self.input().pointer.interact_pos();
-        if let Some(pointer_pos) = pointer_pos {
-            rect.contains(pointer_pos) && self.layer_id_at(pointer_pos) == Some(layer_id)
+        let pointer_pos = if let Some(pointer_pos) = self.input().pointer.interact_pos() {
+            pointer_pos
         } else {
-            false
-        }
+            return false;
+        };
+
+        let (parent, covered_by_sticky) = {
+            let frame_state = self.frame_state();
+            let parent = frame_state
+                .sublayers
+                .iter()
+                .find(|(_, child)| *child == layer_id)
+                .map(|(parent, _)| *parent);
+            // Containers around the pinned contents (e.g. the scroll area) reach above and below it,
+            // and still get the pointer:
+            let covered_by_sticky =
+                frame_state
+                    .pinned_sticky_rects
+                    .iter()
+                    .any(|(sticky_parent, sticky_rect)| {
+                        *sticky_parent == layer_id
+                            && sticky_rect.contains(pointer_pos)
+                            && !(rect.top() <= sticky_rect.top()
+                                && sticky_rect.bottom() <= rect.bottom())
+                    });
+            (parent, covered_by_sticky)
+        };
+        let layer_id = parent.unwrap_or(layer_id);
+        rect.contains(pointer_pos)
+            && !covered_by_sticky
+            && self.layer_id_at(pointer_pos) == Some(layer_id)
     }
 
     // ---------------------------------------------------------------------
@@ -1022,7 +1757,17 @@ impl Context {
 
     /// Allows you to smoothly change the f32 value.
     /// At the first call the value is written to memory.
-    /// When it is called with a new value, it linearly interpolates to it in the given time.
+    /// When it is called with a new value, it linearly interpolates to it.
+    ///
+    /// The function will call [`Self::request_repaint()`] while animating.
+    ///
+    /// The animation time is taken from [`Style::animation_time`].
+    pub fn animate_value(&self, id: Id, value: f32) -> f32 {
+        let animation_time = self.style().animation_time;
+        self.animate_value_with_time(id, value, animation_time)
+    }
+
+    /// Like [`Self::animate_value`] but allows you to control the animation time.
     pub fn animate_value_with_time(&self, id: Id, value: f32, animation_time: f32) -> f32 {
         let animated_value = {
             let ctx_impl = &mut *self.write();
@@ -1061,6 +1806,13 @@ impl Context {
                 tessellation_options.ui(ui);
                 ui.vertical_centered(|ui| reset_button(ui, &mut tessellation_options));
                 *self.tessellation_options() = tessellation_options;
+
+                let mut cache_tessellation = self.options().cache_tessellation;
+                ui.checkbox(&mut cache_tessellation, "Cache tessellation")
+                    .on_hover_text(
+                        "Reuse the tessellation of windows that did not change since last frame",
+                    );
+                self.options().cache_tessellation = cache_tessellation;
             });
     }
 
@@ -1111,11 +1863,22 @@ impl Context {
         .on_hover_text("This is approximately the number of text strings on screen");
         ui.add_space(16.0);
 
+        CollapsingHeader::new("🗂 Layers")
+            .default_open(false)
+            .show(ui, |ui| {
+                self.layers_ui(ui);
+            });
+
         CollapsingHeader::new("📥 Input")
             .default_open(false)
             .show(ui, |ui| {
-                let input = ui.input().clone();
-                input.ui(ui);
+                self.input_ui(ui);
+            });
+
+        CollapsingHeader::new("⏱ Frame timings")
+            .default_open(false)
+            .show(ui, |ui| {
+                self.frame_timings_ui(ui);
             });
 
         CollapsingHeader::new("📊 Paint stats")
@@ -1134,11 +1897,79 @@ impl Context {
         CollapsingHeader::new("🔠 Font texture")
             .default_open(false)
             .show(ui, |ui| {
-                let font_image_size = self.fonts().font_image_size();
-                crate::introspection::font_texture_ui(ui, font_image_size);
+                self.font_atlas_ui(ui);
             });
     }
 
+    /// Show the input of this frame: the [`RawInput`], the pointer state, modifiers, events etc.
+    ///
+    /// ```
+    /// # egui::__run_test_ctx(|ctx| {
+    /// egui::Window::new("📥 Input").show(ctx, |ui| ctx.input_ui(ui));
+    /// # });
+    /// ```
+    pub fn input_ui(&self, ui: &mut Ui) {
+        let input = self.input().clone();
+        input.ui(ui);
+    }
+
+    /// Show the texture atlas with all the glyphs that have been rendered so far.
+    ///
+    /// Hover it to zoom in.
+    pub fn font_atlas_ui(&self, ui: &mut Ui) {
+        let font_image_size = self.fonts().font_image_size();
+        crate::introspection::font_texture_ui(ui, font_image_size);
+    }
+
+    /// Show all areas (panels, windows, popups, …) as a tree, grouped by [`Order`] and back to front.
+    ///
+    /// Hover a layer to highlight it.
+    pub fn layers_ui(&self, ui: &mut Ui) {
+        let layer_ids: Vec<LayerId> = self.memory().areas.order().to_vec();
+        for order in Order::ALL {
+            let layer_ids: Vec<LayerId> = layer_ids
+                .iter()
+                .copied()
+                .filter(|layer_id| layer_id.order == order)
+                .collect();
+            if layer_ids.is_empty() {
+                continue;
+            }
+
+            crate::CollapsingHeader::new(format!("{:?} ({})", order, layer_ids.len()))
+                .id_source(("layers_ui", order))
+                .default_open(true)
+                .show(ui, |ui| {
+                    for layer_id in layer_ids {
+                        let area = self.memory().areas.get(layer_id.id).cloned();
+                        let is_visible = self.memory().areas.is_visible(&layer_id);
+                        let rect = area.map_or(Rect::NOTHING, |area| area.rect());
+                        let text = format!(
+                            "{} {} {:?}",
+                            if is_visible { "👁" } else { "  " },
+                            layer_id.id.short_debug_format(),
+                            rect
+                        );
+                        let response = ui
+                            .add(Label::new(RichText::new(text).monospace()).sense(Sense::hover()));
+                        if response.hovered() && is_visible {
+                            self.debug_painter().debug_rect(
+                                rect,
+                                Color32::RED,
+                                layer_id.short_debug_format(),
+                            );
+                        }
+                    }
+                });
+        }
+    }
+
+    /// Show how long the different stages of recent frames took, with a rolling graph.
+    pub fn frame_timings_ui(&self, ui: &mut Ui) {
+        let history: Vec<(f64, FrameTimings)> = self.read().frame_timer.history.iter().collect();
+        crate::introspection::frame_timings_ui(ui, &self.frame_timings(), &history);
+    }
+
     /// Show stats about the allocated textures.
     pub fn texture_ui(&self, ui: &mut crate::Ui) {
         let tex_mngr = self.tex_manager();
@@ -1196,6 +2027,9 @@ impl Context {
         });
     }
 
+    /// Show what is stored in [`Memory`]: areas, collapsing headers, scroll areas, the active and focused widgets, …
+    ///
+    /// Each kind of state can be reset separately.
     pub fn memory_ui(&self, ui: &mut crate::Ui) {
         if ui
             .button("Reset all")
@@ -1211,6 +2045,12 @@ impl Context {
             "{} widget states stored (of which {} are serialized).",
             num_state, num_serialized
         ));
+        if self.options().gc_after_frames.is_some() {
+            ui.label(format!(
+                "{} unused states and areas forgotten.",
+                self.memory().num_gc_forgotten
+            ));
+        }
 
         ui.horizontal(|ui| {
             ui.label(format!(
@@ -1305,6 +2145,33 @@ impl Context {
         style.ui(ui);
         self.set_style(style);
     }
+
+    /// Show a window where the user can edit the [`Style`] live.
+    ///
+    /// With the `persistence` feature there is also a button to copy the style as RON,
+    /// so you can save your tweaks and load them later (e.g. with `ron::from_str`).
+    pub fn style_editor_window(&self, open: &mut bool) {
+        crate::Window::new("🎨 Style editor")
+            .open(open)
+            .vscroll(true)
+            .show(self, |ui| {
+                self.style_ui(ui);
+
+                #[cfg(feature = "persistence")]
+                ui.vertical_centered(|ui| {
+                    if ui
+                        .button("📋 Copy as RON")
+                        .on_hover_text("Copy the style to the clipboard")
+                        .clicked()
+                    {
+                        let style = self.style();
+                        if let Ok(ron) = ron::ser::to_string_pretty(&*style, Default::default()) {
+                            self.copy_text(ron);
+                        }
+                    }
+                });
+            });
+    }
 }
 
 #[cfg(test)]
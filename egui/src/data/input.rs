@@ -35,10 +35,25 @@ pub struct RawInput {
     /// The default is a very small (but very portable) 2048.
     pub max_texture_side: Option<usize>,
 
+    /// Does the operating system (or browser) prefer a dark theme?
+    ///
+    /// `None` will be treated as "same as last frame".
+    /// If `ctx.memory().options.follow_system_theme` is set, egui will switch theme to match.
+    pub system_dark_mode: Option<bool>,
+
     /// Monotonically increasing time, in seconds. Relative to whatever. Used for animations.
     /// If `None` is provided, egui will assume a time delta of `predicted_dt` (default 1/60 seconds).
+    ///
+    /// This is the only clock egui uses for animations, tooltip delays, double-clicks etc,
+    /// so by controlling it you can make egui fully deterministic, e.g. in tests (see `egui::Harness`).
     pub time: Option<f64>,
 
+    /// When the backend received the oldest of the [`Self::events`], on the same clock as [`Self::time`].
+    ///
+    /// If set, egui will report the input latency in [`crate::InputState::input_latency`]
+    /// and [`crate::PlatformOutput::input_latency`].
+    pub oldest_event_time: Option<f64>,
+
     /// Should be set to the expected time between frames when painting at vsync speeds.
     /// The default for this is 1/60.
     /// Can safely be left at its default value.
@@ -49,6 +64,9 @@ pub struct RawInput {
 
     /// In-order events received this frame.
     ///
+    /// Send all events that happened since last frame, not just the latest state,
+    /// so that e.g. quick clicks and fast typing are not lost at low frame rates.
+    ///
     /// There is currently no way to know if egui handles a particular event,
     /// but you can check if egui is using the keyboard with [`crate::Context::wants_keyboard_input`]
     /// and/or the pointer (mouse/touch) with [`crate::Context::is_using_pointer`].
@@ -70,7 +88,9 @@ impl Default for RawInput {
             screen_rect: None,
             pixels_per_point: None,
             max_texture_side: None,
+            system_dark_mode: None,
             time: None,
+            oldest_event_time: None,
             predicted_dt: 1.0 / 60.0,
             modifiers: Modifiers::default(),
             events: vec![],
@@ -90,7 +110,9 @@ impl RawInput {
             screen_rect: self.screen_rect.take(),
             pixels_per_point: self.pixels_per_point.take(),
             max_texture_side: self.max_texture_side.take(),
+            system_dark_mode: self.system_dark_mode.take(),
             time: self.time.take(),
+            oldest_event_time: self.oldest_event_time.take(),
             predicted_dt: self.predicted_dt,
             modifiers: self.modifiers,
             events: std::mem::take(&mut self.events),
@@ -105,7 +127,9 @@ impl RawInput {
             screen_rect,
             pixels_per_point,
             max_texture_side,
+            system_dark_mode,
             time,
+            oldest_event_time,
             predicted_dt,
             modifiers,
             mut events,
@@ -116,7 +140,9 @@ impl RawInput {
         self.screen_rect = screen_rect.or(self.screen_rect);
         self.pixels_per_point = pixels_per_point.or(self.pixels_per_point);
         self.max_texture_side = max_texture_side.or(self.max_texture_side);
+        self.system_dark_mode = system_dark_mode.or(self.system_dark_mode);
         self.time = time; // use latest time
+        self.oldest_event_time = self.oldest_event_time.or(oldest_event_time);
         self.predicted_dt = predicted_dt; // use latest dt
         self.modifiers = modifiers; // use latest
         self.events.append(&mut events);
@@ -241,6 +267,9 @@ pub enum Event {
         /// The value is in the range from 0.0 (no pressure) to 1.0 (maximum pressure).
         force: f32,
     },
+
+    /// A capture of the screen, as requested with [`crate::Context::request_screenshot`].
+    Screenshot(std::sync::Arc<crate::ColorImage>),
 }
 
 /// Mouse button (or similar for touch input)
@@ -266,7 +295,7 @@ pub const NUM_POINTER_BUTTONS: usize = 5;
 /// State of the modifier keys. These must be fed to egui.
 ///
 /// The best way to compare [`Modifiers`] is by using [`Modifiers::matches`].
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Modifiers {
     /// Either of the alt keys are down (option ⌥ on Mac).
@@ -423,6 +452,64 @@ impl std::ops::BitOr for Modifiers {
     }
 }
 
+// ----------------------------------------------------------------------------
+
+/// A keyboard shortcut, e.g. `Ctrl+Alt+W`.
+///
+/// Can be used with [`crate::InputState::consume_shortcut`]
+/// and shown next to a menu item with [`crate::Button::shortcut_text`].
+///
+/// ```
+/// # use egui::{Key, KeyboardShortcut, Modifiers};
+/// let save = KeyboardShortcut::new(Modifiers::COMMAND | Modifiers::SHIFT, Key::S);
+/// assert_eq!(save.format(false), "Ctrl+Shift+S");
+/// assert_eq!(save.format(true), "Cmd+Shift+S");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct KeyboardShortcut {
+    pub modifiers: Modifiers,
+    pub key: Key,
+}
+
+impl KeyboardShortcut {
+    pub const fn new(modifiers: Modifiers, key: Key) -> Self {
+        Self { modifiers, key }
+    }
+
+    /// Human-readable description of the shortcut, e.g. `"Ctrl+Shift+S"`.
+    ///
+    /// `is_mac` selects the Mac names of the modifier keys (`Cmd`, `Option`).
+    pub fn format(&self, is_mac: bool) -> String {
+        let Modifiers {
+            alt,
+            ctrl,
+            shift,
+            mac_cmd,
+            command,
+        } = self.modifiers;
+
+        let mut names = vec![];
+        if is_mac && (mac_cmd || command) {
+            names.push("Cmd");
+        }
+        if ctrl || (command && !is_mac) {
+            names.push("Ctrl");
+        }
+        if alt {
+            names.push(if is_mac { "Option" } else { "Alt" });
+        }
+        if shift {
+            names.push("Shift");
+        }
+        if !is_mac && mac_cmd {
+            names.push("Cmd");
+        }
+        names.push(self.key.name());
+        names.join("+")
+    }
+}
+
 /// Keyboard keys.
 ///
 /// Includes all keys egui is interested in (such as `Home` and `End`)
@@ -451,6 +538,11 @@ pub enum Key {
     PageUp,
     PageDown,
 
+    /// The `-` key, either from the main row or from the numpad.
+    Minus,
+    /// The `=`/`+` key, either from the main row or the `+` from the numpad.
+    PlusEquals,
+
     /// Either from the main row or from the numpad.
     Num0,
     /// Either from the main row or from the numpad.
@@ -522,13 +614,187 @@ pub enum Key {
     F20,
 }
 
+impl Key {
+    /// Human-readable English name of the key, e.g. `"Enter"`, `"PageUp"`, `"A"` or `"F5"`.
+    ///
+    /// Suitable for showing keyboard shortcuts to the user.
+    /// The inverse of [`Self::from_name`].
+    pub fn name(&self) -> &'static str {
+        match self {
+            Key::ArrowDown => "Down",
+            Key::ArrowLeft => "Left",
+            Key::ArrowRight => "Right",
+            Key::ArrowUp => "Up",
+            Key::Escape => "Escape",
+            Key::Tab => "Tab",
+            Key::Backspace => "Backspace",
+            Key::Enter => "Enter",
+            Key::Space => "Space",
+            Key::Insert => "Insert",
+            Key::Delete => "Delete",
+            Key::Home => "Home",
+            Key::End => "End",
+            Key::PageUp => "PageUp",
+            Key::PageDown => "PageDown",
+            Key::Minus => "Minus",
+            Key::PlusEquals => "Plus",
+            Key::Num0 => "0",
+            Key::Num1 => "1",
+            Key::Num2 => "2",
+            Key::Num3 => "3",
+            Key::Num4 => "4",
+            Key::Num5 => "5",
+            Key::Num6 => "6",
+            Key::Num7 => "7",
+            Key::Num8 => "8",
+            Key::Num9 => "9",
+            Key::A => "A",
+            Key::B => "B",
+            Key::C => "C",
+            Key::D => "D",
+            Key::E => "E",
+            Key::F => "F",
+            Key::G => "G",
+            Key::H => "H",
+            Key::I => "I",
+            Key::J => "J",
+            Key::K => "K",
+            Key::L => "L",
+            Key::M => "M",
+            Key::N => "N",
+            Key::O => "O",
+            Key::P => "P",
+            Key::Q => "Q",
+            Key::R => "R",
+            Key::S => "S",
+            Key::T => "T",
+            Key::U => "U",
+            Key::V => "V",
+            Key::W => "W",
+            Key::X => "X",
+            Key::Y => "Y",
+            Key::Z => "Z",
+            Key::F1 => "F1",
+            Key::F2 => "F2",
+            Key::F3 => "F3",
+            Key::F4 => "F4",
+            Key::F5 => "F5",
+            Key::F6 => "F6",
+            Key::F7 => "F7",
+            Key::F8 => "F8",
+            Key::F9 => "F9",
+            Key::F10 => "F10",
+            Key::F11 => "F11",
+            Key::F12 => "F12",
+            Key::F13 => "F13",
+            Key::F14 => "F14",
+            Key::F15 => "F15",
+            Key::F16 => "F16",
+            Key::F17 => "F17",
+            Key::F18 => "F18",
+            Key::F19 => "F19",
+            Key::F20 => "F20",
+        }
+    }
+
+    /// Parse a key from its [`Self::name`].
+    ///
+    /// ```
+    /// # use egui::Key;
+    /// assert_eq!(Key::from_name("PageUp"), Some(Key::PageUp));
+    /// assert_eq!(Key::from_name(Key::F12.name()), Some(Key::F12));
+    /// assert_eq!(Key::from_name("7"), Some(Key::Num7));
+    /// assert_eq!(Key::from_name("Hyper"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "Down" => Key::ArrowDown,
+            "Left" => Key::ArrowLeft,
+            "Right" => Key::ArrowRight,
+            "Up" => Key::ArrowUp,
+            "Escape" => Key::Escape,
+            "Tab" => Key::Tab,
+            "Backspace" => Key::Backspace,
+            "Enter" => Key::Enter,
+            "Space" => Key::Space,
+            "Insert" => Key::Insert,
+            "Delete" => Key::Delete,
+            "Home" => Key::Home,
+            "End" => Key::End,
+            "PageUp" => Key::PageUp,
+            "PageDown" => Key::PageDown,
+            "Minus" => Key::Minus,
+            "Plus" => Key::PlusEquals,
+            "0" => Key::Num0,
+            "1" => Key::Num1,
+            "2" => Key::Num2,
+            "3" => Key::Num3,
+            "4" => Key::Num4,
+            "5" => Key::Num5,
+            "6" => Key::Num6,
+            "7" => Key::Num7,
+            "8" => Key::Num8,
+            "9" => Key::Num9,
+            "A" => Key::A,
+            "B" => Key::B,
+            "C" => Key::C,
+            "D" => Key::D,
+            "E" => Key::E,
+            "F" => Key::F,
+            "G" => Key::G,
+            "H" => Key::H,
+            "I" => Key::I,
+            "J" => Key::J,
+            "K" => Key::K,
+            "L" => Key::L,
+            "M" => Key::M,
+            "N" => Key::N,
+            "O" => Key::O,
+            "P" => Key::P,
+            "Q" => Key::Q,
+            "R" => Key::R,
+            "S" => Key::S,
+            "T" => Key::T,
+            "U" => Key::U,
+            "V" => Key::V,
+            "W" => Key::W,
+            "X" => Key::X,
+            "Y" => Key::Y,
+            "Z" => Key::Z,
+            "F1" => Key::F1,
+            "F2" => Key::F2,
+            "F3" => Key::F3,
+            "F4" => Key::F4,
+            "F5" => Key::F5,
+            "F6" => Key::F6,
+            "F7" => Key::F7,
+            "F8" => Key::F8,
+            "F9" => Key::F9,
+            "F10" => Key::F10,
+            "F11" => Key::F11,
+            "F12" => Key::F12,
+            "F13" => Key::F13,
+            "F14" => Key::F14,
+            "F15" => Key::F15,
+            "F16" => Key::F16,
+            "F17" => Key::F17,
+            "F18" => Key::F18,
+            "F19" => Key::F19,
+            "F20" => Key::F20,
+            _ => return None,
+        })
+    }
+}
+
 impl RawInput {
     pub fn ui(&self, ui: &mut crate::Ui) {
         let Self {
             screen_rect,
             pixels_per_point,
             max_texture_side,
+            system_dark_mode,
             time,
+            oldest_event_time,
             predicted_dt,
             modifiers,
             events,
@@ -542,11 +808,13 @@ impl RawInput {
                 "Also called HDPI factor.\nNumber of physical pixels per each logical pixel.",
             );
         ui.label(format!("max_texture_side: {:?}", max_texture_side));
+        ui.label(format!("system_dark_mode: {:?}", system_dark_mode));
         if let Some(time) = time {
             ui.label(format!("time: {:.3} s", time));
         } else {
             ui.label("time: None");
         }
+        ui.label(format!("oldest_event_time: {:?}", oldest_event_time));
         ui.label(format!("predicted_dt: {:.1} ms", 1e3 * predicted_dt));
         ui.label(format!("modifiers: {:#?}", modifiers));
         ui.label(format!("hovered_files: {}", hovered_files.len()));
@@ -606,3 +874,35 @@ impl From<u32> for TouchId {
         Self(id as u64)
     }
 }
+
+#[cfg(test)]
+#[test]
+fn shortcut_help_overlay() {
+    use crate::{Harness, Id};
+
+    let mut harness = Harness::new();
+    let id = Id::new("__shortcut_help");
+
+    // Returns whether the help overlay is open:
+    let run = |harness: &mut Harness, register: bool| {
+        harness.run(|ctx| {
+            if register {
+                let save = KeyboardShortcut::new(Modifiers::COMMAND, Key::S);
+                ctx.register_shortcut("File", save, "Save");
+            }
+        });
+        harness.ctx().data().get_temp::<bool>(id).unwrap_or(false)
+    };
+
+    harness.type_text("?");
+    assert!(!run(&mut harness, false), "No shortcuts, so no help");
+    harness.type_text("?");
+    assert!(run(&mut harness, true));
+    assert!(run(&mut harness, true));
+    harness.key_press(Key::Escape);
+    assert!(!run(&mut harness, true));
+    harness.type_text("?");
+    assert!(run(&mut harness, true));
+    harness.type_text("?");
+    assert!(!run(&mut harness, true));
+}
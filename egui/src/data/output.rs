@@ -5,7 +5,7 @@ use crate::WidgetType;
 /// What egui emits each frame from [`crate::Context::run`].
 ///
 /// The backend should use this.
-#[derive(Clone, Default, PartialEq)]
+#[derive(Clone, PartialEq)]
 pub struct FullOutput {
     /// Non-rendering related output.
     pub platform_output: PlatformOutput,
@@ -15,6 +15,13 @@ pub struct FullOutput {
     /// This happens for instance when there is an animation, or if a user has called `Context::request_repaint()`.
     pub needs_repaint: bool,
 
+    /// How long until egui wants to be repainted, even if there is no new input.
+    ///
+    /// This is [`std::time::Duration::ZERO`] if [`Self::needs_repaint`] is set,
+    /// the shortest duration passed to `Context::request_repaint_after` if that was called,
+    /// and otherwise [`std::time::Duration::MAX`], meaning the backend can sleep until the next event.
+    pub repaint_after: std::time::Duration,
+
     /// Texture changes since last frame (including the font texture).
     ///
     /// The backend needs to apply [`crate::TexturesDelta::set`] _before_ painting,
@@ -25,6 +32,25 @@ pub struct FullOutput {
     ///
     /// You can use [`crate::Context::tessellate`] to turn this into triangles.
     pub shapes: Vec<epaint::ClippedShape>,
+
+    /// How long the different stages of the _previous_ frame took.
+    ///
+    /// This frame has not been tessellated yet, so we report the last complete frame,
+    /// including the time spent in [`crate::Context::tessellate`].
+    pub frame_timings: FrameTimings,
+}
+
+impl Default for FullOutput {
+    fn default() -> Self {
+        Self {
+            platform_output: Default::default(),
+            needs_repaint: false,
+            repaint_after: std::time::Duration::MAX,
+            textures_delta: Default::default(),
+            shapes: Default::default(),
+            frame_timings: Default::default(),
+        }
+    }
 }
 
 impl FullOutput {
@@ -33,14 +59,54 @@ impl FullOutput {
         let Self {
             platform_output,
             needs_repaint,
+            repaint_after,
             textures_delta,
             shapes,
+            frame_timings,
         } = newer;
 
         self.platform_output.append(platform_output);
         self.needs_repaint = needs_repaint; // if the last frame doesn't need a repaint, then we don't need to repaint
+        self.repaint_after = repaint_after;
         self.textures_delta.append(textures_delta);
         self.shapes = shapes; // Only paint the latest
+        self.frame_timings = frame_timings;
+    }
+}
+
+/// How long the different stages of a frame took, in seconds.
+///
+/// Get the timings of the last frame with [`crate::Context::frame_timings`]
+/// or [`FullOutput::frame_timings`],
+/// or show them with [`crate::Context::frame_timings_ui`].
+///
+/// The timings are measured with [`std::time::Instant`], which is not available on the web,
+/// so there they are always zero.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct FrameTimings {
+    /// Time spent in [`crate::Context::begin_frame`], mostly processing the input.
+    pub begin_frame: f32,
+
+    /// Time spent building the UI, i.e. between [`crate::Context::begin_frame`] and [`crate::Context::end_frame`].
+    pub ui: f32,
+
+    /// Time spent laying out new text while building the UI.
+    ///
+    /// This is part of [`Self::ui`].
+    pub text_layout: f32,
+
+    /// Time spent in [`crate::Context::end_frame`].
+    pub end_frame: f32,
+
+    /// Time spent in [`crate::Context::tessellate`], which is usually called after [`crate::Context::end_frame`].
+    pub tessellation: f32,
+}
+
+impl FrameTimings {
+    /// Total time spent by egui in this frame.
+    pub fn total(&self) -> f32 {
+        // `text_layout` is part of `ui`
+        self.begin_frame + self.ui + self.end_frame + self.tessellation
     }
 }
 
@@ -61,17 +127,47 @@ pub struct PlatformOutput {
     /// If set, put this text in the system clipboard. Ignore if empty.
     ///
     /// This is often a response to [`crate::Event::Copy`] or [`crate::Event::Cut`].
+    /// See also [`crate::Context::copy_text`].
     pub copied_text: String,
 
     /// Events that may be useful to e.g. a screen reader.
     pub events: Vec<OutputEvent>,
 
+    /// All the widgets and containers (windows, panels, scroll areas, …) shown this frame,
+    /// in the order they were added.
+    ///
+    /// A container comes before its children, see [`AccessibilityNode::parent`].
+    ///
+    /// Only filled in if [`crate::Options::accessibility_tree`] is set.
+    pub accessibility_nodes: Vec<AccessibilityNode>,
+
     /// Is there a mutable [`TextEdit`](crate::TextEdit) under the cursor?
     /// Use by `eframe` web to show/hide mobile keyboard and IME agent.
     pub mutable_text_under_cursor: bool,
 
-    /// Screen-space position of text edit cursor (used for IME).
+    /// Screen-space position of the text cursor of the widget taking text input, if any.
+    ///
+    /// Only set while e.g. a [`TextEdit`](crate::TextEdit) has keyboard focus.
+    /// Backends use this to place the IME candidate window.
+    /// See [`crate::Context::set_text_cursor`].
     pub text_cursor_pos: Option<crate::Pos2>,
+
+    /// The input latency of this frame, in seconds (see [`crate::InputState::input_latency`]).
+    ///
+    /// This is measured from when the oldest event arrived until the start of this frame,
+    /// so it does NOT include the time it takes to paint and present the frame.
+    /// Add those yourself to get the full input-to-screen latency.
+    /// Only available if the backend sets [`crate::RawInput::oldest_event_time`].
+    pub input_latency: Option<f32>,
+
+    /// Set by [`crate::Context::request_screenshot`].
+    ///
+    /// The backend should capture the frame after painting it,
+    /// and send it back to egui as [`crate::Event::Screenshot`] next frame.
+    ///
+    /// See [`crate::Context::request_screenshot`] for which backends support this.
+    /// Backends that don't should log a warning.
+    pub screenshot_requested: bool,
 }
 
 impl PlatformOutput {
@@ -82,23 +178,40 @@ impl PlatformOutput {
     }
 
     /// This can be used by a text-to-speech system to describe the events (if any).
+    ///
+    /// Only the last event is described. See also [`Self::event_descriptions`].
     pub fn events_description(&self) -> String {
         // only describe last event:
         if let Some(event) = self.events.iter().rev().next() {
-            match event {
-                OutputEvent::Clicked(widget_info)
-                | OutputEvent::DoubleClicked(widget_info)
-                | OutputEvent::TripleClicked(widget_info)
-                | OutputEvent::FocusGained(widget_info)
-                | OutputEvent::TextSelectionChanged(widget_info)
-                | OutputEvent::ValueChanged(widget_info) => {
-                    return widget_info.description();
-                }
-            }
+            return event.description();
         }
         Default::default()
     }
 
+    /// A description of each event of this frame that can be read out loud, oldest first.
+    ///
+    /// This is all a minimal backend needs to give spoken feedback when widgets
+    /// gain keyboard focus, are clicked, or change value.
+    pub fn event_descriptions(&self) -> impl Iterator<Item = String> + '_ {
+        self.events.iter().map(OutputEvent::description)
+    }
+
+    /// The containers of the [`AccessibilityNode`] with the given id, innermost first.
+    ///
+    /// Empty unless [`crate::Options::accessibility_tree`] is set.
+    pub fn accessibility_ancestors(
+        &self,
+        id: crate::Id,
+    ) -> impl Iterator<Item = &AccessibilityNode> + '_ {
+        let find = move |id| self.accessibility_nodes.iter().find(|node| node.id == id);
+        let mut parent = find(id).and_then(|node| node.parent);
+        std::iter::from_fn(move || {
+            let node = find(parent?)?;
+            parent = node.parent;
+            Some(node)
+        })
+    }
+
     /// Add on new output.
     pub fn append(&mut self, newer: Self) {
         let Self {
@@ -106,8 +219,11 @@ impl PlatformOutput {
             open_url,
             copied_text,
             mut events,
+            accessibility_nodes,
             mutable_text_under_cursor,
             text_cursor_pos,
+            input_latency,
+            screenshot_requested,
         } = newer;
 
         self.cursor_icon = cursor_icon;
@@ -118,8 +234,11 @@ impl PlatformOutput {
             self.copied_text = copied_text;
         }
         self.events.append(&mut events);
+        self.accessibility_nodes = accessibility_nodes; // Only the latest frame is on screen
         self.mutable_text_under_cursor = mutable_text_under_cursor;
         self.text_cursor_pos = text_cursor_pos.or(self.text_cursor_pos);
+        self.input_latency = input_latency.or(self.input_latency);
+        self.screenshot_requested |= screenshot_requested;
     }
 
     /// Take everything ephemeral (everything except `cursor_icon` currently)
@@ -340,6 +459,25 @@ pub enum OutputEvent {
     ValueChanged(WidgetInfo),
 }
 
+impl OutputEvent {
+    /// The widget this event is about.
+    pub fn widget_info(&self) -> &WidgetInfo {
+        match self {
+            OutputEvent::Clicked(info)
+            | OutputEvent::DoubleClicked(info)
+            | OutputEvent::TripleClicked(info)
+            | OutputEvent::FocusGained(info)
+            | OutputEvent::TextSelectionChanged(info)
+            | OutputEvent::ValueChanged(info) => info,
+        }
+    }
+
+    /// A description of the widget that can be read out loud, e.g. `"Dark mode: checked checkbox"`.
+    pub fn description(&self) -> String {
+        self.widget_info().description()
+    }
+}
+
 impl std::fmt::Debug for OutputEvent {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -353,6 +491,36 @@ impl std::fmt::Debug for OutputEvent {
     }
 }
 
+/// Describes a widget shown this frame, for assistive technologies such as screen readers.
+///
+/// See [`PlatformOutput::accessibility_nodes`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct AccessibilityNode {
+    /// The widget [`crate::Id`].
+    pub id: crate::Id,
+
+    /// Where the widget is on screen.
+    pub rect: crate::Rect,
+
+    /// The role (widget type), label, value and state of the widget.
+    pub info: WidgetInfo,
+
+    /// Does the widget have keyboard focus?
+    pub focused: bool,
+
+    /// The widget that labels this one, if any. See [`crate::Response::labelled_by`].
+    pub labelled_by: Option<crate::Id>,
+
+    /// The container (e.g. a window or a scroll area) this node is in,
+    /// or `None` for the top-level nodes.
+    pub parent: Option<crate::Id>,
+
+    /// The nodes directly inside this one, in the order they were added.
+    /// Empty for everything but containers.
+    pub children: Vec<crate::Id>,
+}
+
 /// Describes a widget such as a [`crate::Button`] or a [`crate::TextEdit`].
 #[derive(Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -520,6 +688,7 @@ impl WidgetInfo {
             WidgetType::ColorButton => "color button",
             WidgetType::ImageButton => "image button",
             WidgetType::CollapsingHeader => "collapsing header",
+            WidgetType::ScrollBar => "scroll bar",
             WidgetType::Label | WidgetType::Other => "",
         };
 
@@ -530,7 +699,7 @@ impl WidgetInfo {
                 let state = if *selected { "checked" } else { "unchecked" };
                 description = format!("{} {}", state, description);
             } else {
-                description += if *selected { "selected" } else { "" };
+                description += if *selected { " selected" } else { "" };
             };
         }
 
@@ -563,3 +732,259 @@ impl WidgetInfo {
         description.trim().to_owned()
     }
 }
+
+#[cfg(test)]
+#[test]
+fn widget_info_description() {
+    let checkbox = WidgetInfo::selected(WidgetType::Checkbox, true, "Dark mode");
+    assert_eq!(checkbox.description(), "Dark mode: checked checkbox");
+
+    let selectable = WidgetInfo::selected(WidgetType::SelectableLabel, true, "Apple");
+    assert_eq!(selectable.description(), "Apple: selectable selected");
+
+    let selectable = WidgetInfo::selected(WidgetType::SelectableLabel, false, "Apple");
+    assert_eq!(selectable.description(), "Apple: selectable");
+}
+
+#[cfg(test)]
+#[test]
+fn request_repaint_after() {
+    use crate::Harness;
+    use std::time::Duration;
+
+    let mut harness = Harness::new();
+
+    // Let any startup repaints settle:
+    for _ in 0..3 {
+        harness.run(|_| {});
+    }
+
+    harness.run(|_| {});
+    assert!(!harness.output().needs_repaint);
+    assert_eq!(harness.output().repaint_after, Duration::MAX);
+
+    harness.run(|ctx| {
+        ctx.request_repaint_after(Duration::from_secs(3));
+        ctx.request_repaint_after(Duration::from_secs(1));
+    });
+    assert!(!harness.output().needs_repaint);
+    assert_eq!(harness.output().repaint_after, Duration::from_secs(1));
+
+    harness.run(|ctx| {
+        ctx.request_repaint_after(Duration::from_secs(1));
+        ctx.request_repaint();
+    });
+    assert!(harness.output().needs_repaint);
+    assert_eq!(harness.output().repaint_after, Duration::ZERO);
+}
+
+#[cfg(test)]
+#[test]
+fn frame_timings() {
+    use crate::Harness;
+
+    let mut harness = Harness::new();
+
+    // Returns the timings reported in the output:
+    let run = |harness: &mut Harness| {
+        harness.run_ui(|ui| {
+            for i in 0..100 {
+                ui.label(format!("Some new text to lay out: {}", i));
+            }
+        });
+        harness.output().frame_timings
+    };
+
+    assert_eq!(
+        run(&mut harness),
+        FrameTimings::default(),
+        "No previous frame"
+    );
+
+    let timings = harness.ctx().frame_timings();
+    assert!(
+        timings.text_layout <= timings.ui,
+        "Text layout is part of the ui"
+    );
+    assert_eq!(timings.tessellation, 0.0, "Not tessellated yet");
+    assert_eq!(
+        timings.total(),
+        timings.begin_frame + timings.ui + timings.end_frame
+    );
+
+    let _ = harness.ctx().tessellate(harness.output().shapes.clone());
+    let tessellated = harness.ctx().frame_timings();
+    assert_eq!(tessellated.begin_frame, timings.begin_frame);
+    assert_eq!(tessellated.ui, timings.ui);
+    assert_eq!(tessellated.end_frame, timings.end_frame);
+    assert_eq!(
+        tessellated.total(),
+        timings.total() + tessellated.tessellation
+    );
+
+    assert_eq!(
+        run(&mut harness),
+        tessellated,
+        "The output reports the previous frame, including its tessellation"
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn platform_output() {
+    use crate::Harness;
+
+    let mut harness = Harness::new();
+    let mut text = String::from("hello");
+    let mut run = |harness: &mut Harness, first_frame: bool| {
+        harness.run_ui(|ui| {
+            let response = ui.text_edit_singleline(&mut text);
+            if first_frame {
+                response.request_focus();
+                ui.ctx().copy_text("copied");
+                ui.ctx().output().open_url = Some(OpenUrl::new_tab("https://www.egui.rs"));
+                ui.ctx().output().cursor_icon = CursorIcon::Wait;
+            }
+        });
+        harness.output().platform_output.clone()
+    };
+
+    let output = run(&mut harness, true);
+    assert_eq!(output.copied_text, "copied");
+    assert!(output.open_url == Some(OpenUrl::new_tab("https://www.egui.rs")));
+    assert_eq!(output.cursor_icon, CursorIcon::Wait);
+
+    // The output only contains what was requested during that frame:
+    let mut output = run(&mut harness, false);
+    assert!(output.copied_text.is_empty());
+    assert!(output.open_url.is_none());
+    assert_eq!(output.cursor_icon, CursorIcon::Default);
+    // The text edit has focus, so the backend is told where to show the IME:
+    assert!(output.text_cursor_pos.is_some());
+
+    // Output from frames the backend hasn't handled yet can be merged:
+    output.append(PlatformOutput {
+        copied_text: "more".to_owned(),
+        ..Default::default()
+    });
+    assert_eq!(output.copied_text, "more");
+    assert!(output.text_cursor_pos.is_some());
+}
+
+#[cfg(test)]
+#[test]
+fn accessibility_tree() {
+    use crate::Harness;
+
+    let mut harness = Harness::new();
+    let mut name = String::from("Ferris");
+    let mut checked = true;
+    let mut run = |harness: &mut Harness| {
+        harness.run_ui(|ui| {
+            ui.horizontal(|ui| {
+                let label = ui.label("Your name: ");
+                let text_edit = ui.text_edit_singleline(&mut name).labelled_by(label.id);
+                text_edit.request_focus();
+            });
+            ui.checkbox(&mut checked, "Checked");
+        });
+        harness.output().platform_output.accessibility_nodes.clone()
+    };
+
+    assert!(run(&mut harness).is_empty(), "Off by default");
+
+    harness.ctx().options().accessibility_tree = true;
+    let nodes = run(&mut harness);
+    assert_eq!(nodes.len(), 4);
+
+    let [panel, label, text_edit, checkbox] = [&nodes[0], &nodes[1], &nodes[2], &nodes[3]];
+    assert_eq!(panel.parent, None);
+    assert_eq!(panel.children, vec![label.id, text_edit.id, checkbox.id]);
+    assert_eq!(panel.rect, harness.ctx().input().screen_rect());
+    assert!(nodes[1..].iter().all(|node| node.parent == Some(panel.id)));
+    assert_eq!(label.info.typ, WidgetType::Label);
+    assert_eq!(label.info.label.as_deref(), Some("Your name: "));
+    assert_eq!(text_edit.info.typ, WidgetType::TextEdit);
+    assert_eq!(text_edit.info.current_text_value.as_deref(), Some("Ferris"));
+    assert_eq!(text_edit.labelled_by, Some(label.id));
+    assert!(text_edit.focused);
+    assert_eq!(checkbox.info.typ, WidgetType::Checkbox);
+    assert_eq!(checkbox.info.selected, Some(true));
+    assert!(!checkbox.focused);
+}
+
+#[cfg(test)]
+#[test]
+fn accessibility_tree_follows_containers() {
+    use crate::{Harness, Id, ScrollArea, Window};
+
+    let mut harness = Harness::new();
+    harness.ctx().options().accessibility_tree = true;
+    let window_rect = harness
+        .run(|ctx| {
+            Window::new("Settings")
+                .show(ctx, |ui| {
+                    ScrollArea::vertical().show(ui, |ui| {
+                        let _ = ui.button("Apply");
+                    });
+                })
+                .map(|response| response.response.rect)
+        })
+        .unwrap();
+
+    let platform_output = &harness.output().platform_output;
+    let nodes = &platform_output.accessibility_nodes;
+    assert_eq!(nodes.len(), 3);
+    let [window, scroll_area, button] = [&nodes[0], &nodes[1], &nodes[2]];
+
+    assert_eq!(window.info.label.as_deref(), Some("Settings"));
+    assert_eq!(window.rect, window_rect);
+    assert_eq!(window.parent, None);
+    assert_eq!(window.children, vec![scroll_area.id]);
+
+    assert!(window.rect.contains_rect(scroll_area.rect));
+    assert_eq!(scroll_area.parent, Some(window.id));
+    assert_eq!(scroll_area.children, vec![button.id]);
+
+    assert_eq!(button.info.typ, WidgetType::Button);
+    assert!(scroll_area.rect.contains_rect(button.rect));
+    assert_eq!(button.parent, Some(scroll_area.id));
+    assert!(button.children.is_empty());
+
+    let ancestors: Vec<Id> = platform_output
+        .accessibility_ancestors(button.id)
+        .map(|node| node.id)
+        .collect();
+    assert_eq!(ancestors, vec![scroll_area.id, window.id]);
+}
+
+#[cfg(test)]
+#[test]
+fn event_descriptions() {
+    use crate::Harness;
+
+    let mut harness = Harness::new();
+    let mut name = String::from("Ferris");
+    let mut checked = false;
+    let mut run = |harness: &mut Harness| {
+        let checkbox_rect = harness.run_ui(|ui| {
+            ui.text_edit_singleline(&mut name);
+            ui.checkbox(&mut checked, "Dark mode").rect
+        });
+        let descriptions: Vec<String> = harness
+            .output()
+            .platform_output
+            .event_descriptions()
+            .collect();
+        (checkbox_rect, descriptions)
+    };
+
+    let (checkbox_rect, descriptions) = run(&mut harness);
+    assert!(descriptions.is_empty());
+
+    harness.click(checkbox_rect.center());
+    assert_eq!(run(&mut harness).1, vec!["Dark mode: checked checkbox"]);
+
+    harness.click(checkbox_rect.center());
+    assert_eq!(run(&mut harness).1, vec!["Dark mode: unchecked checkbox"]);
+}
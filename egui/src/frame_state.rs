@@ -9,6 +9,41 @@ pub(crate) struct TooltipRect {
     pub count: usize,
 }
 
+/// A keyboard shortcut registered with [`Context::register_shortcut`].
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct RegisteredShortcut {
+    pub category: String,
+    pub shortcut: KeyboardShortcut,
+    pub description: String,
+}
+
+/// Two different widgets that used the same [`Id`] in the same frame,
+/// so that they share state (e.g. a scroll offset) without meaning to.
+///
+/// See [`Context::id_clashes`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct IdClash {
+    pub id: Id,
+
+    /// What kind of widget it is, e.g. `"widget"` or `"Grid"`.
+    pub what: String,
+
+    /// Where the [`Id`] was first used.
+    pub first_rect: Rect,
+
+    /// Where the [`Id`] was used again.
+    pub second_rect: Rect,
+}
+
+/// A widget under the pointer, collected for [`crate::style::DebugOptions::inspect_on_hover`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct InspectedWidget {
+    pub id: Id,
+    pub rect: Rect,
+    pub layer_id: LayerId,
+    pub clip_rect: Rect,
+}
+
 /// State that is collected during a frame and then cleared.
 /// Short-term (single frame) memory.
 #[derive(Clone)]
@@ -17,6 +52,13 @@ pub(crate) struct FrameState {
     /// Used to debug [`Id`] clashes of widgets.
     pub(crate) used_ids: IdMap<Rect>,
 
+    /// All [`Area`] [`Id`]s that were used this frame, and where.
+    /// Used to warn about windows with the same title.
+    pub(crate) used_area_ids: IdMap<Rect>,
+
+    /// The [`Id`] clashes found so far this frame.
+    pub(crate) id_clashes: Vec<IdClash>,
+
     /// Starts off as the screen_rect, shrinks as panels are added.
     /// The [`CentralPanel`] does not change this.
     /// This is the area available to Window's.
@@ -34,6 +76,23 @@ pub(crate) struct FrameState {
     /// Initialized to `None` at the start of each frame.
     pub(crate) tooltip_rect: Option<TooltipRect>,
 
+    /// All tooltips shown this frame, including tooltips shown within other tooltips.
+    ///
+    /// The rectangle of each covers any tooltips nested in it too.
+    pub(crate) tooltip_rects: Vec<TooltipRect>,
+
+    /// The [`Self::tooltip_rects`] of the previous frame.
+    /// Used to keep interactive tooltips open while the pointer is on them.
+    pub(crate) tooltip_rects_last_frame: Vec<TooltipRect>,
+
+    /// The widget that wants to show a tooltip this frame,
+    /// and when the pointer started hovering it.
+    /// Used for [`crate::style::Interaction::tooltip_delay`].
+    pub(crate) tooltip_hover: Option<(Id, f64)>,
+
+    /// The [`Self::tooltip_hover`] of the previous frame.
+    pub(crate) tooltip_hover_last_frame: Option<(Id, f64)>,
+
     /// Set to [`InputState::scroll_delta`] on the start of each frame.
     ///
     /// Cleared by the first [`ScrollArea`] that makes use of it.
@@ -41,18 +100,54 @@ pub(crate) struct FrameState {
 
     /// horizontal, vertical
     pub(crate) scroll_target: [Option<(RangeInclusive<f32>, Option<Align>)>; 2],
+
+    /// All keyboard shortcuts registered this frame, in order.
+    /// Listed in the shortcut help overlay.
+    pub(crate) shortcuts: Vec<RegisteredShortcut>,
+
+    /// All widgets under the pointer this frame, while inspecting.
+    /// The innermost one is described by the inspection overlay,
+    /// and the others are the containers it is in.
+    pub(crate) inspected_widgets: Vec<InspectedWidget>,
+
+    /// The widget that takes text input this frame, set by [`crate::Context::set_text_cursor`].
+    pub(crate) text_input_owner: Option<Id>,
+
+    /// `(parent, child)`: layers painted right on top of another layer,
+    /// e.g. pinned [`crate::Ui::sticky`] contents.
+    pub(crate) sublayers: Vec<(LayerId, LayerId)>,
+
+    /// `(parent, rect)`: where pinned [`crate::Ui::sticky`] contents cover their parent layer.
+    /// Widgets in the parent layer are not hovered there.
+    pub(crate) pinned_sticky_rects: Vec<(LayerId, Rect)>,
+
+    /// The accessibility containers that are currently open, innermost last.
+    /// See [`crate::Context::begin_accessibility_container`].
+    pub(crate) accessibility_parents: Vec<Id>,
 }
 
 impl Default for FrameState {
     fn default() -> Self {
         Self {
             used_ids: Default::default(),
+            used_area_ids: Default::default(),
+            id_clashes: Default::default(),
             available_rect: Rect::NAN,
             unused_rect: Rect::NAN,
             used_by_panels: Rect::NAN,
             tooltip_rect: None,
+            tooltip_rects: Default::default(),
+            tooltip_rects_last_frame: Default::default(),
+            tooltip_hover: None,
+            tooltip_hover_last_frame: None,
             scroll_delta: Vec2::ZERO,
             scroll_target: [None, None],
+            shortcuts: Default::default(),
+            inspected_widgets: Default::default(),
+            text_input_owner: None,
+            sublayers: Default::default(),
+            pinned_sticky_rects: Default::default(),
+            accessibility_parents: Default::default(),
         }
     }
 }
@@ -61,21 +156,43 @@ impl FrameState {
     pub(crate) fn begin_frame(&mut self, input: &InputState) {
         let Self {
             used_ids,
+            used_area_ids,
+            id_clashes,
             available_rect,
             unused_rect,
             used_by_panels,
             tooltip_rect,
+            tooltip_rects,
+            tooltip_rects_last_frame,
+            tooltip_hover,
+            tooltip_hover_last_frame,
             scroll_delta,
             scroll_target,
+            shortcuts,
+            inspected_widgets,
+            text_input_owner,
+            sublayers,
+            pinned_sticky_rects,
+            accessibility_parents,
         } = self;
 
         used_ids.clear();
+        used_area_ids.clear();
+        id_clashes.clear();
         *available_rect = input.screen_rect();
         *unused_rect = input.screen_rect();
         *used_by_panels = Rect::NOTHING;
         *tooltip_rect = None;
+        *tooltip_rects_last_frame = std::mem::take(tooltip_rects);
+        *tooltip_hover_last_frame = tooltip_hover.take();
         *scroll_delta = input.scroll_delta;
         *scroll_target = [None, None];
+        shortcuts.clear();
+        inspected_widgets.clear();
+        *text_input_owner = None;
+        sublayers.clear();
+        pinned_sticky_rects.clear();
+        accessibility_parents.clear();
     }
 
     /// How much space is still available after panels has been added.
@@ -140,3 +257,22 @@ impl FrameState {
         self.used_by_panels = self.used_by_panels.union(panel_rect);
     }
 }
+
+#[cfg(test)]
+#[test]
+fn id_clashes() {
+    let mut harness = Harness::new();
+    harness.run_ui(|ui| {
+        ui.collapsing("Same", |_| {});
+        ui.collapsing("Unique", |_| {});
+        ui.collapsing("Same", |_| {});
+    });
+
+    let clashes = harness.ctx().id_clashes();
+    assert_eq!(clashes.len(), 1);
+    assert_eq!(clashes[0].what, "widget");
+    assert!(clashes[0].first_rect.max.y <= clashes[0].second_rect.min.y);
+
+    harness.run(|_| {});
+    assert!(harness.ctx().id_clashes().is_empty());
+}
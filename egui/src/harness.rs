@@ -0,0 +1,302 @@
+//! Run egui without a window or GPU, e.g. in unit tests.
+
+use crate::*;
+
+/// Runs a [`Context`] headlessly, feeding it scripted input.
+///
+/// Queue up input with e.g. [`Self::click`] and [`Self::type_text`],
+/// then run a frame with [`Self::run`] or [`Self::run_ui`].
+/// Each frame advances the time by [`Self::dt`], so animations and double-clicks are deterministic.
+///
+/// You can then assert on whatever your ui code returns (e.g. a [`Response`]),
+/// on [`Context::memory`], on the shapes in [`Self::output`],
+/// or on the pixels of the image returned by [`Self::render`].
+///
+/// This needs the `testing` feature, which you probably only want for your tests:
+///
+/// ```toml
+/// [dev-dependencies]
+/// egui = { version = "0.18", features = ["testing"] }
+/// ```
+///
+/// ```
+/// let mut harness = egui::Harness::new();
+///
+/// // Run a first frame to find out where the button is:
+/// let button_rect = harness.run_ui(|ui| ui.button("Click me").rect);
+///
+/// harness.click(button_rect.center());
+/// let clicked = harness.run_ui(|ui| ui.button("Click me").clicked());
+/// assert!(clicked);
+/// assert!(!harness.output().shapes.is_empty());
+/// ```
+pub struct Harness {
+    ctx: Context,
+    screen_rect: Rect,
+    pixels_per_point: f32,
+    time: f64,
+    dt: f32,
+    modifiers: Modifiers,
+    system_dark_mode: Option<bool>,
+    events: Vec<Event>,
+    output: FullOutput,
+    rasterizer: epaint::rasterizer::Rasterizer,
+}
+
+impl Default for Harness {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Harness {
+    /// A harness with a 1024x768 screen.
+    pub fn new() -> Self {
+        Self {
+            ctx: Context::default(),
+            screen_rect: Rect::from_min_size(Pos2::ZERO, vec2(1024.0, 768.0)),
+            pixels_per_point: 1.0,
+            time: 0.0,
+            dt: 1.0 / 60.0,
+            modifiers: Default::default(),
+            system_dark_mode: None,
+            events: Default::default(),
+            output: Default::default(),
+            rasterizer: Default::default(),
+        }
+    }
+
+    /// Set the size of the screen, in points.
+    pub fn with_screen_size(mut self, size: Vec2) -> Self {
+        self.screen_rect = Rect::from_min_size(Pos2::ZERO, size);
+        self
+    }
+
+    /// Set the number of physical pixels per point. Default: `1.0`.
+    pub fn with_pixels_per_point(mut self, pixels_per_point: f32) -> Self {
+        self.pixels_per_point = pixels_per_point;
+        self
+    }
+
+    /// How many seconds each frame advances the time. Default: `1.0 / 60.0`.
+    pub fn with_dt(mut self, dt: f32) -> Self {
+        self.dt = dt;
+        self
+    }
+
+    /// The [`Context`] being tested.
+    pub fn ctx(&self) -> &Context {
+        &self.ctx
+    }
+
+    /// How many seconds each frame advances the time.
+    pub fn dt(&self) -> f32 {
+        self.dt
+    }
+
+    /// The time of the next frame, in seconds.
+    pub fn time(&self) -> f64 {
+        self.time
+    }
+
+    /// Let time pass without running any frames, e.g. to finish an animation or a tooltip delay.
+    pub fn advance_time(&mut self, seconds: f64) {
+        self.time += seconds;
+    }
+
+    /// Queue an event for the next frame.
+    pub fn push_event(&mut self, event: Event) {
+        self.events.push(event);
+    }
+
+    /// The modifier keys that are held down during the following events.
+    pub fn set_modifiers(&mut self, modifiers: Modifiers) {
+        self.modifiers = modifiers;
+    }
+
+    /// Whether the operating system is in dark mode, see [`RawInput::system_dark_mode`].
+    pub fn set_system_dark_mode(&mut self, system_dark_mode: Option<bool>) {
+        self.system_dark_mode = system_dark_mode;
+    }
+
+    /// Move the mouse to this position.
+    pub fn move_mouse(&mut self, pos: Pos2) {
+        self.push_event(Event::PointerMoved(pos));
+    }
+
+    /// Move the mouse to this position and press the primary button.
+    pub fn press(&mut self, pos: Pos2) {
+        self.move_mouse(pos);
+        self.pointer_button(pos, true);
+    }
+
+    /// Move the mouse to this position and release the primary button.
+    pub fn release(&mut self, pos: Pos2) {
+        self.move_mouse(pos);
+        self.pointer_button(pos, false);
+    }
+
+    /// Press and release the primary mouse button at this position, during the same frame.
+    pub fn click(&mut self, pos: Pos2) {
+        self.press(pos);
+        self.pointer_button(pos, false);
+    }
+
+    fn pointer_button(&mut self, pos: Pos2, pressed: bool) {
+        self.push_event(Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: self.modifiers,
+        });
+    }
+
+    /// Type some text into whatever widget has keyboard focus.
+    pub fn type_text(&mut self, text: impl Into<String>) {
+        self.push_event(Event::Text(text.into()));
+    }
+
+    /// Press and release a key.
+    pub fn key_press(&mut self, key: Key) {
+        for pressed in [true, false] {
+            self.push_event(Event::Key {
+                key,
+                pressed,
+                modifiers: self.modifiers,
+            });
+        }
+    }
+
+    /// The output of the last frame.
+    pub fn output(&self) -> &FullOutput {
+        &self.output
+    }
+
+    /// Run one frame with all the queued input, then advance the time by [`Self::dt`].
+    ///
+    /// Returns whatever `run_ui` returns.
+    pub fn run<R>(&mut self, run_ui: impl FnOnce(&Context) -> R) -> R {
+        let raw_input = RawInput {
+            screen_rect: Some(self.screen_rect),
+            pixels_per_point: Some(self.pixels_per_point),
+            time: Some(self.time),
+            predicted_dt: self.dt,
+            modifiers: self.modifiers,
+            system_dark_mode: self.system_dark_mode,
+            events: std::mem::take(&mut self.events),
+            ..Default::default()
+        };
+        let mut run_ui = Some(run_ui);
+        let mut returned = None;
+        self.output = self.ctx.run(raw_input, |ctx| {
+            if let Some(run_ui) = run_ui.take() {
+                returned = Some(run_ui(ctx));
+            }
+        });
+        self.rasterizer.update_textures(&self.output.textures_delta);
+        if self.output.platform_output.screenshot_requested {
+            let screenshot = std::sync::Arc::new(self.render());
+            self.push_event(Event::Screenshot(screenshot));
+        }
+        self.time += self.dt as f64;
+        returned.expect("Context::run should call run_ui exactly once")
+    }
+
+    /// Render the last frame to an image, using a software rasterizer.
+    ///
+    /// The result is the same on all platforms, so it can be compared to a previously saved image
+    /// in a snapshot test, or be used as a screenshot in documentation.
+    pub fn render(&self) -> ColorImage {
+        let size = self.screen_rect.size() * self.pixels_per_point;
+        let size = [size.x.round() as usize, size.y.round() as usize];
+        let primitives = self.ctx.tessellate(self.output.shapes.clone());
+        self.rasterizer.render(
+            size,
+            self.pixels_per_point,
+            &primitives,
+            Color32::TRANSPARENT,
+        )
+    }
+
+    /// Like [`Self::run`], but puts the ui in a [`CentralPanel`].
+    pub fn run_ui<R>(&mut self, add_contents: impl FnOnce(&mut Ui) -> R) -> R {
+        self.run(|ctx| CentralPanel::default().show(ctx, add_contents).inner)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn type_into_text_edit() {
+    let mut harness = Harness::new();
+    let mut text = String::new();
+
+    let rect = harness.run_ui(|ui| ui.text_edit_singleline(&mut text).rect);
+    harness.click(rect.center());
+    let response = harness.run_ui(|ui| ui.text_edit_singleline(&mut text));
+    assert!(response.has_focus());
+    assert!(harness.ctx().memory().has_focus(response.id));
+
+    harness.type_text("hello");
+    harness.key_press(Key::Backspace);
+    let response = harness.run_ui(|ui| ui.text_edit_singleline(&mut text));
+    assert!(response.changed());
+    assert_eq!(text, "hell");
+    assert!(harness.output().platform_output.text_cursor_pos.is_some());
+    assert_eq!(harness.ctx().text_input_owner(), Some(response.id));
+
+    // Clicking elsewhere gives up the text input:
+    harness.click(pos2(500.0, 500.0));
+    harness.run_ui(|ui| ui.text_edit_singleline(&mut text));
+    assert!(harness.output().platform_output.text_cursor_pos.is_none());
+    assert_eq!(harness.ctx().text_input_owner(), None);
+}
+
+#[cfg(test)]
+#[test]
+fn harness_time() {
+    let mut harness = Harness::new().with_dt(0.5);
+    harness.run(|ctx| assert_eq!(ctx.input().time, 0.0));
+    harness.advance_time(2.0);
+    harness.run(|ctx| assert_eq!(ctx.input().time, 2.5));
+}
+
+#[cfg(test)]
+#[test]
+fn render() {
+    let mut harness = Harness::new()
+        .with_screen_size(vec2(200.0, 100.0))
+        .with_pixels_per_point(2.0);
+    let fill = harness.run(|ctx| ctx.style().visuals.window_fill());
+    let button_rect = harness.run_ui(|ui| ui.button("Click me").rect);
+
+    let image = harness.render();
+    assert_eq!(image.size, [400, 200]);
+    assert_eq!(image[(399, 199)], fill, "The panel covers the whole screen");
+
+    // Somewhere on the button, there is text:
+    let button_rect = button_rect.shrink(2.0);
+    let button_pixels =
+        (button_rect.min.y as usize * 2..button_rect.max.y as usize * 2).flat_map(|y| {
+            (button_rect.min.x as usize * 2..button_rect.max.x as usize * 2).map(move |x| (x, y))
+        });
+    let colors: std::collections::BTreeSet<_> =
+        button_pixels.map(|xy| image[xy].to_array()).collect();
+    assert!(colors.len() > 2);
+
+    // Rendering is deterministic:
+    assert!(harness.render() == image);
+}
+
+#[cfg(test)]
+#[test]
+fn screenshot() {
+    let mut harness = Harness::new().with_screen_size(vec2(100.0, 50.0));
+    harness.run(|ctx| ctx.request_screenshot());
+    let screenshot = harness.run(|ctx| {
+        ctx.input().events.iter().find_map(|event| match event {
+            Event::Screenshot(image) => Some(image.clone()),
+            _ => None,
+        })
+    });
+    assert_eq!(screenshot.unwrap().size, [100, 50]);
+}
@@ -0,0 +1,112 @@
+use crate::{Pos2, Rect, Vec2};
+
+/// The area of a non-rectangular widget that responds to the pointer.
+///
+/// Use with [`crate::Ui::interact_with_hit_shape`] so that e.g. a round knob
+/// is only hovered when the pointer is over the knob, and not over the corners of its bounding rect.
+#[derive(Clone, Debug, PartialEq)]
+pub enum HitShape {
+    Rect(Rect),
+
+    Circle {
+        center: Pos2,
+        radius: f32,
+    },
+
+    /// A convex polygon. The points can be in either winding order.
+    ConvexPolygon(Vec<Pos2>),
+}
+
+impl HitShape {
+    pub fn circle(center: Pos2, radius: f32) -> Self {
+        Self::Circle { center, radius }
+    }
+
+    pub fn convex_polygon(points: Vec<Pos2>) -> Self {
+        Self::ConvexPolygon(points)
+    }
+
+    /// A thick line between two points, e.g. a connector between two nodes.
+    pub fn line_segment(points: [Pos2; 2], width: f32) -> Self {
+        let [a, b] = points;
+        let dir = (b - a).normalized();
+        let normal = 0.5 * width * dir.rot90();
+        if !normal.is_finite() {
+            return Self::circle(a, 0.5 * width);
+        }
+        Self::ConvexPolygon(vec![a + normal, b + normal, b - normal, a - normal])
+    }
+
+    /// The smallest rectangle containing the whole shape.
+    pub fn bounding_rect(&self) -> Rect {
+        match self {
+            Self::Rect(rect) => *rect,
+            Self::Circle { center, radius } => {
+                Rect::from_center_size(*center, Vec2::splat(2.0 * radius))
+            }
+            Self::ConvexPolygon(points) => {
+                let mut rect = Rect::NOTHING;
+                for point in points {
+                    rect.extend_with(*point);
+                }
+                rect
+            }
+        }
+    }
+
+    /// Is the given position inside (or on the edge of) the shape?
+    pub fn contains(&self, pos: Pos2) -> bool {
+        match self {
+            Self::Rect(rect) => rect.contains(pos),
+            Self::Circle { center, radius } => center.distance_sq(pos) <= radius * radius,
+            Self::ConvexPolygon(points) => {
+                if points.len() < 3 {
+                    return false;
+                }
+
+                // Inside if we are on the same side of all edges:
+                let mut has_positive = false;
+                let mut has_negative = false;
+                for (i, a) in points.iter().enumerate() {
+                    let b = points[(i + 1) % points.len()];
+                    let cross = (b - *a).x * (pos - *a).y - (b - *a).y * (pos - *a).x;
+                    has_positive |= cross > 0.0;
+                    has_negative |= cross < 0.0;
+                }
+                !(has_positive && has_negative)
+            }
+        }
+    }
+}
+
+impl From<Rect> for HitShape {
+    #[inline]
+    fn from(rect: Rect) -> Self {
+        Self::Rect(rect)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn test_hit_shape_contains() {
+    use crate::pos2;
+
+    let circle = HitShape::circle(pos2(10.0, 10.0), 5.0);
+    assert!(circle.contains(pos2(13.0, 13.0)));
+    assert!(
+        !circle.contains(pos2(14.0, 14.0)),
+        "Corner of the bounding rect"
+    );
+    assert_eq!(
+        circle.bounding_rect(),
+        Rect::from_min_max(pos2(5.0, 5.0), pos2(15.0, 15.0))
+    );
+
+    let triangle = HitShape::convex_polygon(vec![pos2(0.0, 0.0), pos2(10.0, 0.0), pos2(0.0, 10.0)]);
+    assert!(triangle.contains(pos2(2.0, 2.0)));
+    assert!(!triangle.contains(pos2(8.0, 8.0)));
+
+    let line = HitShape::line_segment([pos2(0.0, 0.0), pos2(100.0, 100.0)], 4.0);
+    assert!(line.contains(pos2(50.0, 51.0)));
+    assert!(!line.contains(pos2(50.0, 60.0)));
+}
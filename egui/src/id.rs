@@ -65,6 +65,21 @@ impl Id {
         format!("{:04X}", self.0 as u16)
     }
 
+    /// A color derived from this [`Id`].
+    ///
+    /// The same [`Id`] always gives the same color, and different [`Id`]s are likely to get distinct colors.
+    /// Useful for debug visualizations, or for consistently coloring things like plot series.
+    ///
+    /// ```
+    /// # use egui::Id;
+    /// assert_eq!(Id::new("apple").color(), Id::new("apple").color());
+    /// assert_ne!(Id::new("apple").color(), Id::new("banana").color());
+    /// ```
+    pub fn color(&self) -> crate::Color32 {
+        let hue = (self.0 as f64 / u64::MAX as f64) as f32;
+        epaint::color::Hsva::new(hue, 0.85, 0.5, 1.0).into()
+    }
+
     #[inline(always)]
     pub(crate) fn value(&self) -> u64 {
         self.0
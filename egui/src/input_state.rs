@@ -14,9 +14,6 @@ const MAX_CLICK_DIST: f32 = 6.0; // TODO(emilk): move to settings
 /// If the pointer is down for longer than this, it won't become a click (but it is still a drag)
 const MAX_CLICK_DURATION: f64 = 0.6; // TODO(emilk): move to settings
 
-/// The new pointer press must come within this many seconds from previous pointer release
-const MAX_DOUBLE_CLICK_DELAY: f64 = 0.3; // TODO(emilk): move to settings
-
 /// Input state that egui updates each frame.
 ///
 /// You can check if `egui` is using the inputs using
@@ -62,6 +59,11 @@ pub struct InputState {
     /// This depends on the backend.
     pub max_texture_side: usize,
 
+    /// Does the operating system prefer a dark theme? `None` if unknown.
+    ///
+    /// See [`RawInput::system_dark_mode`].
+    pub system_dark_mode: Option<bool>,
+
     /// Time in seconds. Relative to whatever. Used for animation.
     pub time: f64,
 
@@ -104,6 +106,13 @@ pub struct InputState {
     /// and will effectively slow down the animation when FPS drops below 10.
     pub stable_dt: f32,
 
+    /// Time from when the backend received the oldest input event of this frame
+    /// until the start of this frame.
+    ///
+    /// Only available if the backend sets [`RawInput::oldest_event_time`].
+    /// This does not include the time it takes to paint and present the frame.
+    pub input_latency: Option<f32>,
+
     /// Which modifier keys are down at the start of the frame?
     pub modifiers: Modifiers,
 
@@ -125,10 +134,12 @@ impl Default for InputState {
             screen_rect: Rect::from_min_size(Default::default(), vec2(10_000.0, 10_000.0)),
             pixels_per_point: 1.0,
             max_texture_side: 2048,
+            system_dark_mode: None,
             time: 0.0,
             unstable_dt: 1.0 / 60.0,
             predicted_dt: 1.0 / 60.0,
             stable_dt: 1.0 / 60.0,
+            input_latency: None,
             modifiers: Default::default(),
             keys_down: Default::default(),
             events: Default::default(),
@@ -138,7 +149,24 @@ impl Default for InputState {
 
 impl InputState {
     #[must_use]
-    pub fn begin_frame(mut self, new: RawInput, requested_repaint_last_frame: bool) -> InputState {
+    pub fn begin_frame(self, new: RawInput, requested_repaint_last_frame: bool) -> InputState {
+        let max_double_click_delay = crate::style::Interaction::default().max_double_click_delay;
+        self.begin_frame_with_double_click_delay(
+            new,
+            requested_repaint_last_frame,
+            max_double_click_delay,
+        )
+    }
+
+    /// Like [`Self::begin_frame`], but with the
+    /// [`crate::style::Interaction::max_double_click_delay`] of the current style.
+    #[must_use]
+    pub(crate) fn begin_frame_with_double_click_delay(
+        mut self,
+        new: RawInput,
+        requested_repaint_last_frame: bool,
+        max_double_click_delay: f64,
+    ) -> InputState {
         let time = new.time.unwrap_or(self.time + new.predicted_dt as f64);
         let unstable_dt = (time - self.time) as f32;
 
@@ -150,12 +178,16 @@ impl InputState {
             new.predicted_dt
         };
 
+        let input_latency = new
+            .oldest_event_time
+            .map(|event_time| (time - event_time).max(0.0) as f32);
+
         let screen_rect = new.screen_rect.unwrap_or(self.screen_rect);
         self.create_touch_states_for_new_devices(&new.events);
         for touch_state in self.touch_states.values_mut() {
             touch_state.begin_frame(time, &new, self.pointer.interact_pos);
         }
-        let pointer = self.pointer.begin_frame(time, &new);
+        let pointer = self.pointer.begin_frame(time, &new, max_double_click_delay);
 
         let mut keys_down = self.keys_down;
         let mut scroll_delta = Vec2::ZERO;
@@ -186,10 +218,12 @@ impl InputState {
             screen_rect,
             pixels_per_point: new.pixels_per_point.unwrap_or(self.pixels_per_point),
             max_texture_side: new.max_texture_side.unwrap_or(self.max_texture_side),
+            system_dark_mode: new.system_dark_mode.or(self.system_dark_mode),
             time,
             unstable_dt,
             predicted_dt: new.predicted_dt,
             stable_dt,
+            input_latency,
             modifiers: new.modifiers,
             keys_down,
             events: new.events.clone(), // TODO(emilk): remove clone() and use raw.events
@@ -241,6 +275,24 @@ impl InputState {
         )
     }
 
+    /// How many points the user scrolled or panned this frame, either with the scroll wheel
+    /// or by moving two or more fingers on a touch screen.
+    ///
+    /// Use this for panning things like maps and plots.
+    #[inline(always)]
+    pub fn translation_delta(&self) -> Vec2 {
+        self.multi_touch()
+            .map_or(self.scroll_delta, |touch| touch.translation_delta)
+    }
+
+    /// Rotation in radians this frame (e.g. from a two-finger rotation gesture).
+    ///
+    /// This is only non-zero for multi-touch gestures.
+    #[inline(always)]
+    pub fn rotation_delta(&self) -> f32 {
+        self.multi_touch().map_or(0.0, |touch| touch.rotation_delta)
+    }
+
     pub fn wants_repaint(&self) -> bool {
         self.pointer.wants_repaint() || self.scroll_delta != Vec2::ZERO || !self.events.is_empty()
     }
@@ -267,6 +319,27 @@ impl InputState {
         match_found
     }
 
+    /// Check if the given shortcut has been pressed.
+    ///
+    /// If so, `true` is returned and the key pressed is consumed, so that this will only return `true` once.
+    /// Call this before adding any widgets that may react to the same keys,
+    /// e.g. at the start of the frame for global shortcuts.
+    /// If a focused [`crate::TextEdit`] should get the keys instead,
+    /// check [`crate::Context::wants_keyboard_input`] first.
+    ///
+    /// ```
+    /// # let ctx = egui::Context::default();
+    /// const SAVE: egui::KeyboardShortcut =
+    ///     egui::KeyboardShortcut::new(egui::Modifiers::COMMAND, egui::Key::S);
+    /// if ctx.input_mut().consume_shortcut(&SAVE) {
+    ///     // save
+    /// }
+    /// ```
+    pub fn consume_shortcut(&mut self, shortcut: &KeyboardShortcut) -> bool {
+        let KeyboardShortcut { modifiers, key } = *shortcut;
+        self.consume_key(modifiers, key)
+    }
+
     /// Was the given key pressed this frame?
     pub fn key_pressed(&self, desired_key: Key) -> bool {
         self.num_presses(desired_key) > 0
@@ -411,7 +484,10 @@ pub(crate) enum PointerEvent {
         position: Pos2,
         button: PointerButton,
     },
-    Released(Option<Click>),
+    Released {
+        click: Option<Click>,
+        button: PointerButton,
+    },
 }
 
 impl PointerEvent {
@@ -419,10 +495,10 @@ impl PointerEvent {
         matches!(self, PointerEvent::Pressed { .. })
     }
     pub fn is_release(&self) -> bool {
-        matches!(self, PointerEvent::Released(_))
+        matches!(self, PointerEvent::Released { .. })
     }
     pub fn is_click(&self) -> bool {
-        matches!(self, PointerEvent::Released(Some(_click)))
+        matches!(self, PointerEvent::Released { click: Some(_), .. })
     }
 }
 
@@ -453,6 +529,10 @@ pub struct PointerState {
     /// Current velocity of pointer.
     velocity: Vec2,
 
+    /// The axis (0 = x, 1 = y) the pointer has moved the most along
+    /// during the current (or last) click/drag.
+    dominant_axis: usize,
+
     /// Recent movement of the pointer.
     /// Used for calculating velocity of pointer.
     pos_history: History<Pos2>,
@@ -491,6 +571,7 @@ impl Default for PointerState {
             interact_pos: None,
             delta: Vec2::ZERO,
             velocity: Vec2::ZERO,
+            dominant_axis: 0,
             pos_history: History::new(0..1000, 0.1),
             down: Default::default(),
             press_origin: None,
@@ -505,7 +586,13 @@ impl Default for PointerState {
 
 impl PointerState {
     #[must_use]
-    pub(crate) fn begin_frame(mut self, time: f64, new: &RawInput) -> PointerState {
+    pub(crate) fn begin_frame(
+        mut self,
+        time: f64,
+        new: &RawInput,
+        max_double_click_delay: f64,
+    ) -> PointerState {
+        let prev_time = self.time;
         self.time = time;
 
         self.pointer_events.clear();
@@ -513,6 +600,16 @@ impl PointerState {
         let old_pos = self.latest_pos;
         self.interact_pos = self.latest_pos;
 
+        // The integration only gives us the events once per frame, without timestamps.
+        // To get a decent velocity estimate at low frame rates we spread
+        // the movement events evenly over the time since last frame.
+        let num_moves = new
+            .events
+            .iter()
+            .filter(|event| matches!(event, Event::PointerMoved(_)))
+            .count();
+        let mut move_idx = 0;
+
         for event in &new.events {
             match event {
                 Event::PointerMoved(pos) => {
@@ -521,9 +618,16 @@ impl PointerState {
                     self.latest_pos = Some(pos);
                     self.interact_pos = Some(pos);
 
+                    move_idx += 1;
+                    self.pos_history
+                        .add(sub_frame_time(prev_time, time, move_idx, num_moves), pos);
+
                     if let Some(press_origin) = self.press_origin {
                         self.has_moved_too_much_for_a_click |=
                             press_origin.distance(pos) > MAX_CLICK_DIST;
+
+                        let drag = pos - press_origin;
+                        self.dominant_axis = if drag.x.abs() >= drag.y.abs() { 0 } else { 1 };
                     }
 
                     self.pointer_events.push(PointerEvent::Moved(pos));
@@ -561,9 +665,9 @@ impl PointerState {
 
                         let click = if clicked {
                             let double_click =
-                                (time - self.last_click_time) < MAX_DOUBLE_CLICK_DELAY;
+                                (time - self.last_click_time) < max_double_click_delay;
                             let triple_click =
-                                (time - self.last_last_click_time) < (MAX_DOUBLE_CLICK_DELAY * 2.0);
+                                (time - self.last_last_click_time) < (max_double_click_delay * 2.0);
                             let count = if triple_click {
                                 3
                             } else if double_click {
@@ -585,7 +689,8 @@ impl PointerState {
                             None
                         };
 
-                        self.pointer_events.push(PointerEvent::Released(click));
+                        self.pointer_events
+                            .push(PointerEvent::Released { click, button });
 
                         self.press_origin = None;
                         self.press_start_time = None;
@@ -608,7 +713,11 @@ impl PointerState {
         };
 
         if let Some(pos) = self.latest_pos {
-            self.pos_history.add(time, pos);
+            if num_moves == 0 {
+                // The pointer stayed put this frame. Record that, so the velocity goes to zero
+                // when the pointer stops (the moves themselves are already recorded above).
+                self.pos_history.add(time, pos);
+            }
         } else {
             // we do not clear the `pos_history` here, because it is exactly when a finger has
             // released from the touch screen that we may want to assign a velocity to whatever
@@ -636,12 +745,48 @@ impl PointerState {
         self.delta
     }
 
+    /// Like [`Self::delta`], but only along the axis the pointer
+    /// has moved the most since the current click/drag started.
+    ///
+    /// Same as [`Self::delta`] if no mouse button is down.
+    pub fn dominant_axis_delta(&self) -> Vec2 {
+        if self.press_origin.is_some() {
+            let mut delta = Vec2::ZERO;
+            delta[self.dominant_axis] = self.delta[self.dominant_axis];
+            delta
+        } else {
+            self.delta
+        }
+    }
+
     /// Current velocity of pointer.
     #[inline(always)]
     pub fn velocity(&self) -> Vec2 {
         self.velocity
     }
 
+    /// Like [`Self::velocity`], but only along the axis the pointer
+    /// has moved the most during the current (or just released) click/drag.
+    ///
+    /// Use this for flinging things after a drag that was locked with [`Self::dominant_axis_delta`].
+    pub fn dominant_axis_velocity(&self) -> Vec2 {
+        let mut velocity = Vec2::ZERO;
+        velocity[self.dominant_axis] = self.velocity[self.dominant_axis];
+        velocity
+    }
+
+    /// Where we expect the pointer to be `dt` seconds from now,
+    /// extrapolated from the current [`Self::velocity`].
+    ///
+    /// This can be used to hide some of the input latency when dragging things around,
+    /// e.g. by passing in [`InputState::predicted_dt`].
+    ///
+    /// `None` if there is no pointer.
+    #[inline]
+    pub fn predicted_pos(&self, dt: f32) -> Option<Pos2> {
+        self.latest_pos.map(|pos| pos + dt * self.velocity)
+    }
+
     /// Where did the current click/drag originate?
     /// `None` if no mouse button is down.
     #[inline(always)]
@@ -717,7 +862,7 @@ impl PointerState {
     pub fn button_released(&self, button: PointerButton) -> bool {
         self.pointer_events
             .iter()
-            .any(|event| matches!(event, &PointerEvent::Released(Some(Click{button: b, ..})) if button == b))
+            .any(|event| matches!(event, &PointerEvent::Released { button: b, .. } if button == b))
     }
 
     /// Was the primary button released this frame?
@@ -730,6 +875,11 @@ impl PointerState {
         self.button_released(PointerButton::Secondary)
     }
 
+    /// Was the middle button released this frame?
+    pub fn middle_released(&self) -> bool {
+        self.button_released(PointerButton::Middle)
+    }
+
     /// Is any pointer button currently down?
     pub fn any_down(&self) -> bool {
         self.down.iter().any(|&down| down)
@@ -757,17 +907,34 @@ impl PointerState {
         self.button_clicked(PointerButton::Secondary)
     }
 
-    // /// Was this button pressed (`!down -> down`) this frame?
-    // /// This can sometimes return `true` even if `any_down() == false`
-    // /// because a press can be shorted than one frame.
-    // pub fn button_pressed(&self, button: PointerButton) -> bool {
-    //     self.pointer_events.iter().any(|event| event.is_press())
-    // }
+    /// Was the middle button clicked this frame?
+    pub fn middle_clicked(&self) -> bool {
+        self.button_clicked(PointerButton::Middle)
+    }
 
-    // /// Was this button released (`down -> !down`) this frame?
-    // pub fn button_released(&self, button: PointerButton) -> bool {
-    //     self.pointer_events.iter().any(|event| event.is_release())
-    // }
+    /// Was this button pressed (`!down -> down`) this frame?
+    /// This can sometimes return `true` even if `button_down(button) == false`
+    /// because a press can be shorted than one frame.
+    pub fn button_pressed(&self, button: PointerButton) -> bool {
+        self.pointer_events
+            .iter()
+            .any(|event| matches!(event, &PointerEvent::Pressed { button: b, .. } if button == b))
+    }
+
+    /// Was the primary button pressed this frame?
+    pub fn primary_pressed(&self) -> bool {
+        self.button_pressed(PointerButton::Primary)
+    }
+
+    /// Was the secondary button pressed this frame?
+    pub fn secondary_pressed(&self) -> bool {
+        self.button_pressed(PointerButton::Secondary)
+    }
+
+    /// Was the middle button pressed this frame?
+    pub fn middle_pressed(&self) -> bool {
+        self.button_pressed(PointerButton::Middle)
+    }
 
     /// Is this button currently down?
     #[inline(always)]
@@ -814,6 +981,16 @@ impl PointerState {
     }
 }
 
+/// Estimated time of event number `idx` (one-based) out of `count` events
+/// that arrived in the frame between `prev_time` and `time`.
+fn sub_frame_time(prev_time: f64, time: f64, idx: usize, count: usize) -> f64 {
+    if prev_time.is_finite() && prev_time < time && count > 0 {
+        lerp(prev_time..=time, idx as f64 / count as f64)
+    } else {
+        time
+    }
+}
+
 impl InputState {
     pub fn ui(&self, ui: &mut crate::Ui) {
         let Self {
@@ -825,10 +1002,12 @@ impl InputState {
             screen_rect,
             pixels_per_point,
             max_texture_side,
+            system_dark_mode,
             time,
             unstable_dt,
             predicted_dt,
             stable_dt,
+            input_latency,
             modifiers,
             keys_down,
             events,
@@ -865,6 +1044,7 @@ impl InputState {
             "max texture size (on each side): {}",
             max_texture_side
         ));
+        ui.label(format!("system_dark_mode: {:?}", system_dark_mode));
         ui.label(format!("time: {:.3} s", time));
         ui.label(format!(
             "time since previous frame: {:.1} ms",
@@ -872,6 +1052,11 @@ impl InputState {
         ));
         ui.label(format!("predicted_dt: {:.1} ms", 1e3 * predicted_dt));
         ui.label(format!("stable_dt:    {:.1} ms", 1e3 * stable_dt));
+        if let Some(input_latency) = input_latency {
+            ui.label(format!("input_latency: {:.1} ms", 1e3 * input_latency));
+        } else {
+            ui.label("input_latency: None");
+        }
         ui.label(format!("modifiers: {:#?}", modifiers));
         ui.label(format!("keys_down: {:?}", keys_down));
         ui.scope(|ui| {
@@ -890,6 +1075,7 @@ impl PointerState {
             interact_pos,
             delta,
             velocity,
+            dominant_axis: _,
             pos_history: _,
             down,
             press_origin,
@@ -919,3 +1105,202 @@ impl PointerState {
         ui.label(format!("pointer_events: {:?}", pointer_events));
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn pointer_velocity_at_low_frame_rate() {
+        let speed = 100.0; // points per second
+        let dt = 0.1; // 10 fps
+        let moves_per_frame = 4;
+
+        let mut pointer = PointerState::default();
+        for frame in 1..=5 {
+            let frame_start = (frame - 1) as f64 * dt;
+            let events = (1..=moves_per_frame)
+                .map(|i| {
+                    let t = frame_start + dt * i as f64 / moves_per_frame as f64;
+                    Event::PointerMoved(pos2(speed * t as f32, 0.0))
+                })
+                .collect();
+            let raw_input = RawInput {
+                events,
+                ..Default::default()
+            };
+            pointer = pointer.begin_frame(frame as f64 * dt, &raw_input, 0.3);
+        }
+
+        assert!((pointer.velocity().x - speed).abs() < 1.0);
+        assert_eq!(pointer.velocity().y, 0.0);
+
+        let predicted = pointer.predicted_pos(0.5).unwrap();
+        assert!((predicted.x - pointer.hover_pos().unwrap().x - 0.5 * speed).abs() < 1.0);
+    }
+
+    #[test]
+    fn pointer_history_has_one_sample_per_move() {
+        let frame = |pointer: PointerState, time: f64, events: Vec<Event>| {
+            let raw_input = RawInput {
+                events,
+                ..Default::default()
+            };
+            pointer.begin_frame(time, &raw_input, 0.3)
+        };
+
+        let pointer = frame(PointerState::default(), 0.0, vec![]);
+        let moves = (1..=4)
+            .map(|i| Event::PointerMoved(pos2(10.0 * i as f32, 0.0)))
+            .collect();
+        let pointer = frame(pointer, 0.04, moves);
+        assert_eq!(pointer.pos_history.len(), 4);
+        assert!(pointer.velocity().x > 0.0);
+
+        // Holding the pointer still brings the velocity down to zero:
+        let mut pointer = pointer;
+        for i in 1..=10 {
+            pointer = frame(pointer, 0.04 + 0.02 * i as f64, vec![]);
+        }
+        assert_eq!(pointer.velocity(), Vec2::ZERO);
+    }
+
+    #[test]
+    fn dominant_axis_after_release() {
+        let button_event = |pos: Pos2, pressed: bool| Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Default::default(),
+        };
+        let frame = |pointer: PointerState, time: f64, events: Vec<Event>| {
+            let raw_input = RawInput {
+                events,
+                ..Default::default()
+            };
+            pointer.begin_frame(time, &raw_input, 0.3)
+        };
+
+        let mut pointer = frame(
+            PointerState::default(),
+            0.0,
+            vec![button_event(pos2(0.0, 0.0), true)],
+        );
+        for i in 1..=5 {
+            let pos = pos2(10.0 * i as f32, 2.0 * i as f32);
+            pointer = frame(pointer, 0.02 * i as f64, vec![Event::PointerMoved(pos)]);
+            assert_eq!(pointer.dominant_axis_delta(), vec2(10.0, 0.0));
+        }
+        let pointer = frame(pointer, 0.12, vec![button_event(pos2(50.0, 10.0), false)]);
+        assert!(pointer.velocity().y > 0.0);
+        assert!(pointer.dominant_axis_velocity().x > 0.0);
+        assert_eq!(pointer.dominant_axis_velocity().y, 0.0);
+    }
+
+    #[test]
+    fn middle_button_drag_release() {
+        let button_event = |x: f32, pressed: bool| Event::PointerButton {
+            pos: pos2(x, 0.0),
+            button: PointerButton::Middle,
+            pressed,
+            modifiers: Default::default(),
+        };
+        let frame = |pointer: PointerState, time: f64, events: Vec<Event>| {
+            let raw_input = RawInput {
+                events,
+                ..Default::default()
+            };
+            pointer.begin_frame(time, &raw_input, 0.3)
+        };
+
+        let pointer = frame(PointerState::default(), 0.0, vec![button_event(0.0, true)]);
+        assert!(pointer.middle_pressed());
+        assert!(pointer.middle_down());
+        assert!(!pointer.primary_pressed());
+
+        let pointer = frame(pointer, 0.1, vec![Event::PointerMoved(pos2(50.0, 0.0))]);
+        let pointer = frame(pointer, 0.2, vec![button_event(50.0, false)]);
+        assert!(pointer.middle_released());
+        assert!(!pointer.middle_pressed());
+        assert!(!pointer.middle_down());
+    }
+
+    #[test]
+    fn input_latency() {
+        let input = InputState::default().begin_frame(
+            RawInput {
+                time: Some(1.0),
+                oldest_event_time: Some(0.99),
+                ..Default::default()
+            },
+            false,
+        );
+        assert!((input.input_latency.unwrap() - 0.01).abs() < 1e-4);
+
+        let input = input.begin_frame(
+            RawInput {
+                time: Some(2.0),
+                ..Default::default()
+            },
+            false,
+        );
+        assert_eq!(input.input_latency, None);
+    }
+
+    #[test]
+    fn no_clicks_or_text_dropped_within_one_frame() {
+        use crate::{Harness, Sense};
+
+        let mut harness = Harness::new();
+        let pos = pos2(100.0, 100.0);
+
+        // A whole click, and two characters, all arriving between two frames:
+        harness.press(pos);
+        harness.type_text("a");
+        harness.release(pos);
+        harness.type_text("b");
+
+        let (clicked, text) = harness.run_ui(|ui| {
+            let clicked = ui.allocate_rect(ui.max_rect(), Sense::click()).clicked();
+            let mut text = String::new();
+            for event in &ui.input().events {
+                if let Event::Text(t) = event {
+                    text += t;
+                }
+            }
+            (clicked, text)
+        });
+        assert!(clicked);
+        assert_eq!(text, "ab");
+    }
+
+    #[test]
+    fn pixels_per_point_change() {
+        use crate::Harness;
+
+        // Returns the size of a label, and if any textures were uploaded:
+        let run = |harness: &mut Harness| {
+            let text_size = harness.run_ui(|ui| ui.label("Hello HiDPI").rect.size());
+            (text_size, !harness.output().textures_delta.set.is_empty())
+        };
+
+        let mut harness = Harness::new();
+        let (size_1x, uploaded) = run(&mut harness);
+        assert!(uploaded, "Font atlas is uploaded");
+        let (_, uploaded) = run(&mut harness);
+        assert!(!uploaded);
+
+        let mut harness = harness.with_pixels_per_point(2.0);
+        let (size_2x, uploaded) = run(&mut harness);
+        assert_eq!(harness.ctx().fonts().pixels_per_point(), 2.0);
+        assert!(uploaded, "Font atlas is regenerated at the new scale");
+
+        // Layout happens in points, so the size should be (almost) the same:
+        assert!(
+            (size_1x - size_2x).length() < 2.0,
+            "{:?} vs {:?}",
+            size_1x,
+            size_2x
+        );
+    }
+}
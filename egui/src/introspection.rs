@@ -131,6 +131,108 @@ impl Widget for &epaint::stats::PaintStats {
     }
 }
 
+/// Show the given [`FrameTimings`] and a rolling graph of the `history` of recent frames.
+pub(crate) fn frame_timings_ui(
+    ui: &mut Ui,
+    timings: &FrameTimings,
+    history: &[(f64, FrameTimings)],
+) {
+    if cfg!(target_arch = "wasm32") {
+        ui.label("Frame timings are not available on the web.");
+        return;
+    }
+
+    let FrameTimings {
+        begin_frame,
+        ui: ui_time,
+        text_layout,
+        end_frame,
+        tessellation,
+    } = *timings;
+
+    // (name, time, color), stacked from the bottom of the graph:
+    let stages = |timings: &FrameTimings| {
+        [
+            (
+                "begin_frame",
+                timings.begin_frame,
+                Color32::from_rgb(100, 150, 250),
+            ),
+            (
+                "ui",
+                timings.ui - timings.text_layout,
+                Color32::from_rgb(100, 200, 100),
+            ),
+            (
+                "text layout",
+                timings.text_layout,
+                Color32::from_rgb(220, 200, 80),
+            ),
+            (
+                "end_frame",
+                timings.end_frame,
+                Color32::from_rgb(230, 130, 70),
+            ),
+            (
+                "tessellation",
+                timings.tessellation,
+                Color32::from_rgb(200, 100, 200),
+            ),
+        ]
+    };
+
+    ui.scope(|ui| {
+        ui.style_mut().override_text_style = Some(TextStyle::Monospace);
+        ui.label(format!("{:6.2} ms begin_frame (input)", 1e3 * begin_frame));
+        ui.label(format!("{:6.2} ms ui", 1e3 * ui_time));
+        ui.label(format!(
+            "{:6.2} ms   of which text layout",
+            1e3 * text_layout
+        ));
+        ui.label(format!("{:6.2} ms end_frame", 1e3 * end_frame));
+        ui.label(format!("{:6.2} ms tessellation", 1e3 * tessellation));
+        ui.label(format!("{:6.2} ms total", 1e3 * timings.total()));
+    });
+
+    ui.horizontal_wrapped(|ui| {
+        for (name, _, color) in stages(timings) {
+            ui.colored_label(color, format!("■ {}", name));
+        }
+    });
+
+    let height = ui.spacing().slider_width / 2.0;
+    let size = vec2(ui.available_size_before_wrap().x, height);
+    let (rect, response) = ui.allocate_at_least(size, Sense::hover());
+    let painter = ui.painter_at(rect);
+    painter.rect_filled(rect, 0.0, ui.visuals().extreme_bg_color);
+
+    let max_total = history
+        .iter()
+        .map(|(_, timings)| timings.total())
+        .fold(1e-3, f32::max);
+    let rect = rect.shrink(2.0);
+    let bar_width = (rect.width() / history.len().max(1) as f32).clamp(1.0, 4.0);
+
+    // Newest frame to the right:
+    for (i, (_, timings)) in history.iter().rev().enumerate() {
+        let x = rect.right() - (i as f32 + 0.5) * bar_width;
+        if x < rect.left() {
+            break;
+        }
+        let mut y = rect.bottom();
+        for (_, time, color) in stages(timings) {
+            let bar_height = rect.height() * time.max(0.0) / max_total;
+            painter.line_segment(
+                [pos2(x, y), pos2(x, y - bar_height)],
+                Stroke::new(bar_width, color),
+            );
+            y -= bar_height;
+        }
+    }
+
+    response.on_hover_text(format!("Top of graph: {:.2} ms", 1e3 * max_total));
+}
+
 fn label(ui: &mut Ui, alloc_info: &epaint::stats::AllocInfo, what: &str) -> Response {
     ui.add(Label::new(alloc_info.format(what)).wrap(false))
 }
@@ -144,6 +246,7 @@ impl Widget for &mut epaint::TessellationOptions {
                 coarse_tessellation_culling,
                 prerasterized_discs,
                 round_text_to_pixels,
+                round_rects_to_pixels,
                 debug_paint_clip_rects,
                 debug_paint_text_rects,
                 debug_ignore_clip_rects,
@@ -161,6 +264,9 @@ impl Widget for &mut epaint::TessellationOptions {
 
             ui.checkbox(prerasterized_discs, "Speed up filled circles with pre-rasterization");
 
+            ui.checkbox(round_rects_to_pixels, "Align rectangles to pixel grid")
+                .on_hover_text("Makes thin rectangle outlines crisp, but rectangles may move by up to half a pixel.");
+
             ui.add(
                 crate::widgets::Slider::new(bezier_tolerance, 0.0001..=10.0)
                     .logarithmic(true)
@@ -190,9 +296,61 @@ impl Widget for &memory::Interaction {
             ui.label(format!("click_id: {:?}", self.click_id));
             ui.label(format!("drag_id: {:?}", self.drag_id));
             ui.label(format!("drag_is_window: {:?}", self.drag_is_window));
+            ui.label(format!("focus: {:?}", self.focus.focused()));
             ui.label(format!("click_interest: {:?}", self.click_interest));
             ui.label(format!("drag_interest: {:?}", self.drag_interest));
         })
         .response
     }
 }
+
+#[cfg(test)]
+#[test]
+fn inspect_on_hover() {
+    let mut harness = Harness::new();
+    let mut style = (*harness.ctx().style()).clone();
+    style.debug.inspect_on_hover = Some(Modifiers::ALT);
+    harness.ctx().set_style(style);
+
+    let overlay_text = |harness: &Harness| {
+        harness
+            .output()
+            .shapes
+            .iter()
+            .find_map(|clipped| match &clipped.1 {
+                Shape::Text(text) if text.galley.job.text.starts_with("id:") => {
+                    Some(text.galley.job.text.clone())
+                }
+                _ => None,
+            })
+    };
+
+    let button = harness.run_ui(|ui| ui.button("Inspect me"));
+    harness.move_mouse(button.rect.center());
+    harness.run_ui(|ui| ui.button("Inspect me"));
+    assert_eq!(overlay_text(&harness), None, "Alt is not held");
+
+    harness.set_modifiers(Modifiers::ALT);
+    harness.move_mouse(button.rect.center());
+    harness.run_ui(|ui| ui.button("Inspect me"));
+    let text = overlay_text(&harness).unwrap();
+    assert!(text.contains(&button.id.short_debug_format()));
+    assert!(text.contains(&button.layer_id.short_debug_format()));
+    assert!(text.contains('└'), "The central panel contains the button");
+}
+
+#[cfg(test)]
+#[test]
+fn introspection_windows() {
+    let mut harness = Harness::new().with_screen_size(vec2(1024.0, 4096.0));
+    for _ in 0..2 {
+        harness.run(|ctx| {
+            Window::new("Inspection").show(ctx, |ui| ctx.inspection_ui(ui));
+            Window::new("Memory").show(ctx, |ui| ctx.memory_ui(ui));
+            Window::new("Input").show(ctx, |ui| ctx.input_ui(ui));
+            Window::new("Font atlas").show(ctx, |ui| ctx.font_atlas_ui(ui));
+            Window::new("Layers").show(ctx, |ui| ctx.layers_ui(ui));
+        });
+    }
+    assert_eq!(harness.ctx().memory().areas.visible_windows().len(), 5);
+}
@@ -26,7 +26,7 @@ pub enum Order {
 
 impl Order {
     const COUNT: usize = 6;
-    const ALL: [Order; Self::COUNT] = [
+    pub(crate) const ALL: [Order; Self::COUNT] = [
         Self::Background,
         Self::PanelResizeLine,
         Self::Middle,
@@ -161,32 +161,77 @@ impl GraphicLayers {
             .or_default()
     }
 
-    pub fn drain(&mut self, area_order: &[LayerId]) -> impl ExactSizeIterator<Item = ClippedShape> {
-        let mut all_shapes: Vec<_> = Default::default();
-
-        for &order in &Order::ALL {
-            let order_map = &mut self.0[order as usize];
-
+    /// Move all shapes out of the layers, in paint order.
+    ///
+    /// For each non-empty layer, its [`LayerId`] and number of shapes are pushed to `layer_lengths`, in the same order.
+    ///
+    /// The per-layer buffers keep their capacity, so next frame can paint into them
+    /// without reallocating.
+    pub fn drain(
+        &mut self,
+        area_order: &[LayerId],
+        layer_lengths: &mut Vec<(LayerId, usize)>,
+    ) -> impl ExactSizeIterator<Item = ClippedShape> {
+        for order_map in &mut self.0 {
             // If a layer is empty at the start of the frame
             // then nobody has added to it, and it is old and defunct.
             // Free it to save memory:
             order_map.retain(|_, list| !list.is_empty());
+        }
+
+        let num_shapes = self
+            .0
+            .iter()
+            .flat_map(|order_map| order_map.values())
+            .map(|list| list.0.len())
+            .sum();
+        let mut all_shapes: Vec<_> = Vec::with_capacity(num_shapes);
+
+        for &order in &Order::ALL {
+            let order_map = &mut self.0[order as usize];
 
             // First do the layers part of area_order:
             for layer_id in area_order {
                 if layer_id.order == order {
                     if let Some(list) = order_map.get_mut(&layer_id.id) {
-                        all_shapes.append(&mut list.0);
+                        if !list.is_empty() {
+                            layer_lengths.push((*layer_id, list.0.len()));
+                            all_shapes.append(&mut list.0);
+                        }
                     }
                 }
             }
 
             // Also draw areas that are missing in `area_order`:
-            for shapes in order_map.values_mut() {
-                all_shapes.append(&mut shapes.0);
+            for (&id, list) in order_map.iter_mut() {
+                if !list.is_empty() {
+                    layer_lengths.push((LayerId::new(order, id), list.0.len()));
+                    all_shapes.append(&mut list.0);
+                }
             }
         }
 
         all_shapes.into_iter()
     }
 }
+
+#[cfg(test)]
+#[test]
+fn drain_keeps_layer_capacity() {
+    let layer_id = LayerId::background();
+    let mut layers = GraphicLayers::default();
+    for _ in 0..100 {
+        layers.list(layer_id).add(Rect::EVERYTHING, Shape::Noop);
+    }
+    let capacity = layers.list(layer_id).0.capacity();
+
+    let mut layer_lengths = vec![];
+    let shapes = layers.drain(&[layer_id], &mut layer_lengths);
+    assert_eq!(shapes.len(), 100);
+    assert_eq!(layer_lengths, vec![(layer_id, 100)]);
+    drop(shapes);
+
+    // The buffer is ready for re-use next frame:
+    assert!(layers.list(layer_id).is_empty());
+    assert_eq!(layers.list(layer_id).0.capacity(), capacity);
+}
@@ -229,6 +229,20 @@ impl Layout {
         }
     }
 
+    /// Wrap to a new row (or column) when reaching the end of the available space,
+    /// e.g. for a toolbar or a list of tags.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.with_layout(egui::Layout::left_to_right().with_main_wrap(true), |ui| {
+    ///     for tag in ["rust", "gui", "immediate mode", "wasm"] {
+    ///         ui.button(tag);
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    ///
+    /// See also [`crate::Ui::horizontal_wrapped`].
     #[inline(always)]
     pub fn with_main_wrap(self, main_wrap: bool) -> Self {
         Self { main_wrap, ..self }
@@ -242,6 +256,12 @@ impl Layout {
         }
     }
 
+    /// How to align things on the main axis, e.g. within a justified area.
+    #[inline(always)]
+    pub fn with_main_align(self, main_align: Align) -> Self {
+        Self { main_align, ..self }
+    }
+
     #[inline(always)]
     pub fn with_main_justify(self, main_justify: bool) -> Self {
         Self {
@@ -271,6 +291,16 @@ impl Layout {
         self.main_wrap
     }
 
+    #[inline(always)]
+    pub fn main_align(&self) -> Align {
+        self.main_align
+    }
+
+    #[inline(always)]
+    pub fn main_justify(&self) -> bool {
+        self.main_justify
+    }
+
     #[inline(always)]
     pub fn cross_align(&self) -> Align {
         self.cross_align
@@ -728,7 +758,7 @@ impl Layout {
     /// Move to the next row in a wrapping layout.
     /// Otherwise does nothing.
     pub(crate) fn end_row(&mut self, region: &mut Region, spacing: Vec2) {
-        if self.main_wrap {
+        if self.main_wrap || self.is_horizontal() {
             match self.main_dir {
                 Direction::LeftToRight => {
                     let new_top = region.cursor.bottom() + spacing.y;
@@ -746,6 +776,12 @@ impl Layout {
                 }
                 Direction::TopDown | Direction::BottomUp => {}
             }
+
+            if !self.main_wrap {
+                // Unlike wrapping layouts, we only place widgets within the `max_rect`,
+                // so make sure the new row fits:
+                region.max_rect.max.y = region.max_rect.max.y.max(region.cursor.max.y);
+            }
         }
     }
 
@@ -800,3 +836,39 @@ impl Layout {
         painter.debug_text(next_pos, align, stroke.color, text);
     }
 }
+
+#[cfg(test)]
+#[test]
+fn layout_directions() {
+    use crate::Harness;
+
+    Harness::new().run_ui(|ui| {
+        let max_rect = ui.max_rect();
+
+        let buttons = ui
+            .with_layout(Layout::right_to_left(), |ui| {
+                let ok = ui.button("Ok").rect;
+                let cancel = ui.button("Cancel").rect;
+                (ok, cancel)
+            })
+            .inner;
+        assert_eq!(buttons.0.right(), max_rect.right());
+        assert!(buttons.1.right() < buttons.0.left());
+
+        let status = ui
+            .with_layout(Layout::bottom_up(Align::LEFT), |ui| ui.label("Status").rect)
+            .inner;
+        assert!(max_rect.bottom() - status.bottom() < ui.spacing().interact_size.y);
+        assert_eq!(status.left(), max_rect.left());
+
+        let layout = Layout::top_down(Align::Center)
+            .with_main_wrap(true)
+            .with_main_align(Align::Max)
+            .with_main_justify(true)
+            .with_cross_justify(true);
+        assert!(layout.main_wrap());
+        assert_eq!(layout.main_align(), Align::Max);
+        assert!(layout.main_justify());
+        assert!(layout.cross_justify());
+    });
+}
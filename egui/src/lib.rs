@@ -305,6 +305,9 @@ mod context;
 mod data;
 mod frame_state;
 pub(crate) mod grid;
+#[cfg(feature = "testing")]
+mod harness;
+mod hit_shape;
 mod id;
 mod input_state;
 pub mod introspection;
@@ -317,6 +320,7 @@ pub(crate) mod placer;
 mod response;
 mod sense;
 pub mod style;
+mod tessellation_cache;
 mod ui;
 pub mod util;
 mod widget_text;
@@ -325,7 +329,10 @@ pub mod widgets;
 pub use epaint;
 pub use epaint::emath;
 
-pub use emath::{lerp, pos2, remap, remap_clamp, vec2, Align, Align2, NumExt, Pos2, Rect, Vec2};
+pub use emath::{
+    easing, inverse_lerp, lerp, pos2, remap, remap_clamp, vec2, Align, Align2, NumExt, Pos2, Rect,
+    Vec2,
+};
 #[cfg(feature = "color-hex")]
 pub use epaint::hex_color;
 pub use epaint::{
@@ -333,7 +340,7 @@ pub use epaint::{
     text::{FontData, FontDefinitions, FontFamily, FontId, FontTweak},
     textures::{TextureFilter, TexturesDelta},
     ClippedPrimitive, Color32, ColorImage, FontImage, ImageData, Mesh, PaintCallback,
-    PaintCallbackInfo, Rgba, Rounding, Shape, Stroke, TextureHandle, TextureId,
+    PaintCallbackInfo, PixelRect, Rgba, Rounding, Shape, Stroke, TextureHandle, TextureId,
 };
 
 pub mod text {
@@ -349,14 +356,19 @@ pub use {
     context::Context,
     data::{
         input::*,
-        output::{self, CursorIcon, FullOutput, PlatformOutput, WidgetInfo},
+        output::{
+            self, AccessibilityNode, CursorIcon, FrameTimings, FullOutput, PlatformOutput,
+            WidgetInfo,
+        },
     },
+    frame_state::IdClash,
     grid::Grid,
+    hit_shape::HitShape,
     id::{Id, IdMap},
     input_state::{InputState, MultiTouchInfo, PointerState},
     layers::{LayerId, Order},
     layout::*,
-    memory::Memory,
+    memory::{Memory, UiSnapshot},
     painter::Painter,
     response::{InnerResponse, Response},
     sense::Sense,
@@ -367,6 +379,9 @@ pub use {
     widgets::*,
 };
 
+#[cfg(feature = "testing")]
+pub use harness::Harness;
+
 // ----------------------------------------------------------------------------
 
 /// Helper function that adds a label when compiling with debug assertions enabled.
@@ -375,7 +390,7 @@ pub fn warn_if_debug_build(ui: &mut crate::Ui) {
         ui.label(
             RichText::new("‼ Debug build ‼")
                 .small()
-                .color(crate::Color32::RED),
+                .color(ui.visuals().warn_fg_color),
         )
         .on_hover_text("egui was compiled with debug assertions enabled.");
     }
@@ -515,6 +530,7 @@ pub enum WidgetType {
     ColorButton,
     ImageButton,
     CollapsingHeader,
+    ScrollBar,
 
     /// If you cannot fit any of the above slots.
     ///
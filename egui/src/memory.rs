@@ -1,6 +1,6 @@
 use epaint::ahash::AHashSet;
 
-use crate::{area, window, Id, IdMap, InputState, LayerId, Pos2, Rect, Style};
+use crate::{area, window, Id, IdMap, InputState, LayerId, Pos2, Rect, Style, Visuals};
 
 // ----------------------------------------------------------------------------
 
@@ -10,7 +10,8 @@ use crate::{area, window, Id, IdMap, InputState, LayerId, Pos2, Rect, Style};
 /// how far the user has scrolled in a [`ScrollArea`](crate::ScrollArea) etc.
 ///
 /// If you want this to persist when closing your app you should serialize [`Memory`] and store it.
-/// For this you need to enable the `persistence`.
+/// For this you need to enable the `persistence` feature,
+/// which also adds [`crate::Context::save_memory`] and [`crate::Context::load_memory`].
 ///
 /// If you want to store data for your widgets, you should look at [`Memory::data`]
 #[derive(Clone, Debug, Default)]
@@ -66,6 +67,11 @@ pub struct Memory {
     #[cfg_attr(feature = "persistence", serde(skip))]
     pub(crate) new_pixels_per_point: Option<f32>,
 
+    /// The scale set by the integration or [`crate::Context::set_pixels_per_point`],
+    /// before applying [`Options::zoom_factor`].
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    pub(crate) native_pixels_per_point: Option<f32>,
+
     /// new fonts that will be applied at the start of the next frame
     #[cfg_attr(feature = "persistence", serde(skip))]
     pub(crate) new_font_definitions: Option<epaint::text::FontDefinitions>,
@@ -81,6 +87,9 @@ pub struct Memory {
 
     pub(crate) areas: Areas,
 
+    /// Named workspace layouts, see [`Self::save_layout`].
+    layouts: std::collections::BTreeMap<String, UiSnapshot>,
+
     /// Which popup-window is open (if any)?
     /// Could be a combo box, color picker, menu etc.
     #[cfg_attr(feature = "persistence", serde(skip))]
@@ -88,6 +97,10 @@ pub struct Memory {
 
     #[cfg_attr(feature = "persistence", serde(skip))]
     everything_is_visible: bool,
+
+    /// How many stale states have been forgotten because of [`Options::gc_after_frames`].
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    pub(crate) num_gc_forgotten: usize,
 }
 
 // ----------------------------------------------------------------------------
@@ -104,11 +117,27 @@ pub struct Options {
     /// Controls the tessellator.
     pub tessellation_options: epaint::TessellationOptions,
 
+    /// If true, [`crate::Context::tessellate`] reuses last frame's tessellation
+    /// of the layers (e.g. windows) whose shapes did not change.
+    ///
+    /// This saves time when most of the UI is static,
+    /// but costs a little extra when everything changes every frame.
+    pub cache_tessellation: bool,
+
     /// This does not at all change the behavior of egui,
     /// but is a signal to any backend that we want the [`crate::PlatformOutput::events`] read out loud.
     /// Screen readers is an experimental feature of egui, and not supported on all platforms.
     pub screen_reader: bool,
 
+    /// If `true`, egui describes all widgets shown each frame in [`crate::PlatformOutput::accessibility_nodes`],
+    /// so that a backend can expose them to assistive technologies.
+    ///
+    /// Together with [`Self::screen_reader`], `egui-winit` uses this to also read out
+    /// the name of the window that the keyboard focus moves into.
+    ///
+    /// This costs a little bit of performance, so it is off by default.
+    pub accessibility_tree: bool,
+
     /// If true, the most common glyphs (ASCII) are pre-rendered to the texture atlas.
     ///
     /// Only the fonts in [`Style::text_styles`] will be pre-cached.
@@ -116,6 +145,54 @@ pub struct Options {
     /// This can lead to fewer texture operations, but may use up the texture atlas quicker
     /// if you are changing [`Style::text_styles`], of have a lot of text styles.
     pub preload_font_glyphs: bool,
+
+    /// If true, the arrow keys move keyboard focus to the closest widget in that direction.
+    ///
+    /// Together with Space/Enter for clicking the focused widget, this makes it possible to use
+    /// egui without a mouse, e.g. with a gamepad: have your integration send the D-pad as arrow keys
+    /// and the confirm button as [`crate::Key::Enter`].
+    ///
+    /// Widgets that use the arrow keys themselves when focused (e.g. sliders) keep doing so,
+    /// see [`Memory::capture_arrow_keys`].
+    pub arrow_keys_move_focus: bool,
+
+    /// If true, egui switches between [`Self::dark_visuals`] and [`Self::light_visuals`]
+    /// whenever the backend reports a change in [`crate::RawInput::system_dark_mode`].
+    ///
+    /// Only the [`Visuals`] are switched, the rest of the [`Style`] is left alone.
+    pub follow_system_theme: bool,
+
+    /// The visuals to use when [`Self::follow_system_theme`] switches to dark mode.
+    ///
+    /// When switching away from dark mode, the current visuals are stored here,
+    /// so any changes you made to them are kept for the next time.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub dark_visuals: Visuals,
+
+    /// The visuals to use when [`Self::follow_system_theme`] switches to light mode.
+    ///
+    /// When switching away from light mode, the current visuals are stored here,
+    /// so any changes you made to them are kept for the next time.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub light_visuals: Visuals,
+
+    /// A user zoom factor on top of the native scale of the screen.
+    ///
+    /// The actual [`crate::Context::pixels_per_point`] is the native `pixels_per_point`
+    /// (e.g. from [`crate::RawInput::pixels_per_point`]) times this.
+    /// Use [`crate::Context::set_zoom_factor`] to change it.
+    pub zoom_factor: f32,
+
+    /// If true, `Ctrl/Cmd` + `=`/`+`, `-` and `0` zooms the whole UI in, out,
+    /// and back to the native scale, by changing [`Self::zoom_factor`].
+    pub zoom_with_keyboard: bool,
+
+    /// If set, egui forgets the state of widgets and areas (e.g. scroll offsets, window positions)
+    /// that have not been used for this many frames.
+    ///
+    /// By default egui remembers everything, which means [`Memory`] keeps growing
+    /// if you create a lot of widgets with unique [`Id`]s over time.
+    pub gc_after_frames: Option<u64>,
 }
 
 impl Default for Options {
@@ -123,8 +200,17 @@ impl Default for Options {
         Self {
             style: Default::default(),
             tessellation_options: Default::default(),
+            cache_tessellation: false,
             screen_reader: false,
+            accessibility_tree: false,
             preload_font_glyphs: true,
+            arrow_keys_move_focus: false,
+            follow_system_theme: false,
+            dark_visuals: Visuals::dark(),
+            light_visuals: Visuals::light(),
+            zoom_factor: 1.0,
+            zoom_with_keyboard: true,
+            gc_after_frames: None,
         }
     }
 }
@@ -186,11 +272,62 @@ pub(crate) struct Focus {
     /// If `true`, pressing tab will NOT move focus away from the current widget.
     is_focus_locked: bool,
 
+    /// Was the focus moved to the current widget with the keyboard (i.e. Tab, Shift+Tab or arrow keys)?
+    /// If so, we show a focus ring around it.
+    from_keyboard: bool,
+
     /// Set at the beginning of the frame, set to `false` when "used".
     pressed_tab: bool,
 
     /// Set at the beginning of the frame, set to `false` when "used".
     pressed_shift_tab: bool,
+
+    /// Arrow key pressed this frame, if [`Options::arrow_keys_move_focus`] is on.
+    focus_direction: Option<FocusDirection>,
+
+    /// Does the focused widget use the horizontal/vertical arrow keys this frame?
+    arrow_keys_captured: [bool; 2],
+
+    /// The rectangles of the widgets interested in focus this frame,
+    /// used for moving focus with the arrow keys.
+    focus_candidates: Vec<(Id, Rect)>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum FocusDirection {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl FocusDirection {
+    fn from_key(key: crate::Key) -> Option<Self> {
+        match key {
+            crate::Key::ArrowLeft => Some(Self::Left),
+            crate::Key::ArrowRight => Some(Self::Right),
+            crate::Key::ArrowUp => Some(Self::Up),
+            crate::Key::ArrowDown => Some(Self::Down),
+            _ => None,
+        }
+    }
+
+    /// 0 for horizontal, 1 for vertical.
+    fn axis(self) -> usize {
+        match self {
+            Self::Left | Self::Right => 0,
+            Self::Up | Self::Down => 1,
+        }
+    }
+
+    fn vec(self) -> crate::Vec2 {
+        match self {
+            Self::Left => -crate::Vec2::X,
+            Self::Right => crate::Vec2::X,
+            Self::Up => -crate::Vec2::Y,
+            Self::Down => crate::Vec2::Y,
+        }
+    }
 }
 
 impl Interaction {
@@ -203,6 +340,7 @@ impl Interaction {
         &mut self,
         prev_input: &crate::input_state::InputState,
         new_input: &crate::data::input::RawInput,
+        arrow_keys_move_focus: bool,
     ) {
         self.click_interest = false;
         self.drag_interest = false;
@@ -217,7 +355,7 @@ impl Interaction {
             self.drag_id = None;
         }
 
-        self.focus.begin_frame(new_input);
+        self.focus.begin_frame(new_input, arrow_keys_move_focus);
     }
 }
 
@@ -227,7 +365,11 @@ impl Focus {
         self.id
     }
 
-    fn begin_frame(&mut self, new_input: &crate::data::input::RawInput) {
+    fn begin_frame(
+        &mut self,
+        new_input: &crate::data::input::RawInput,
+        arrow_keys_move_focus: bool,
+    ) {
         self.id_previous_frame = self.id;
         if let Some(id) = self.id_next_frame.take() {
             self.id = Some(id);
@@ -235,6 +377,9 @@ impl Focus {
 
         self.pressed_tab = false;
         self.pressed_shift_tab = false;
+        self.focus_direction = None;
+        self.arrow_keys_captured = [false; 2];
+        self.focus_candidates.clear();
         for event in &new_input.events {
             if matches!(
                 event,
@@ -263,10 +408,29 @@ impl Focus {
                     }
                 }
             }
+
+            if let crate::Event::Key {
+                key,
+                pressed: true,
+                modifiers,
+            } = event
+            {
+                if arrow_keys_move_focus && modifiers.is_none() && !self.is_focus_locked {
+                    if let Some(direction) = FocusDirection::from_key(*key) {
+                        self.focus_direction = Some(direction);
+                    }
+                }
+            }
         }
     }
 
     pub(crate) fn end_frame(&mut self, used_ids: &IdMap<Rect>) {
+        if let Some(direction) = self.focus_direction {
+            if !self.arrow_keys_captured[direction.axis()] {
+                self.move_focus_in_direction(direction);
+            }
+        }
+
         if let Some(id) = self.id {
             // Allow calling `request_focus` one frame and not using it until next frame
             let recently_gained_focus = self.id_previous_frame != Some(id);
@@ -282,10 +446,50 @@ impl Focus {
         self.id_previous_frame == Some(id)
     }
 
+    /// Move focus to the closest widget in the given direction.
+    fn move_focus_in_direction(&mut self, direction: FocusDirection) {
+        let current_rect = self.id.and_then(|focused| {
+            self.focus_candidates
+                .iter()
+                .find(|(id, _)| *id == focused)
+                .map(|(_, rect)| *rect)
+        });
+
+        let new_focus = if let Some(current_rect) = current_rect {
+            let dir = direction.vec();
+            let axis = direction.axis();
+            self.focus_candidates
+                .iter()
+                .filter(|(id, _)| Some(*id) != self.id)
+                .filter_map(|(id, rect)| {
+                    let delta = rect.center() - current_rect.center();
+                    let along = delta.dot(dir);
+                    // Only consider widgets beyond the edge of the focused widget:
+                    if along <= 0.5 * current_rect.size()[axis] {
+                        return None;
+                    }
+                    let across = delta[1 - axis].abs();
+                    // Prefer widgets that are straight ahead:
+                    Some((*id, along + 2.0 * across))
+                })
+                .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+                .map(|(id, _)| id)
+        } else {
+            // Nothing (visible) has focus - start with the first widget:
+            self.focus_candidates.first().map(|(id, _)| *id)
+        };
+
+        if let Some(new_focus) = new_focus {
+            self.id_next_frame = Some(new_focus); // frame-delay so gained_focus works
+            self.from_keyboard = true;
+        }
+    }
+
     fn interested_in_focus(&mut self, id: Id) {
         if self.give_to_next && !self.had_focus_last_frame(id) {
             self.id = Some(id);
             self.give_to_next = false;
+            self.from_keyboard = true;
         } else if self.id == Some(id) {
             if self.pressed_tab && !self.is_focus_locked {
                 self.id = None;
@@ -294,10 +498,14 @@ impl Focus {
             } else if self.pressed_shift_tab && !self.is_focus_locked {
                 self.id_next_frame = self.last_interested; // frame-delay so gained_focus works
                 self.pressed_shift_tab = false;
+                self.from_keyboard = true;
             }
         } else if self.pressed_tab && self.id == None && !self.give_to_next {
             // nothing has focus and the user pressed tab - give focus to the first widgets that wants it:
             self.id = Some(id);
+            self.from_keyboard = true;
+            // The tab has been used up, so the widget should not give the focus away again:
+            self.pressed_tab = false;
         }
 
         self.last_interested = Some(id);
@@ -310,7 +518,10 @@ impl Memory {
         prev_input: &crate::input_state::InputState,
         new_input: &crate::data::input::RawInput,
     ) {
-        self.interaction.begin_frame(prev_input, new_input);
+        self.interaction
+            .begin_frame(prev_input, new_input, self.options.arrow_keys_move_focus);
+        self.data
+            .set_track_last_used(self.options.gc_after_frames.is_some());
 
         if !prev_input.pointer.any_down() {
             self.window_interaction = None;
@@ -319,7 +530,15 @@ impl Memory {
 
     pub(crate) fn end_frame(&mut self, input: &InputState, used_ids: &IdMap<Rect>) {
         self.caches.update();
-        self.areas.end_frame();
+
+        if let Some(max_age) = self.options.gc_after_frames {
+            let generation = self.data.generation();
+            self.num_gc_forgotten += self.data.gc(max_age);
+            self.num_gc_forgotten += self.areas.gc(generation, max_age);
+        }
+        self.areas.end_frame(self.data.generation());
+        self.data.next_generation();
+
         self.interaction.focus.end_frame(used_ids);
         self.drag_value.end_frame(input);
     }
@@ -359,6 +578,15 @@ impl Memory {
         self.interaction.focus.id
     }
 
+    /// Does this widget have keyboard focus because the user navigated to it with the keyboard
+    /// (Tab, Shift+Tab or the arrow keys)?
+    ///
+    /// Such widgets are shown with a focus ring.
+    #[inline(always)]
+    pub fn has_keyboard_focus(&self, id: Id) -> bool {
+        self.has_focus(id) && self.interaction.focus.from_keyboard
+    }
+
     /// Prevent keyboard focus from moving away from this widget even if users presses the tab key.
     /// You must first give focus to the widget before calling this.
     pub fn lock_focus(&mut self, id: Id, lock_focus: bool) {
@@ -382,6 +610,7 @@ impl Memory {
     pub fn request_focus(&mut self, id: Id) {
         self.interaction.focus.id = Some(id);
         self.interaction.focus.is_focus_locked = false;
+        self.interaction.focus.from_keyboard = false;
     }
 
     /// Surrender keyboard focus for a specific widget.
@@ -395,12 +624,31 @@ impl Memory {
     }
 
     /// Register this widget as being interested in getting keyboard focus.
-    /// This will allow the user to select it with tab and shift-tab.
+    /// This will allow the user to select it with tab and shift-tab,
+    /// or with the arrow keys if [`Options::arrow_keys_move_focus`] is on.
     #[inline(always)]
-    pub(crate) fn interested_in_focus(&mut self, id: Id) {
+    pub(crate) fn interested_in_focus(&mut self, id: Id, rect: Rect) {
+        if self.options.arrow_keys_move_focus && rect.is_positive() {
+            self.interaction.focus.focus_candidates.push((id, rect));
+        }
         self.interaction.focus.interested_in_focus(id);
     }
 
+    /// Call this if the widget `id` uses the arrow keys while focused,
+    /// so that they won't move the focus when [`Options::arrow_keys_move_focus`] is on.
+    ///
+    /// For instance, a horizontal slider uses the left and right arrow keys,
+    /// but the up and down arrows can still be used to move to another widget.
+    ///
+    /// Does nothing unless the widget has focus.
+    pub fn capture_arrow_keys(&mut self, id: Id, horizontal: bool, vertical: bool) {
+        if self.has_focus(id) {
+            let captured = &mut self.interaction.focus.arrow_keys_captured;
+            captured[0] |= horizontal;
+            captured[1] |= vertical;
+        }
+    }
+
     /// Stop editing of active [`TextEdit`](crate::TextEdit) (if any).
     #[inline(always)]
     pub fn stop_text_input(&mut self) {
@@ -427,6 +675,129 @@ impl Memory {
     pub fn reset_areas(&mut self) {
         self.areas = Default::default();
     }
+
+    /// Forget all the state egui keeps for the widget or [`crate::Area`] with the given [`Id`],
+    /// so that it is shown as if for the first time next frame.
+    ///
+    /// This forgets the position of an area, if a collapsing header is open,
+    /// the offset of a scroll area, the size of a resizable region and the cursor of a text edit,
+    /// and gives up keyboard focus if the widget has it.
+    ///
+    /// Custom state you stored in [`Self::data`] is not affected:
+    /// remove it with [`crate::util::IdTypeMap::remove`].
+    ///
+    /// See also [`Self::reset_window`] and [`Self::reset_scroll`].
+    pub fn forget(&mut self, id: Id) {
+        self.areas.remove(id);
+        self.data.remove::<crate::collapsing_header::InnerState>(id);
+        self.data.remove::<crate::scroll_area::State>(id);
+        self.data.remove::<crate::resize::State>(id);
+        self.data.remove::<crate::text_edit::TextEditState>(id);
+        self.surrender_focus(id);
+    }
+
+    /// Forget the position and size of a [`crate::Window`], and if it is collapsed,
+    /// so that it goes back to its default layout.
+    ///
+    /// The [`Id`] of a window is `Id::new(title)` unless you set it with [`crate::Window::id`].
+    pub fn reset_window(&mut self, id: Id) {
+        self.forget(id);
+        self.forget(id.with("resize"));
+        self.forget(id.with("collapsing"));
+    }
+
+    /// Scroll a [`crate::ScrollArea`] back to the start.
+    ///
+    /// The [`Id`] is returned by `ScrollArea::show` as `output.id`.
+    pub fn reset_scroll(&mut self, id: Id) {
+        self.data.remove::<crate::scroll_area::State>(id);
+    }
+
+    /// Go back to the default open state of all collapsing headers.
+    pub fn forget_all_collapsing_headers(&mut self) {
+        self.data
+            .remove_by_type::<crate::collapsing_header::InnerState>();
+    }
+
+    /// Capture the layout state of all windows and widgets.
+    ///
+    /// See [`UiSnapshot`].
+    pub fn snapshot(&self) -> UiSnapshot {
+        UiSnapshot {
+            data: self.data.clone(),
+            areas: self.areas.clone(),
+        }
+    }
+
+    /// Go back to the state captured by [`Self::snapshot`].
+    pub fn restore(&mut self, snapshot: UiSnapshot) {
+        let UiSnapshot { data, areas } = snapshot;
+        self.data = data;
+        self.areas = areas;
+    }
+}
+
+/// ## Named layouts
+/// Save the current window arrangement under a name (e.g. "Editing" or "Debugging") and switch between them.
+///
+/// The layouts are part of [`Memory`], so they are persisted together with it
+/// (e.g. by `eframe` when the `persistence` feature is enabled).
+///
+/// ```
+/// # let ctx = egui::Context::default();
+/// ctx.memory().save_layout("Editing");
+/// assert!(ctx.memory().layout_names().any(|name| name == "Editing"));
+/// assert!(ctx.memory().apply_layout("Editing"));
+/// assert!(ctx.memory().delete_layout("Editing"));
+/// ```
+impl Memory {
+    /// Save the current layout under the given name, replacing any previous layout with the same name.
+    pub fn save_layout(&mut self, name: impl Into<String>) {
+        let snapshot = self.snapshot();
+        self.layouts.insert(name.into(), snapshot);
+    }
+
+    /// Switch to a previously saved layout.
+    ///
+    /// Returns `false` if there is no layout with that name.
+    pub fn apply_layout(&mut self, name: &str) -> bool {
+        if let Some(snapshot) = self.layouts.get(name).cloned() {
+            self.restore(snapshot);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Forget a saved layout.
+    ///
+    /// Returns `false` if there is no layout with that name.
+    pub fn delete_layout(&mut self, name: &str) -> bool {
+        self.layouts.remove(name).is_some()
+    }
+
+    /// The names of all saved layouts, in alphabetical order.
+    pub fn layout_names(&self) -> impl ExactSizeIterator<Item = &str> + '_ {
+        self.layouts.keys().map(|name| name.as_str())
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// The layout state of all windows and widgets at some point in time,
+/// as captured by [`Memory::snapshot`] or [`crate::Context::snapshot`].
+///
+/// This includes window positions and sizes, scroll offsets, which collapsing headers are open etc,
+/// but not the [`Options`] or any ongoing interaction.
+///
+/// Useful for implementing undo of workspace changes, or a "reset layout" button.
+/// With the `persistence` feature this can be serialized.
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+pub struct UiSnapshot {
+    data: crate::util::IdTypeMap,
+    areas: Areas,
 }
 
 /// ## Popups
@@ -487,6 +858,10 @@ pub struct Areas {
     visible_last_frame: AHashSet<LayerId>,
     visible_current_frame: AHashSet<LayerId>,
 
+    /// The generation (frame) in which each area was last visible.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    last_visible: IdMap<u64>,
+
     /// When an area want to be on top, it is put in here.
     /// At the end of the frame, this is used to reorder the layers.
     /// This means if several layers want to be on top, they will keep their relative order.
@@ -517,6 +892,32 @@ impl Areas {
         }
     }
 
+    pub(crate) fn remove(&mut self, id: Id) {
+        self.areas.remove(&id);
+        self.order.retain(|layer| layer.id != id);
+        self.last_visible.remove(&id);
+    }
+
+    /// Forget areas that have not been visible in the last `max_age` generations.
+    ///
+    /// Returns the number of forgotten areas.
+    pub(crate) fn gc(&mut self, generation: u64, max_age: u64) -> usize {
+        let Self {
+            areas,
+            order,
+            last_visible,
+            ..
+        } = self;
+        let len_before = areas.len();
+        areas.retain(|id, _| {
+            let visible = *last_visible.entry(*id).or_insert(generation);
+            generation - visible <= max_age
+        });
+        order.retain(|layer| areas.contains_key(&layer.id));
+        last_visible.retain(|id, _| areas.contains_key(id));
+        len_before - areas.len()
+    }
+
     /// Top-most layer at the given position.
     pub fn layer_id_at(&self, pos: Pos2, resize_interact_radius_side: f32) -> Option<LayerId> {
         for layer in self.order.iter().rev() {
@@ -569,15 +970,19 @@ impl Areas {
         }
     }
 
-    pub(crate) fn end_frame(&mut self) {
+    pub(crate) fn end_frame(&mut self, generation: u64) {
         let Self {
             visible_last_frame,
             visible_current_frame,
             order,
+            last_visible,
             wants_to_be_on_top,
             ..
         } = self;
 
+        for layer_id in visible_current_frame.iter() {
+            last_visible.insert(layer_id.id, generation);
+        }
         std::mem::swap(visible_last_frame, visible_current_frame);
         visible_current_frame.clear();
         order.sort_by_key(|layer| (layer.order, wants_to_be_on_top.contains(layer)));
@@ -593,3 +998,263 @@ fn memory_impl_send_sync() {
     fn assert_send_sync<T: Send + Sync>() {}
     assert_send_sync::<Memory>();
 }
+
+#[cfg(test)]
+#[test]
+fn arrow_keys_move_focus() {
+    use crate::{Harness, Key};
+
+    let mut harness = Harness::new();
+    harness.ctx().memory().options.arrow_keys_move_focus = true;
+
+    // Lay out a 2x2 grid of buttons and return their ids, row by row:
+    let run = |harness: &mut Harness| {
+        harness.run_ui(|ui| {
+            let mut ids = vec![];
+            for row in 0..2 {
+                ui.horizontal(|ui| {
+                    for column in 0..2 {
+                        ids.push(ui.button(format!("{} {}", row, column)).id);
+                    }
+                });
+            }
+            ids
+        })
+    };
+
+    let ids = run(&mut harness);
+    harness.ctx().memory().request_focus(ids[0]);
+
+    harness.key_press(Key::ArrowRight);
+    run(&mut harness);
+    run(&mut harness); // focus moves with a frame delay
+    assert_eq!(harness.ctx().memory().focus(), Some(ids[1]));
+
+    harness.key_press(Key::ArrowDown);
+    run(&mut harness);
+    run(&mut harness);
+    assert_eq!(harness.ctx().memory().focus(), Some(ids[3]));
+
+    harness.key_press(Key::ArrowLeft);
+    run(&mut harness);
+    run(&mut harness);
+    assert_eq!(harness.ctx().memory().focus(), Some(ids[2]));
+
+    // Nothing further down:
+    harness.key_press(Key::ArrowDown);
+    run(&mut harness);
+    run(&mut harness);
+    assert_eq!(harness.ctx().memory().focus(), Some(ids[2]));
+}
+
+#[cfg(test)]
+#[test]
+fn follow_system_theme() {
+    use crate::{vec2, Color32, Harness};
+
+    let mut harness = Harness::new();
+
+    // Returns whether dark mode is on after a frame:
+    let run = |harness: &mut Harness, system_dark_mode| {
+        harness.set_system_dark_mode(system_dark_mode);
+        harness.run(|_| {});
+        harness.ctx().style().visuals.dark_mode
+    };
+
+    assert!(
+        run(&mut harness, Some(false)),
+        "Only follow the system theme if asked to"
+    );
+
+    harness.ctx().memory().options.follow_system_theme = true;
+    assert!(!run(&mut harness, Some(false)));
+    assert!(!run(&mut harness, None));
+    assert!(run(&mut harness, Some(true)));
+
+    harness.ctx().set_style(Style::light());
+    assert!(!run(&mut harness, None), "The user can still change theme");
+
+    harness.ctx().memory().options.dark_visuals.hyperlink_color = Color32::RED;
+    let mut style = (*harness.ctx().style()).clone();
+    style.visuals.hyperlink_color = Color32::GREEN;
+    style.spacing.item_spacing = vec2(3.0, 3.0);
+    harness.ctx().set_style(style);
+    assert!(run(&mut harness, Some(true)));
+    assert_eq!(harness.ctx().style().visuals.hyperlink_color, Color32::RED);
+    assert_eq!(
+        harness.ctx().style().spacing.item_spacing,
+        vec2(3.0, 3.0),
+        "Only the visuals are switched"
+    );
+    assert!(!run(&mut harness, Some(false)));
+    assert_eq!(
+        harness.ctx().style().visuals.hyperlink_color,
+        Color32::GREEN,
+        "Changes to the visuals are kept"
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn zoom_factor() {
+    use crate::{Harness, Key, Modifiers};
+
+    let run = |harness: &mut Harness| {
+        harness.run(|_| {});
+        harness.ctx().pixels_per_point()
+    };
+
+    let mut harness = Harness::new().with_pixels_per_point(2.0);
+    assert_eq!(run(&mut harness), 2.0);
+    harness.set_modifiers(Modifiers::COMMAND);
+    harness.key_press(Key::PlusEquals);
+    assert_eq!(run(&mut harness), 2.2);
+    assert_eq!(harness.ctx().zoom_factor(), 1.1);
+    assert_eq!(run(&mut harness), 2.2);
+
+    // The zoom is kept when moving to a monitor with a different scale:
+    let mut harness = harness.with_pixels_per_point(1.0);
+    assert_eq!(run(&mut harness), 1.1);
+
+    harness.key_press(Key::Minus);
+    assert_eq!(run(&mut harness), 1.0);
+    harness.key_press(Key::Minus);
+    assert_eq!(run(&mut harness), 0.9);
+    harness.key_press(Key::Num0);
+    assert_eq!(run(&mut harness), 1.0);
+    harness.set_modifiers(Modifiers::NONE);
+    harness.key_press(Key::Minus);
+    assert_eq!(run(&mut harness), 1.0);
+
+    harness.ctx().set_zoom_factor(1.5);
+    assert_eq!(run(&mut harness), 1.5);
+
+    harness.ctx().memory().options.zoom_with_keyboard = false;
+    harness.set_modifiers(Modifiers::COMMAND);
+    harness.key_press(Key::Num0);
+    assert_eq!(run(&mut harness), 1.5);
+}
+
+#[cfg(all(test, feature = "persistence"))]
+#[test]
+fn save_and_load_memory() {
+    use crate::{pos2, vec2, Context, Harness, Window};
+
+    let mut harness = Harness::new().with_screen_size(vec2(800.0, 600.0));
+    harness.run(|ctx| {
+        Window::new("Window")
+            .default_pos(pos2(100.0, 200.0))
+            .show(ctx, |_| {});
+    });
+    let ctx = harness.ctx();
+    ctx.data().insert_persisted(Id::new("counter"), 42_u32);
+    ctx.memory().request_focus(Id::new("focused"));
+    let window_pos = |ctx: &Context| ctx.memory().areas.get(Id::new("Window")).map(|s| s.pos);
+    assert_eq!(window_pos(ctx), Some(pos2(100.0, 200.0)));
+    let saved = ctx.save_memory().unwrap();
+
+    let harness = Harness::new();
+    let ctx = harness.ctx();
+    ctx.set_visuals(Visuals::light());
+    ctx.load_memory(&saved).unwrap();
+    assert_eq!(window_pos(ctx), Some(pos2(100.0, 200.0)));
+    assert_eq!(
+        ctx.data().get_persisted::<u32>(Id::new("counter")),
+        Some(42)
+    );
+    assert_eq!(ctx.memory().focus(), None, "Transient state is not saved");
+    assert!(!ctx.style().visuals.dark_mode, "The style is kept");
+
+    assert!(ctx.load_memory("not ron").is_err());
+}
+
+#[cfg(test)]
+#[test]
+fn reset_ui_state() {
+    use crate::{collapsing_header::CollapsingState, scroll_area, Harness, ScrollArea, Window};
+
+    let mut harness = Harness::new();
+    let scroll_id = harness
+        .run(|ctx| Window::new("Window").show(ctx, |ui| ScrollArea::vertical().show(ui, |_| {}).id))
+        .and_then(|response| response.inner)
+        .unwrap();
+    let ctx = harness.ctx();
+    let window_id = Id::new("Window");
+
+    assert!(ctx.memory().areas.get(window_id).is_some());
+    assert!(CollapsingState::load(ctx, window_id.with("collapsing")).is_some());
+    ctx.memory().reset_window(window_id);
+    assert!(ctx.memory().areas.get(window_id).is_none());
+    assert!(CollapsingState::load(ctx, window_id.with("collapsing")).is_none());
+
+    assert!(scroll_area::State::load(ctx, scroll_id).is_some());
+    ctx.memory().reset_scroll(scroll_id);
+    assert!(scroll_area::State::load(ctx, scroll_id).is_none());
+
+    let header_id = Id::new("header");
+    CollapsingState::load_with_default_open(ctx, header_id, false).store(ctx);
+    ctx.memory().forget_all_collapsing_headers();
+    assert!(CollapsingState::load(ctx, header_id).is_none());
+}
+
+#[cfg(test)]
+#[test]
+fn memory_gc() {
+    use crate::{Harness, ScrollArea, Window};
+
+    let mut harness = Harness::new();
+    harness.ctx().memory().options.gc_after_frames = Some(2);
+    let run = |harness: &mut Harness, show_b: bool| {
+        harness.run(|ctx| {
+            Window::new("A").show(ctx, |ui| {
+                ScrollArea::vertical().show(ui, |_| {});
+            });
+            if show_b {
+                Window::new("B").show(ctx, |ui| {
+                    ScrollArea::vertical().show(ui, |_| {});
+                });
+            }
+        });
+    };
+
+    run(&mut harness, true);
+    let num_states = harness.ctx().data().len();
+    assert!(harness.ctx().memory().areas.get(Id::new("B")).is_some());
+
+    for _ in 0..4 {
+        run(&mut harness, false);
+    }
+    let ctx = harness.ctx();
+    assert!(ctx.memory().areas.get(Id::new("A")).is_some());
+    assert!(ctx.memory().areas.get(Id::new("B")).is_none());
+    assert!(ctx.data().len() < num_states);
+    assert!(ctx.memory().num_gc_forgotten > 0);
+}
+
+#[cfg(test)]
+#[test]
+fn tab_focuses_first_widget() {
+    use crate::{Harness, Key};
+
+    let mut harness = Harness::new();
+    let mut text = String::new();
+    let mut run = |harness: &mut Harness| {
+        let ids = harness.run_ui(|ui| {
+            [
+                ui.text_edit_singleline(&mut text).id,
+                ui.button("Button").id,
+            ]
+        });
+        (ids, harness.ctx().memory().focus())
+    };
+
+    let ([text_edit, button], focus) = run(&mut harness);
+    assert_eq!(focus, None);
+
+    // Nothing has focus, so tab gives it to the first widget:
+    harness.key_press(Key::Tab);
+    assert_eq!(run(&mut harness).1, Some(text_edit));
+
+    harness.key_press(Key::Tab);
+    assert_eq!(run(&mut harness).1, Some(button));
+}
@@ -263,6 +263,9 @@ impl Response {
         self.dragged
     }
 
+    /// Is the widget being dragged with the given button?
+    ///
+    /// For instance, use `response.dragged_by(PointerButton::Middle)` for middle-click panning.
     pub fn dragged_by(&self, button: PointerButton) -> bool {
         self.dragged() && self.ctx.input().pointer.button_down(button)
     }
@@ -272,15 +275,34 @@ impl Response {
         self.dragged && self.ctx.input().pointer.any_pressed()
     }
 
+    /// Did a drag on this widgets begin this frame, using the given button?
+    pub fn drag_started_by(&self, button: PointerButton) -> bool {
+        self.dragged && self.ctx.input().pointer.button_pressed(button)
+    }
+
     /// The widget was being dragged, but now it has been released.
     pub fn drag_released(&self) -> bool {
         self.drag_released
     }
 
+    /// The widget was being dragged by the given button, but now it has been released.
+    pub fn drag_released_by(&self, button: PointerButton) -> bool {
+        self.drag_released && self.ctx.input().pointer.button_released(button)
+    }
+
     /// If dragged, how many points were we dragged and in what direction?
+    ///
+    /// If [`crate::style::Interaction::drag_axis_lock`] is set and Shift is held down,
+    /// the movement is locked to the dominant axis of the drag.
     pub fn drag_delta(&self) -> Vec2 {
         if self.dragged() {
-            self.ctx.input().pointer.delta()
+            let drag_axis_lock = self.ctx.style().interaction.drag_axis_lock;
+            let input = self.ctx.input();
+            if drag_axis_lock && input.modifiers.shift {
+                input.pointer.dominant_axis_delta()
+            } else {
+                input.pointer.delta()
+            }
         } else {
             Vec2::ZERO
         }
@@ -314,6 +336,11 @@ impl Response {
     /// e.g. the slider was dragged, text was entered in a [`TextEdit`](crate::TextEdit) etc.
     /// Always `false` for something like a [`Button`](crate::Button).
     ///
+    /// All built-in widgets that edit a value set this, including
+    /// [`Checkbox`](crate::Checkbox), [`Slider`](crate::Slider), [`DragValue`](crate::DragValue),
+    /// [`TextEdit`](crate::TextEdit) and the color edit buttons,
+    /// so you can use it to e.g. mark a document as dirty.
+    ///
     /// Can sometimes be `true` even though the data didn't changed
     /// (e.g. if the user entered a character and erased it the same frame).
     ///
@@ -342,9 +369,29 @@ impl Response {
     /// For that, use [`Self::on_disabled_hover_ui`] instead.
     ///
     /// If you call this multiple times the tooltips will stack underneath the previous ones.
+    ///
+    /// The tooltip can contain any widgets, e.g. images, colored text or a small [`crate::Grid`],
+    /// and is sized to fit its contents.
+    /// A widget inside a tooltip can have a tooltip of its own.
+    ///
+    /// If [`crate::style::Interaction::interactive_tooltips`] is set, the tooltip stays open
+    /// while the pointer is on it, so it can contain interactive widgets.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.label("Hover me").on_hover_ui(|ui| {
+    ///     ui.colored_label(egui::Color32::RED, "Warning");
+    ///     egui::Grid::new("tooltip_grid").show(ui, |ui| {
+    ///         ui.label("Width");
+    ///         ui.label("42");
+    ///         ui.end_row();
+    ///     });
+    /// });
+    /// # });
+    /// ```
     #[doc(alias = "tooltip")]
     pub fn on_hover_ui(self, add_contents: impl FnOnce(&mut Ui)) -> Self {
-        if self.should_show_hover_ui() {
+        if self.should_show_hover_ui() || self.is_pointer_on_interactive_tooltip() {
             crate::containers::show_tooltip_for(
                 &self.ctx,
                 self.id.with("__tooltip"),
@@ -380,6 +427,31 @@ impl Response {
         self
     }
 
+    /// Was our interactive tooltip shown last frame, and is the pointer now on it (or on its way to it)?
+    fn is_pointer_on_interactive_tooltip(&self) -> bool {
+        if !self.ctx.style().interaction.interactive_tooltips {
+            return false;
+        }
+
+        let tooltip_id = self.id.with("__tooltip");
+        let tooltip = self
+            .ctx
+            .frame_state()
+            .tooltip_rects_last_frame
+            .iter()
+            .find(|tooltip| tooltip.id == tooltip_id)
+            .copied();
+        let pointer_pos = self.ctx.input().pointer.hover_pos();
+        match (tooltip, pointer_pos) {
+            (Some(tooltip), Some(pointer_pos)) => {
+                // The bounding box of the widget and the tooltip is a grace area,
+                // so that the tooltip doesn't close when crossing the gap between them:
+                self.rect.union(tooltip.rect).contains(pointer_pos)
+            }
+            _ => false,
+        }
+    }
+
     fn should_show_hover_ui(&self) -> bool {
         if self.ctx.memory().everything_is_visible() {
             return true;
@@ -405,6 +477,33 @@ impl Response {
             return false;
         }
 
+        let tooltip_delay = self.ctx.style().interaction.tooltip_delay;
+        if tooltip_delay > 0.0 {
+            let now = self.ctx.input().time;
+            let hover_start = {
+                let mut frame_state = self.ctx.frame_state();
+                let hover_start = match frame_state.tooltip_hover_last_frame {
+                    Some((id, start)) if id == self.id => start,
+                    _ => now,
+                };
+                frame_state.tooltip_hover = Some((self.id, hover_start));
+                hover_start
+            };
+
+            let tooltip_grace_time = self.ctx.style().interaction.tooltip_grace_time;
+            let recently_showed_tooltip =
+                crate::containers::popup::MonoState::seconds_since_last_tooltip(&self.ctx)
+                    <= tooltip_grace_time;
+
+            let remaining_delay = tooltip_delay - (now - hover_start);
+            if remaining_delay > 0.0 && !recently_showed_tooltip {
+                // wait for the delay to pass
+                self.ctx
+                    .request_repaint_after(std::time::Duration::from_secs_f64(remaining_delay));
+                return false;
+            }
+        }
+
         true
     }
 
@@ -444,6 +543,17 @@ impl Response {
         self
     }
 
+    /// When hovered or dragged, use this icon for the mouse cursor.
+    ///
+    /// Useful for e.g. resize handles, so that the cursor stays the same
+    /// even if the pointer moves outside the handle during the drag.
+    pub fn on_hover_and_drag_cursor(self, cursor: CursorIcon) -> Self {
+        if self.hovered() || self.dragged() {
+            self.ctx.output().cursor_icon = cursor;
+        }
+        self
+    }
+
     /// Check for more interactions (e.g. sense clicks on a [`Response`] returned from a label).
     ///
     /// Note that this call will not add any hover-effects to the widget, so when possible
@@ -493,6 +603,9 @@ impl Response {
 
     /// For accessibility.
     ///
+    /// Emits [`crate::output::OutputEvent`]s and, if [`crate::Options::accessibility_tree`] is set,
+    /// an [`crate::AccessibilityNode`]. Custom widgets should call this to describe their role, label and value.
+    ///
     /// Call after interacting and potential calls to [`Self::mark_changed`].
     pub fn widget_info(&self, make_info: impl Fn() -> crate::WidgetInfo) {
         use crate::output::OutputEvent;
@@ -512,6 +625,46 @@ impl Response {
         if let Some(event) = event {
             self.ctx.output().events.push(event);
         }
+
+        if self.ctx.options().accessibility_tree {
+            self.ctx.push_accessibility_node(crate::AccessibilityNode {
+                id: self.id,
+                rect: self.rect,
+                info: make_info(),
+                focused: self.has_focus(),
+                labelled_by: None,
+                parent: None,
+                children: Vec::new(),
+            });
+        }
+    }
+
+    /// Tell assistive technologies that this widget is labelled by another widget,
+    /// e.g. a [`crate::Label`] in front of a [`crate::TextEdit`].
+    ///
+    /// This only has an effect if [`crate::Options::accessibility_tree`] is set.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut name = String::new();
+    /// ui.horizontal(|ui| {
+    ///     let label = ui.label("Your name: ");
+    ///     ui.text_edit_singleline(&mut name).labelled_by(label.id);
+    /// });
+    /// # });
+    /// ```
+    pub fn labelled_by(self, id: Id) -> Self {
+        if let Some(node) = self
+            .ctx
+            .output()
+            .accessibility_nodes
+            .iter_mut()
+            .rev()
+            .find(|node| node.id == self.id)
+        {
+            node.labelled_by = Some(id);
+        }
+        self
     }
 
     /// Response to secondary clicks (right-clicks) by showing the given menu.
@@ -647,3 +800,88 @@ impl<R> InnerResponse<R> {
         Self { inner, response }
     }
 }
+
+#[cfg(test)]
+#[test]
+fn tooltip_delay_repaints_once_the_delay_is_over() {
+    use crate::Harness;
+
+    let mut harness = Harness::new();
+    let mut style = (*harness.ctx().style()).clone();
+    style.interaction.tooltip_delay = 0.5;
+    harness.ctx().set_style(style);
+
+    let show = |ui: &mut Ui| ui.button("Hover me").on_hover_text("Tooltip").rect;
+    let rect = harness.run_ui(show);
+    harness.move_mouse(rect.center());
+
+    // Wait for the pointer to be still, then for the tooltip delay, without busy-repainting:
+    let mut waited_for_delay = false;
+    for _ in 0..10 {
+        harness.run_ui(show);
+        let output = harness.output();
+        if !output.needs_repaint && output.repaint_after < std::time::Duration::MAX {
+            assert!(output.repaint_after.as_secs_f64() <= 0.5);
+            waited_for_delay = true;
+        }
+    }
+    assert!(waited_for_delay);
+}
+
+#[cfg(test)]
+#[test]
+fn value_widgets_report_changed() {
+    use crate::{vec2, DragValue, Harness, Slider};
+
+    let mut harness = Harness::new();
+    let mut checked = false;
+    let mut slider_value = 0.0;
+    let mut drag_value = 0.0;
+    let mut text = String::new();
+
+    // Returns the rects of the widgets, and which of them reported a change:
+    let mut run = |harness: &mut Harness| {
+        harness.run_ui(|ui| {
+            let mut rects = vec![];
+            let mut changed = vec![];
+            for response in [
+                ui.checkbox(&mut checked, "Checkbox"),
+                ui.add(Slider::new(&mut slider_value, 0.0..=100.0)),
+                ui.add(DragValue::new(&mut drag_value)),
+                ui.text_edit_singleline(&mut text),
+            ] {
+                rects.push(response.rect);
+                changed.push(response.changed());
+            }
+            (rects, changed)
+        })
+    };
+
+    let (rects, changed) = run(&mut harness);
+    assert_eq!(changed, [false; 4]);
+    let [checkbox, slider, drag, text_edit] = [rects[0], rects[1], rects[2], rects[3]];
+
+    harness.press(checkbox.center());
+    run(&mut harness);
+    harness.release(checkbox.center());
+    assert_eq!(run(&mut harness).1, [true, false, false, false]);
+
+    harness.press(slider.right_center());
+    assert_eq!(run(&mut harness).1, [false, true, false, false]);
+    harness.release(slider.right_center());
+    run(&mut harness);
+
+    harness.press(drag.center());
+    run(&mut harness);
+    harness.move_mouse(drag.center() + vec2(20.0, 0.0));
+    assert_eq!(run(&mut harness).1, [false, false, true, false]);
+    harness.release(drag.center() + vec2(20.0, 0.0));
+    run(&mut harness);
+
+    harness.click(text_edit.center());
+    run(&mut harness);
+    harness.type_text("a");
+    assert_eq!(run(&mut harness).1, [false, false, false, true]);
+
+    assert_eq!(run(&mut harness).1, [false; 4]);
+}
@@ -1,4 +1,8 @@
 /// What sort of interaction is a widget sensitive to?
+///
+/// Pass this to [`crate::Ui::interact`] or [`crate::Ui::allocate_response`].
+/// A widget that only senses [`Self::hover`] (e.g. a non-interactive [`crate::Label`])
+/// never becomes active, so clicks and drags on it go to whatever interactive widget is behind it.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 // #[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Sense {
@@ -11,6 +15,9 @@ pub struct Sense {
     /// this widgets want focus.
     /// Anything interactive + labels that can be focused
     /// for the benefit of screen readers.
+    ///
+    /// Turn this off for things that can be clicked or dragged
+    /// but should be skipped when moving the focus with the keyboard.
     pub focusable: bool,
 }
 
@@ -288,6 +288,13 @@ pub struct Spacing {
     pub combo_height: f32,
 
     pub scroll_bar_width: f32,
+
+    /// Margin between the contents of a [`ScrollArea`](crate::ScrollArea) and its scroll bars.
+    pub scroll_bar_inner_margin: f32,
+
+    /// The minimum length of the handle of a scroll bar,
+    /// so that it can be grabbed even when the content is huge.
+    pub scroll_handle_min_length: f32,
 }
 
 impl Spacing {
@@ -392,6 +399,33 @@ pub struct Interaction {
 
     /// If `false`, tooltips will show up anytime you hover anything, even is mouse is still moving
     pub show_tooltips_only_when_still: bool,
+
+    /// If `true`, tooltips shown with [`crate::Response::on_hover_ui`] stay open while the pointer
+    /// is moved onto them, so that they can contain buttons, links etc.
+    pub interactive_tooltips: bool,
+
+    /// How many seconds the pointer must hover a widget before its tooltip is shown.
+    pub tooltip_delay: f64,
+
+    /// If a tooltip was shown less than this many seconds ago,
+    /// the next tooltip is shown without waiting for [`Self::tooltip_delay`].
+    ///
+    /// This lets the user quickly move between widgets with tooltips.
+    pub tooltip_grace_time: f64,
+
+    /// If `true`, holding down Shift while dragging will lock the movement
+    /// to the axis the pointer has moved the most along.
+    ///
+    /// Off by default, since some widgets use Shift for other things,
+    /// e.g. [`crate::DragValue`] is slower when dragged with Shift held down.
+    ///
+    /// See [`crate::Response::drag_delta`].
+    pub drag_axis_lock: bool,
+
+    /// Maximum time (in seconds) between two clicks for them to count as a double-click.
+    ///
+    /// Three clicks within twice this time is a triple-click.
+    pub max_double_click_delay: f64,
 }
 
 /// Controls the visual style (colors etc) of egui.
@@ -426,6 +460,12 @@ pub struct Visuals {
     /// it is disabled, non-interactive, hovered etc.
     pub override_text_color: Option<Color32>,
 
+    /// Disabled widgets (see [`crate::Ui::set_enabled`]) are faded towards this color,
+    /// giving them their gray look.
+    ///
+    /// If `None`, the window background color is used.
+    pub disabled_fade_color: Option<Color32>,
+
     /// Visual styles of widgets
     pub widgets: Widgets,
 
@@ -434,6 +474,12 @@ pub struct Visuals {
     /// The color used for [`Hyperlink`],
     pub hyperlink_color: Color32,
 
+    /// The color used for warning text, e.g. "Unsaved changes".
+    pub warn_fg_color: Color32,
+
+    /// The color used for error text, e.g. a failed validation or the debug painter errors.
+    pub error_fg_color: Color32,
+
     /// Something just barely different from the background color.
     /// Used for [`crate::Grid::striped`].
     pub faint_bg_color: Color32,
@@ -465,6 +511,9 @@ pub struct Visuals {
 
     /// Show a background behind collapsing headers.
     pub collapsing_header_frame: bool,
+
+    /// Draw a vertical line left of indented regions, e.g. in [`CollapsingHeader`](crate::CollapsingHeader).
+    pub indent_has_left_vline: bool,
 }
 
 impl Visuals {
@@ -487,6 +536,13 @@ impl Visuals {
         self.widgets.active.text_color()
     }
 
+    /// The color disabled widgets are faded towards.
+    #[inline(always)]
+    pub fn fade_out_to_color(&self) -> Color32 {
+        self.disabled_fade_color
+            .unwrap_or_else(|| self.window_fill())
+    }
+
     /// Window background color.
     #[inline(always)]
     pub fn window_fill(&self) -> Color32 {
@@ -572,8 +628,9 @@ impl WidgetVisuals {
 }
 
 /// Options for help debug egui by adding extra visualization
-#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct DebugOptions {
     /// However over widgets to see their rectangles
     pub debug_on_hover: bool,
@@ -582,6 +639,28 @@ pub struct DebugOptions {
     /// Show which widgets make their parent higher
     pub show_expand_height: bool,
     pub show_resize: bool,
+    /// Paint a warning over widgets that use the same [`crate::Id`] in the same frame.
+    ///
+    /// On by default in debug builds. See also [`crate::Context::id_clashes`].
+    pub warn_on_id_clash: bool,
+    /// While these modifiers are held, hovering a widget shows its [`crate::Id`], rect, layer, clip rect
+    /// and the containers it is in, a bit like the element inspector of a web browser.
+    ///
+    /// Default: `None` (off).
+    pub inspect_on_hover: Option<crate::Modifiers>,
+}
+
+impl Default for DebugOptions {
+    fn default() -> Self {
+        Self {
+            debug_on_hover: false,
+            show_expand_width: false,
+            show_expand_height: false,
+            show_resize: false,
+            warn_on_id_clash: cfg!(debug_assertions),
+            inspect_on_hover: None,
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -626,6 +705,24 @@ impl Default for Style {
     }
 }
 
+impl Style {
+    /// The default dark theme.
+    pub fn dark() -> Self {
+        Self {
+            visuals: Visuals::dark(),
+            ..Default::default()
+        }
+    }
+
+    /// The default light theme.
+    pub fn light() -> Self {
+        Self {
+            visuals: Visuals::light(),
+            ..Default::default()
+        }
+    }
+}
+
 impl Default for Spacing {
     fn default() -> Self {
         Self {
@@ -642,6 +739,8 @@ impl Default for Spacing {
             tooltip_width: 600.0,
             combo_height: 200.0,
             scroll_bar_width: 8.0,
+            scroll_bar_inner_margin: 8.0,
+            scroll_handle_min_length: 12.0,
             indent_ends_with_horizontal_line: false,
         }
     }
@@ -653,6 +752,11 @@ impl Default for Interaction {
             resize_grab_radius_side: 5.0,
             resize_grab_radius_corner: 10.0,
             show_tooltips_only_when_still: false,
+            interactive_tooltips: false,
+            tooltip_delay: 0.0,
+            tooltip_grace_time: 0.2,
+            drag_axis_lock: false,
+            max_double_click_delay: 0.3,
         }
     }
 }
@@ -663,9 +767,12 @@ impl Visuals {
         Self {
             dark_mode: true,
             override_text_color: None,
+            disabled_fade_color: None,
             widgets: Widgets::default(),
             selection: Selection::default(),
             hyperlink_color: Color32::from_rgb(90, 170, 255),
+            warn_fg_color: Color32::from_rgb(255, 143, 0), // orange
+            error_fg_color: Color32::from_rgb(255, 0, 0),  // red
             faint_bg_color: Color32::from_gray(35),
             extreme_bg_color: Color32::from_gray(10), // e.g. TextEdit background
             code_bg_color: Color32::from_gray(64),
@@ -678,6 +785,7 @@ impl Visuals {
             clip_rect_margin: 3.0, // should be at least half the size of the widest frame stroke + max WidgetVisuals::expansion
             button_frame: true,
             collapsing_header_frame: false,
+            indent_has_left_vline: true,
         }
     }
 
@@ -688,6 +796,8 @@ impl Visuals {
             widgets: Widgets::light(),
             selection: Selection::light(),
             hyperlink_color: Color32::from_rgb(0, 155, 255),
+            warn_fg_color: Color32::from_rgb(255, 100, 0), // slightly orange red. it's difficult to find a warning color that pops on bright background.
+            error_fg_color: Color32::from_rgb(255, 0, 0),  // red
             faint_bg_color: Color32::from_gray(242),
             extreme_bg_color: Color32::from_gray(255), // e.g. TextEdit background
             code_bg_color: Color32::from_gray(230),
@@ -919,6 +1029,8 @@ impl Spacing {
             indent_ends_with_horizontal_line,
             combo_height,
             scroll_bar_width,
+            scroll_bar_inner_margin,
+            scroll_handle_min_length,
         } = self;
 
         ui.add(slider_vec2(item_spacing, 0.0..=20.0, "Item spacing"));
@@ -972,6 +1084,14 @@ impl Spacing {
             ui.add(DragValue::new(scroll_bar_width).clamp_range(0.0..=32.0));
             ui.label("Scroll-bar width width");
         });
+        ui.horizontal(|ui| {
+            ui.add(DragValue::new(scroll_bar_inner_margin).clamp_range(0.0..=32.0));
+            ui.label("Scroll-bar inner margin");
+        });
+        ui.horizontal(|ui| {
+            ui.add(DragValue::new(scroll_handle_min_length).clamp_range(0.0..=64.0));
+            ui.label("Scroll-bar handle min length");
+        });
 
         ui.horizontal(|ui| {
             ui.label("Checkboxes etc:");
@@ -1017,6 +1137,11 @@ impl Interaction {
             resize_grab_radius_side,
             resize_grab_radius_corner,
             show_tooltips_only_when_still,
+            interactive_tooltips,
+            tooltip_delay,
+            tooltip_grace_time,
+            drag_axis_lock,
+            max_double_click_delay,
         } = self;
         ui.add(Slider::new(resize_grab_radius_side, 0.0..=20.0).text("resize_grab_radius_side"));
         ui.add(
@@ -1026,6 +1151,24 @@ impl Interaction {
             show_tooltips_only_when_still,
             "Only show tooltips if mouse is still",
         );
+        ui.checkbox(interactive_tooltips, "Interactive tooltips")
+            .on_hover_text("Keep tooltips open while the mouse is on them");
+        ui.add(
+            Slider::new(tooltip_delay, 0.0..=2.0)
+                .suffix(" s")
+                .text("tooltip_delay"),
+        );
+        ui.add(
+            Slider::new(tooltip_grace_time, 0.0..=2.0)
+                .suffix(" s")
+                .text("tooltip_grace_time"),
+        );
+        ui.checkbox(drag_axis_lock, "Hold Shift to lock drags to one axis");
+        ui.add(
+            Slider::new(max_double_click_delay, 0.1..=1.0)
+                .suffix(" s")
+                .text("max_double_click_delay"),
+        );
 
         ui.vertical_centered(|ui| reset_button(ui, self));
     }
@@ -1134,9 +1277,12 @@ impl Visuals {
         let Self {
             dark_mode: _,
             override_text_color: _,
+            disabled_fade_color,
             widgets,
             selection,
             hyperlink_color,
+            warn_fg_color,
+            error_fg_color,
             faint_bg_color,
             extreme_bg_color,
             code_bg_color,
@@ -1149,6 +1295,7 @@ impl Visuals {
             clip_rect_margin,
             button_frame,
             collapsing_header_frame,
+            indent_has_left_vline,
         } = self;
 
         ui.collapsing("Background Colors", |ui| {
@@ -1159,6 +1306,14 @@ impl Visuals {
             );
             ui_color(ui, extreme_bg_color, "Extreme")
                 .on_hover_text("Background of plots and paintings");
+
+            let window_fill = widgets.noninteractive.bg_fill;
+            let mut fade_color = disabled_fade_color.unwrap_or(window_fill);
+            ui_color(ui, &mut fade_color, "Disabled widgets fade")
+                .on_hover_text("Disabled widgets are faded towards this color");
+            if fade_color != disabled_fade_color.unwrap_or(window_fill) {
+                *disabled_fade_color = Some(fade_color);
+            }
         });
 
         ui.collapsing("Window", |ui| {
@@ -1190,6 +1345,8 @@ impl Visuals {
         });
 
         ui_color(ui, hyperlink_color, "hyperlink_color");
+        ui_color(ui, warn_fg_color, "Warnings");
+        ui_color(ui, error_fg_color, "Errors");
         ui.add(Slider::new(resize_corner_size, 0.0..=20.0).text("resize_corner_size"));
         ui.add(Slider::new(text_cursor_width, 0.0..=4.0).text("text_cursor_width"));
         ui.checkbox(text_cursor_preview, "Preview text cursor on hover");
@@ -1197,6 +1354,10 @@ impl Visuals {
 
         ui.checkbox(button_frame, "Button has a frame");
         ui.checkbox(collapsing_header_frame, "Collapsing header has a frame");
+        ui.checkbox(
+            indent_has_left_vline,
+            "Paint a vertical line left of indented regions",
+        );
 
         ui.vertical_centered(|ui| reset_button(ui, self));
     }
@@ -1209,6 +1370,8 @@ impl DebugOptions {
             show_expand_width: debug_expand_width,
             show_expand_height: debug_expand_height,
             show_resize: debug_resize,
+            warn_on_id_clash,
+            inspect_on_hover,
         } = self;
 
         ui.checkbox(debug_on_hover, "Show debug info on hover");
@@ -1221,6 +1384,19 @@ impl DebugOptions {
             "Show which widgets make their parent higher",
         );
         ui.checkbox(debug_resize, "Debug Resize");
+        ui.checkbox(warn_on_id_clash, "Warn about Id clashes");
+
+        let mut inspect = inspect_on_hover.is_some();
+        if ui
+            .checkbox(&mut inspect, "Inspect widgets while holding Alt")
+            .changed()
+        {
+            *inspect_on_hover = if inspect {
+                Some(crate::Modifiers::ALT)
+            } else {
+                None
+            };
+        }
 
         ui.vertical_centered(|ui| reset_button(ui, self));
     }
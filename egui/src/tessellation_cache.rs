@@ -0,0 +1,190 @@
+//! Reuse the tessellation of layers (e.g. windows) that did not change since last frame.
+
+use epaint::{
+    ahash::AHashMap, tessellator, ClippedPrimitive, ClippedShape, PreparedDisc, Primitive,
+    TessellationOptions,
+};
+
+use crate::LayerId;
+
+/// Everything the tessellation depends on, except the shapes.
+#[derive(Clone, PartialEq)]
+struct TessellationInput {
+    pixels_per_point: f32,
+    options: TessellationOptions,
+    font_tex_size: [usize; 2],
+    prepared_discs: Vec<PreparedDisc>,
+}
+
+struct CachedLayer {
+    shapes: Vec<ClippedShape>,
+    primitives: Vec<ClippedPrimitive>,
+}
+
+/// Remembers the shapes and the resulting primitives of each layer from last frame.
+///
+/// Comparing the shapes of a layer is cheaper than tessellating them,
+/// so this saves time when most layers stay the same (e.g. a static UI with one animated window).
+/// When everything changes every frame it only adds the cost of the comparison.
+#[derive(Default)]
+pub(crate) struct TessellationCache {
+    input: Option<TessellationInput>,
+    layers: AHashMap<LayerId, CachedLayer>,
+
+    /// How many layers were reused by the last call to [`Self::tessellate`].
+    pub num_reused_layers: usize,
+}
+
+impl TessellationCache {
+    /// Like [`tessellator::tessellate_shapes`], but reuses the primitives of layers that did not change.
+    ///
+    /// `layers` is which layer each consecutive run of `shapes` belongs to, and how long that run is.
+    /// If it doesn't match `shapes`, nothing is cached.
+    pub fn tessellate(
+        &mut self,
+        pixels_per_point: f32,
+        options: TessellationOptions,
+        font_tex_size: [usize; 2],
+        prepared_discs: Vec<PreparedDisc>,
+        layers: &[(LayerId, usize)],
+        mut shapes: Vec<ClippedShape>,
+    ) -> Vec<ClippedPrimitive> {
+        self.num_reused_layers = 0;
+
+        let num_shapes: usize = layers.iter().map(|(_, len)| len).sum();
+        if num_shapes != shapes.len() {
+            // Someone added or removed shapes after the frame ended, so we don't know what is what:
+            self.input = None;
+            self.layers.clear();
+            return tessellator::tessellate_shapes(
+                pixels_per_point,
+                options,
+                font_tex_size,
+                prepared_discs,
+                shapes,
+            );
+        }
+
+        let input = TessellationInput {
+            pixels_per_point,
+            options,
+            font_tex_size,
+            prepared_discs,
+        };
+        if self.input.as_ref() != Some(&input) {
+            self.layers.clear();
+        }
+
+        let mut previous_layers = std::mem::take(&mut self.layers);
+        let mut clipped_primitives = Vec::new();
+
+        // Split off the layers from the back, so we don't need to copy the shapes:
+        let mut layer_primitives = Vec::with_capacity(layers.len());
+        for &(layer_id, len) in layers.iter().rev() {
+            let layer_shapes = shapes.split_off(shapes.len() - len);
+
+            let cached = previous_layers
+                .remove(&layer_id)
+                .filter(|cached| cached.shapes == layer_shapes);
+            let cached = if let Some(cached) = cached {
+                self.num_reused_layers += 1;
+                cached
+            } else {
+                let primitives = tessellator::tessellate_shapes(
+                    input.pixels_per_point,
+                    input.options,
+                    input.font_tex_size,
+                    input.prepared_discs.clone(),
+                    layer_shapes.clone(),
+                );
+                CachedLayer {
+                    shapes: layer_shapes,
+                    primitives,
+                }
+            };
+            layer_primitives.push(cached.primitives.clone());
+            self.layers.insert(layer_id, cached);
+        }
+
+        for primitives in layer_primitives.into_iter().rev() {
+            append_primitives(&mut clipped_primitives, primitives);
+        }
+
+        self.input = Some(input);
+        clipped_primitives
+    }
+}
+
+/// Like `out.extend(primitives)`, but merges meshes at the seam like the tessellator would,
+/// so we get as few primitives as without the cache.
+fn append_primitives(out: &mut Vec<ClippedPrimitive>, primitives: Vec<ClippedPrimitive>) {
+    let mut primitives = primitives.into_iter();
+    if let Some(first) = primitives.next() {
+        match (out.last_mut(), first) {
+            (
+                Some(ClippedPrimitive {
+                    clip_rect,
+                    primitive: Primitive::Mesh(out_mesh),
+                }),
+                ClippedPrimitive {
+                    clip_rect: first_clip_rect,
+                    primitive: Primitive::Mesh(first_mesh),
+                },
+            ) if *clip_rect == first_clip_rect && out_mesh.texture_id == first_mesh.texture_id => {
+                out_mesh.append(first_mesh);
+            }
+            (_, first) => out.push(first),
+        }
+    }
+    out.extend(primitives);
+}
+
+#[cfg(test)]
+#[test]
+fn reuse_unchanged_layers() {
+    use crate::{pos2, Color32, Rect, Shape};
+
+    let background = LayerId::background();
+    let debug = LayerId::debug();
+    let layers = [(background, 1), (debug, 1)];
+    let shapes = |color| {
+        let rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(10.0, 10.0));
+        vec![
+            ClippedShape(
+                Rect::EVERYTHING,
+                Shape::rect_filled(rect, 0.0, Color32::RED),
+            ),
+            ClippedShape(Rect::EVERYTHING, Shape::rect_filled(rect, 2.0, color)),
+        ]
+    };
+    let uncached = |shapes| {
+        let primitives =
+            tessellator::tessellate_shapes(1.0, Default::default(), [1, 1], vec![], shapes);
+        format!("{:?}", primitives)
+    };
+
+    let mut cache = TessellationCache::default();
+    let mut tessellate = |layers: &[(LayerId, usize)], shapes| {
+        let primitives = cache.tessellate(1.0, Default::default(), [1, 1], vec![], layers, shapes);
+        (cache.num_reused_layers, format!("{:?}", primitives))
+    };
+
+    let expected = uncached(shapes(Color32::GREEN));
+    assert_eq!(
+        tessellate(&layers, shapes(Color32::GREEN)),
+        (0, expected.clone())
+    );
+    assert_eq!(tessellate(&layers, shapes(Color32::GREEN)), (2, expected));
+
+    let expected = uncached(shapes(Color32::BLUE));
+    assert_eq!(
+        tessellate(&layers, shapes(Color32::BLUE)),
+        (1, expected.clone())
+    );
+
+    // If the layers don't add up, we can't use the cache:
+    assert_eq!(
+        tessellate(&layers[..1], shapes(Color32::BLUE)),
+        (0, expected)
+    );
+}
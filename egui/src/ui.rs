@@ -59,6 +59,13 @@ pub struct Ui {
     /// and all widgets will assume a gray style.
     enabled: bool,
 
+    /// If true, widgets still respond to the pointer (e.g. for scrolling and text selection),
+    /// but will not change their values.
+    read_only: bool,
+
+    /// If set, all [`CollapsingHeader`]s in this Ui (and its children) are forced open or closed.
+    collapsing_open: Option<bool>,
+
     /// Indicates whether this Ui belongs to a Menu.
     menu_state: Option<Arc<RwLock<MenuState>>>,
 }
@@ -80,6 +87,8 @@ impl Ui {
             style,
             placer: Placer::new(max_rect, Layout::default()),
             enabled: true,
+            read_only: false,
+            collapsing_open: None,
             menu_state: None,
         }
     }
@@ -107,6 +116,8 @@ impl Ui {
             style: self.style.clone(),
             placer: Placer::new(max_rect, layout),
             enabled: self.enabled,
+            read_only: self.read_only,
+            collapsing_open: self.collapsing_open,
             menu_state,
         }
     }
@@ -222,6 +233,9 @@ impl Ui {
     ///
     /// Calling `set_enabled(true)` has no effect - it will NOT re-enable the [`Ui`] once disabled.
     ///
+    /// The gray look comes from fading towards [`Visuals::fade_out_to_color`].
+    /// Use [`Response::on_disabled_hover_text`] to explain to the user why a widget is disabled.
+    ///
     /// ### Example
     /// ```
     /// # egui::__run_test_ui(|ui| {
@@ -239,10 +253,66 @@ impl Ui {
         self.enabled &= enabled;
         if !self.enabled && self.is_visible() {
             self.painter
-                .set_fade_to_color(Some(self.visuals().window_fill()));
+                .set_fade_to_color(Some(self.visuals().fade_out_to_color()));
         }
     }
 
+    /// If `true`, value widgets (sliders, checkboxes, text edits, …) in this [`Ui`]
+    /// are shown as usual but will not change their values.
+    ///
+    /// See [`Self::read_only`].
+    #[inline]
+    pub fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    /// Calling `set_read_only(true)` will stop all future widgets in this [`Ui`] from modifying their values.
+    ///
+    /// Unlike [`Self::set_enabled`] the widgets keep their normal look,
+    /// and the user can still hover them, scroll, and select and copy text.
+    ///
+    /// Usually it is more convenient to use [`Self::read_only`].
+    ///
+    /// Calling `set_read_only(false)` has no effect - it will NOT make a read-only [`Ui`] editable again.
+    pub fn set_read_only(&mut self, read_only: bool) {
+        self.read_only |= read_only;
+    }
+
+    /// If set, all [`CollapsingHeader`]s added to this [`Ui`] and its children are forced open or closed,
+    /// unless they have their own [`CollapsingHeader::open`].
+    ///
+    /// Nested headers are reached in the same frame, so this is all you need for "expand all" buttons:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let mut open_all = None;
+    /// ui.horizontal(|ui| {
+    ///     if ui.button("Expand all").clicked() {
+    ///         open_all = Some(true);
+    ///     }
+    ///     if ui.button("Collapse all").clicked() {
+    ///         open_all = Some(false);
+    ///     }
+    /// });
+    ///
+    /// ui.scope(|ui| {
+    ///     ui.set_collapsing_open(open_all);
+    ///     ui.collapsing("Settings", |ui| {
+    ///         ui.collapsing("Advanced", |ui| ui.label("…"));
+    ///     });
+    /// });
+    /// # });
+    /// ```
+    pub fn set_collapsing_open(&mut self, open: Option<bool>) {
+        self.collapsing_open = open;
+    }
+
+    /// See [`Self::set_collapsing_open`].
+    #[inline]
+    pub fn collapsing_open(&self) -> Option<bool> {
+        self.collapsing_open
+    }
+
     /// If `false`, any widgets added to the [`Ui`] will be invisible and non-interactive.
     #[inline]
     pub fn is_visible(&self) -> bool {
@@ -401,6 +471,26 @@ impl Ui {
     }
 
     /// Can be used for culling: if `false`, then no part of `rect` will be visible on screen.
+    ///
+    /// This is cheap, so you can use it to skip building expensive widgets that are
+    /// scrolled out of view in a [`crate::ScrollArea`]:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::ScrollArea::vertical().show(ui, |ui| {
+    ///     for i in 0..1000 {
+    ///         let (rect, _response) =
+    ///             ui.allocate_exact_size(egui::vec2(200.0, 40.0), egui::Sense::hover());
+    ///         if ui.is_rect_visible(rect) {
+    ///             // Only build the expensive contents when they can be seen:
+    ///             ui.allocate_ui_at_rect(rect, |ui| {
+    ///                 ui.label(format!("Item {}", i));
+    ///             });
+    ///         }
+    ///     }
+    /// });
+    /// # });
+    /// ```
     pub fn is_rect_visible(&self, rect: Rect) -> bool {
         self.is_visible() && rect.intersects(self.clip_rect())
     }
@@ -452,6 +542,17 @@ impl Ui {
 
     /// Set the maximum width of the ui.
     /// You won't be able to shrink it below the current minimum size.
+    ///
+    /// Text will wrap at this width:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.vertical(|ui| {
+    ///     ui.set_max_width(400.0);
+    ///     ui.label("A long text that will wrap before it gets wider than 400 points.");
+    /// });
+    /// # });
+    /// ```
     pub fn set_max_width(&mut self, width: f32) {
         self.placer.set_max_width(width);
     }
@@ -473,6 +574,19 @@ impl Ui {
 
     /// Set the minimum width of the ui.
     /// This can't shrink the ui, only make it larger.
+    ///
+    /// In an auto-sized container (like a menu) with a justified layout,
+    /// this can be used to give several buttons the same width:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.menu_button("File", |ui| {
+    ///     ui.set_min_width(150.0);
+    ///     ui.button("Open");
+    ///     ui.button("Save as…");
+    /// });
+    /// # });
+    /// ```
     pub fn set_min_width(&mut self, width: f32) {
         self.placer.set_min_width(width);
     }
@@ -598,6 +712,23 @@ impl Ui {
 /// # Interaction
 impl Ui {
     /// Check for clicks, drags and/or hover on a specific region of this [`Ui`].
+    ///
+    /// The returned [`Response`] has everything a custom widget needs,
+    /// e.g. [`Response::clicked`], [`Response::drag_delta`] and [`Response::hover_pos`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let rect = egui::Rect::from_min_size(ui.min_rect().min, egui::vec2(64.0, 64.0));
+    /// let response = ui.interact(rect, ui.id().with("knob"), egui::Sense::click_and_drag());
+    /// if response.dragged() {
+    ///     let delta = response.drag_delta();
+    ///     // …
+    /// }
+    /// if response.double_clicked() {
+    ///     // …
+    /// }
+    /// # });
+    /// ```
     pub fn interact(&self, rect: Rect, id: Id, sense: Sense) -> Response {
         self.ctx().interact(
             self.clip_rect(),
@@ -610,6 +741,29 @@ impl Ui {
         )
     }
 
+    /// Like [`Self::interact`], but only responds when the pointer is inside the given [`HitShape`].
+    ///
+    /// Use this for non-rectangular widgets, like round knobs or diagonal connectors.
+    /// The [`Response::rect`] is the bounding rectangle of the shape.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let (rect, _) = ui.allocate_exact_size(egui::vec2(32.0, 32.0), egui::Sense::hover());
+    /// let knob = egui::HitShape::circle(rect.center(), 16.0);
+    /// let response = ui.interact_with_hit_shape(&knob, ui.id().with("knob"), egui::Sense::drag());
+    /// # });
+    /// ```
+    pub fn interact_with_hit_shape(&self, hit_shape: &HitShape, id: Id, sense: Sense) -> Response {
+        self.ctx().interact_with_hit_shape(
+            self.clip_rect(),
+            self.layer_id(),
+            id,
+            hit_shape,
+            sense,
+            self.enabled,
+        )
+    }
+
     /// Is the pointer (mouse/touch) above this rectangle in this [`Ui`]?
     ///
     /// The `clip_rect` and layer of this [`Ui`] will be respected, so, for instance,
@@ -624,6 +778,43 @@ impl Ui {
     pub fn ui_contains_pointer(&self) -> bool {
         self.rect_contains_pointer(self.min_rect())
     }
+
+    /// Accept files dragged from the operating system onto the given rectangle.
+    ///
+    /// While files are being dragged over the rectangle it is highlighted,
+    /// and the files dropped onto it this frame are returned.
+    ///
+    /// Some platforms don't report the pointer position while dragging files.
+    /// In that case all dragged files are assumed to target this rectangle.
+    ///
+    /// See also [`crate::RawInput::hovered_files`] and [`crate::RawInput::dropped_files`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let response = ui.label("Drop files here");
+    /// for file in ui.file_drop_target(response.rect) {
+    ///     println!("Dropped {:?}", file.path);
+    /// }
+    /// # });
+    /// ```
+    pub fn file_drop_target(&self, rect: Rect) -> Vec<DroppedFile> {
+        let pointer_known = self.input().pointer.hover_pos().is_some();
+        if pointer_known && !self.rect_contains_pointer(rect) {
+            return vec![];
+        }
+
+        if !self.input().raw.hovered_files.is_empty() && self.is_rect_visible(rect) {
+            let visuals = self.visuals();
+            self.painter().rect(
+                rect,
+                visuals.widgets.noninteractive.rounding,
+                visuals.selection.bg_fill.linear_multiply(0.25),
+                visuals.selection.stroke,
+            );
+        }
+
+        self.input().raw.dropped_files.clone()
+    }
 }
 
 /// # Allocating space: where do I put my widgets?
@@ -658,6 +849,19 @@ impl Ui {
     /// The response rect will be larger if this is part of a justified layout or similar.
     /// This means that if this is a narrow widget in a wide justified layout, then
     /// the widget will react to interactions outside the returned [`Rect`].
+    ///
+    /// The returned [`Rect`] is aligned within the allocated space according to the current [`Layout`].
+    /// This is the recommended way to allocate space for a custom widget:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let (rect, response) = ui.allocate_exact_size(egui::vec2(40.0, 20.0), egui::Sense::click());
+    /// if ui.is_rect_visible(rect) {
+    ///     let visuals = ui.style().interact(&response);
+    ///     ui.painter().rect_filled(rect, visuals.rounding, visuals.bg_fill);
+    /// }
+    /// # });
+    /// ```
     pub fn allocate_exact_size(&mut self, desired_size: Vec2, sense: Sense) -> (Rect, Response) {
         let response = self.allocate_response(desired_size, sense);
         let rect = self
@@ -812,6 +1016,20 @@ impl Ui {
     /// If the contents overflow, more space will be allocated.
     /// When finished, the amount of space actually used (`min_rect`) will be allocated.
     /// So you can request a lot of space and then use less.
+    ///
+    /// The width of `desired_size` is the width available to the contents,
+    /// so text will wrap at that width. Combine with [`Self::indent`] for quote or callout blocks:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.indent("quote", |ui| {
+    ///     let response = ui.allocate_ui(egui::vec2(200.0, 0.0), |ui| {
+    ///         ui.label("A long quote that is too wide to fit on a single line of 200 points.");
+    ///     }).response;
+    ///     assert!(response.rect.width() <= 200.0);
+    /// });
+    /// # });
+    /// ```
     #[inline]
     pub fn allocate_ui<R>(
         &mut self,
@@ -868,6 +1086,19 @@ impl Ui {
     /// If the contents overflow, more space will be allocated.
     /// When finished, the amount of space actually used (`min_rect`) will be allocated.
     /// So you can request a lot of space and then use less.
+    ///
+    /// The rectangle can be anywhere, regardless of where the cursor is,
+    /// which is useful for overlays and other manual layouts.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let min = ui.max_rect().min + egui::vec2(50.0, 50.0);
+    /// let rect = egui::Rect::from_min_size(min, egui::vec2(100.0, 40.0));
+    /// ui.allocate_ui_at_rect(rect, |ui| {
+    ///     ui.label("Placed at an exact position");
+    /// });
+    /// # });
+    /// ```
     pub fn allocate_ui_at_rect<R>(
         &mut self,
         max_rect: Rect,
@@ -1049,6 +1280,17 @@ impl Ui {
 
     /// Add a [`Widget`] to this [`Ui`] at a specific location (manual layout).
     ///
+    /// The widget will fill the given rectangle, if it can.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// let rect = egui::Rect::from_min_size(ui.max_rect().min, egui::vec2(80.0, 30.0));
+    /// if ui.put(rect, egui::Button::new("Click me")).clicked() {
+    ///     /* … */
+    /// }
+    /// # });
+    /// ```
+    ///
     /// See also [`Self::add`] and [`Self::add_sized`].
     pub fn put(&mut self, max_rect: Rect, widget: impl Widget) -> Response {
         self.allocate_ui_at_rect(max_rect, |ui| {
@@ -1112,6 +1354,31 @@ impl Ui {
         })
     }
 
+    /// Add a section where the widgets show their values, but the user cannot modify them.
+    ///
+    /// Unlike [`Self::add_enabled_ui`] the widgets are not grayed out,
+    /// and the user can still scroll, and select and copy text.
+    ///
+    /// See also [`Self::set_read_only`] and [`Self::is_read_only`].
+    ///
+    /// ### Example
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut settings = (true, 0.5_f32, String::from("Hello"));
+    /// ui.read_only(|ui| {
+    ///     ui.checkbox(&mut settings.0, "Checkbox");
+    ///     ui.add(egui::Slider::new(&mut settings.1, 0.0..=1.0));
+    ///     ui.text_edit_singleline(&mut settings.2); // Text can still be selected and copied
+    /// });
+    /// # });
+    /// ```
+    pub fn read_only<R>(&mut self, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        self.scope(|ui| {
+            ui.set_read_only(true);
+            add_contents(ui)
+        })
+    }
+
     /// Add a single [`Widget`] that is possibly invisible.
     ///
     /// An invisible widget still takes up the same space as if it were visible.
@@ -1180,6 +1447,10 @@ impl Ui {
     /// This will be in addition to the [`crate::style::Spacing::item_spacing`].
     ///
     /// [`Self::min_rect`] will expand to contain the space.
+    ///
+    /// To push the remaining widgets to the far end of a row or column,
+    /// put them in a nested layout with the opposite direction using [`Self::with_layout`],
+    /// which will take up all the remaining space.
     #[inline]
     pub fn add_space(&mut self, amount: f32) {
         self.placer.advance_cursor(amount);
@@ -1258,6 +1529,26 @@ impl Ui {
         Label::new(text.into().weak()).ui(self)
     }
 
+    /// Show a small, faint "(?)" that shows the given help text when hovered.
+    ///
+    /// Usually placed after the label or widget it explains:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut value = 0.5;
+    /// ui.horizontal(|ui| {
+    ///     ui.add(egui::Slider::new(&mut value, 0.0..=1.0).text("Gamma"));
+    ///     ui.help_marker("Brightness correction applied before display.");
+    /// });
+    /// # });
+    /// ```
+    pub fn help_marker(&mut self, text: impl Into<WidgetText>) -> Response {
+        Label::new(RichText::new("(?)").weak())
+            .sense(Sense::hover())
+            .ui(self)
+            .on_hover_text(text)
+    }
+
     /// Looks like a hyperlink.
     ///
     /// Shortcut for `add(Link::new(text))`.
@@ -1382,7 +1673,7 @@ impl Ui {
     /// See also [`Self::checkbox`].
     pub fn toggle_value(&mut self, selected: &mut bool, text: impl Into<WidgetText>) -> Response {
         let mut response = self.selectable_label(*selected, text);
-        if response.clicked() {
+        if response.clicked() && !self.read_only {
             *selected = !*selected;
             response.mark_changed();
         }
@@ -1423,7 +1714,7 @@ impl Ui {
         text: impl Into<WidgetText>,
     ) -> Response {
         let mut response = self.radio(*current_value == alternative, text);
-        if response.clicked() {
+        if response.clicked() && !self.read_only {
             *current_value = alternative;
             response.mark_changed();
         }
@@ -1451,13 +1742,64 @@ impl Ui {
         text: impl Into<WidgetText>,
     ) -> Response {
         let mut response = self.selectable_label(*current_value == selected_value, text);
-        if response.clicked() {
+        if response.clicked() && !self.read_only {
             *current_value = selected_value;
             response.mark_changed();
         }
         response
     }
 
+    /// Show a value widget together with a marker telling if the value differs from a baseline,
+    /// e.g. the default value in a settings panel, or the other side in a config comparison tool.
+    ///
+    /// If `*value != *baseline` a colored dot and a revert button is shown after the widget.
+    /// Clicking the revert button sets `*value` to `baseline` and marks the response as changed.
+    /// The space for the marker is always reserved, so the layout does not jump when the value changes.
+    ///
+    /// Returns the response of the value widget.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # let mut volume = 0.8;
+    /// # let mut mute = false;
+    /// ui.with_baseline(&mut volume, &0.5, |ui, volume| {
+    ///     ui.add(egui::Slider::new(volume, 0.0..=1.0).text("Volume"))
+    /// });
+    /// ui.with_baseline(&mut mute, &false, |ui, mute| ui.checkbox(mute, "Mute"));
+    /// # });
+    /// ```
+    pub fn with_baseline<Value: Clone + PartialEq>(
+        &mut self,
+        value: &mut Value,
+        baseline: &Value,
+        add_contents: impl FnOnce(&mut Ui, &mut Value) -> Response,
+    ) -> Response {
+        self.horizontal(|ui| {
+            let mut response = add_contents(ui, value);
+
+            let differs = *value != *baseline;
+            let size = Vec2::splat(ui.spacing().interact_size.y);
+            let (dot_rect, dot_response) = ui.allocate_exact_size(size, Sense::hover());
+            if differs && ui.is_rect_visible(dot_rect) {
+                let radius = 0.25 * dot_rect.height();
+                let color = ui.visuals().selection.bg_fill;
+                ui.painter().circle_filled(dot_rect.center(), radius, color);
+            }
+            if differs {
+                dot_response.on_hover_text("Changed from the baseline");
+            }
+
+            let revert = ui.add_visible(differs, Button::new("⟲").small().frame(false));
+            if revert.on_hover_text("Revert to the baseline").clicked() && !ui.is_read_only() {
+                *value = baseline.clone();
+                response.mark_changed();
+            }
+
+            response
+        })
+        .inner
+    }
+
     /// Shortcut for `add(Separator::default())`
     ///
     /// See also [`Separator`].
@@ -1679,10 +2021,15 @@ impl Ui {
     /// # egui::__run_test_ui(|ui| {
     /// ui.scope(|ui| {
     ///     ui.spacing_mut().slider_width = 200.0; // Temporary change
+    ///     ui.visuals_mut().override_text_color = Some(egui::Color32::RED);
     ///     // …
     /// });
+    /// // Back to the previous style here.
     /// # });
     /// ```
+    ///
+    /// The changes only affect the child ui (and its children).
+    /// Neither this [`Ui`] nor the global style in the [`Context`] is modified.
     pub fn scope<R>(&mut self, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
         self.scope_dyn(Box::new(add_contents), Id::new("child"))
     }
@@ -1713,6 +2060,84 @@ impl Ui {
         })
     }
 
+    /// Keep the contents visible at the top of the enclosing [`ScrollArea`] while it is scrolled past,
+    /// e.g. for section headers.
+    ///
+    /// The contents are painted on a layer above the rest of the scroll area,
+    /// and while pinned on top of the background color of windows and panels.
+    /// A later sticky [`Ui`] covers an earlier one, so each section header replaces the previous one.
+    ///
+    /// Only works in top-down layouts.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// egui::ScrollArea::vertical().show(ui, |ui| {
+    ///     for section in 0..10 {
+    ///         ui.sticky(|ui| ui.heading(format!("Section {}", section)));
+    ///         for item in 0..20 {
+    ///             ui.label(format!("Item {}", item));
+    ///         }
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    pub fn sticky<R>(&mut self, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        let id = self.auto_id_with("sticky");
+        // A scroll area clips its contents a bit outside of its inner rect:
+        let pin_top = self.clip_rect().top() + self.visuals().clip_rect_margin;
+        let last_size: Option<Vec2> = self.data().get_temp(id);
+        let layer_id = LayerId::new(self.layer_id().order, id);
+        self.ctx().set_sublayer(self.layer_id(), layer_id);
+
+        let scrolled_past =
+            self.layout().main_dir() == Direction::TopDown && self.cursor().top() < pin_top;
+        let last_size = match last_size {
+            Some(last_size) if scrolled_past => last_size,
+            _ => {
+                let response = self.with_layer_id(layer_id, add_contents);
+                self.data().insert_temp(id, response.response.rect.size());
+                return response;
+            }
+        };
+
+        // Keep the space in the layout, but show the contents pinned to the top:
+        let available = self.available_rect_before_wrap();
+        let pinned_rect = Rect::from_min_size(
+            pos2(available.left(), pin_top),
+            vec2(available.width(), last_size.y),
+        );
+
+        let next_auto_id_source = self.next_auto_id_source;
+        let mut child_ui =
+            self.child_ui_with_id_source(pinned_rect, *self.layout(), Id::new("child"));
+        self.next_auto_id_source = next_auto_id_source; // Same ids as when not pinned, like in `scope`.
+        child_ui.painter.set_layer_id(layer_id);
+        let background = child_ui.painter().add(Shape::Noop);
+        let inner = add_contents(&mut child_ui);
+
+        let content_rect = child_ui.min_rect();
+        let background_rect = Rect::from_x_y_ranges(
+            available.x_range(),
+            self.clip_rect().top()..=content_rect.bottom() + self.spacing().item_spacing.y,
+        );
+        let fill = self.visuals().window_fill();
+        child_ui
+            .painter()
+            .set(background, Shape::rect_filled(background_rect, 0.0, fill));
+        self.data().insert_temp(id, content_rect.size());
+
+        let (id, _) = self.allocate_space(content_rect.size());
+        let response = self.interact(content_rect, id, Sense::hover());
+        // Starting where the contents are pinned, so the scroll area around them reaches above it:
+        let covered_rect = Rect::from_x_y_ranges(
+            background_rect.x_range(),
+            pin_top..=background_rect.bottom(),
+        );
+        self.ctx()
+            .set_pinned_sticky_rect(self.layer_id(), self.clip_rect().intersect(covered_rect));
+        InnerResponse::new(inner, response)
+    }
+
     /// A [`CollapsingHeader`] that starts out collapsed.
     pub fn collapsing<R>(
         &mut self,
@@ -1724,7 +2149,21 @@ impl Ui {
 
     /// Create a child ui which is indented to the right.
     ///
+    /// The indentation is [`crate::style::Spacing::indent`].
+    /// A vertical guide line is painted to the left of the indented region
+    /// if [`crate::style::Visuals::indent_has_left_vline`] is set.
+    ///
     /// The `id_source` here be anything at all.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.label("Parent");
+    /// ui.indent("children", |ui| {
+    ///     ui.label("Child");
+    ///     ui.label("Another child");
+    /// });
+    /// # });
+    /// ```
     // TODO(emilk): remove `id_source` argument?
     #[inline]
     pub fn indent<R>(
@@ -1762,13 +2201,17 @@ impl Ui {
             child_ui.add_space(4.0);
         }
 
-        // draw a faint line on the left to mark the indented section
         let stroke = self.visuals().widgets.noninteractive.bg_stroke;
         let left_top = child_rect.min - 0.5 * indent * Vec2::X;
         let left_top = self.painter().round_pos_to_pixels(left_top);
         let left_bottom = pos2(left_top.x, child_ui.min_rect().bottom() - 2.0);
         let left_bottom = self.painter().round_pos_to_pixels(left_bottom);
-        self.painter.line_segment([left_top, left_bottom], stroke);
+
+        if self.visuals().indent_has_left_vline {
+            // draw a faint line on the left to mark the indented section
+            self.painter.line_segment([left_top, left_bottom], stroke);
+        }
+
         if end_with_horizontal_line {
             let fudge = 2.0; // looks nicer with button rounding in collapsing headers
             let right_bottom = pos2(child_ui.min_rect().right() - fudge, left_bottom.y);
@@ -1854,6 +2297,16 @@ impl Ui {
     /// but can be used for tooltips (`on_hover_text`).
     /// It also contains the [`Rect`] used by the horizontal layout.
     ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.horizontal_wrapped(|ui| {
+    ///     ui.label("This text and this");
+    ///     ui.hyperlink_to("link", "https://github.com/emilk/egui");
+    ///     ui.label("will wrap onto the next row if they don't fit.");
+    /// });
+    /// # });
+    /// ```
+    ///
     /// See also [`Self::with_layout`] for more options.
     pub fn horizontal_wrapped<R>(
         &mut self,
@@ -1951,6 +2404,21 @@ impl Ui {
     /// # });
     /// ```
     ///
+    /// Since the new layout fills the remaining space, it can be used to right-align
+    /// the buttons of a dialog while keeping other things on the left:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.horizontal(|ui| {
+    ///     ui.label("Save changes?");
+    ///     ui.with_layout(egui::Layout::right_to_left(), |ui| {
+    ///         if ui.button("OK").clicked() { /* … */ }
+    ///         if ui.button("Cancel").clicked() { /* … */ }
+    ///     });
+    /// });
+    /// # });
+    /// ```
+    ///
     /// See also [`Self::allocate_ui_with_layout`],
     /// and the helpers [`Self::horizontal`], [`Self::vertical`], etc.
     #[inline]
@@ -2005,8 +2473,23 @@ impl Ui {
         self.placer.is_grid()
     }
 
-    /// Move to the next row in a grid layout or wrapping layout.
+    /// Move to the next row in a grid layout or horizontal layout.
     /// Otherwise does nothing.
+    ///
+    /// The next row starts below the tallest widget of the current row,
+    /// separated by [`crate::style::Spacing::item_spacing`].
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// ui.horizontal(|ui| {
+    ///     let first = ui.label("First row");
+    ///     ui.end_row();
+    ///     let second = ui.label("Second row");
+    ///     assert!(first.rect.bottom() < second.rect.top());
+    ///     assert_eq!(first.rect.left(), second.rect.left());
+    /// });
+    /// # });
+    /// ```
     pub fn end_row(&mut self) {
         self.placer
             .end_row(self.spacing().item_spacing, &self.painter().clone());
@@ -2017,7 +2500,9 @@ impl Ui {
         self.placer.set_row_height(height);
     }
 
-    /// Temporarily split split an Ui into several columns.
+    /// Temporarily split an Ui into several columns of equal width.
+    ///
+    /// Afterwards the cursor is advanced past the tallest of the columns.
     ///
     /// ```
     /// # egui::__run_test_ui(|ui| {
@@ -2146,3 +2631,417 @@ impl Ui {
         }
     }
 }
+
+#[cfg(test)]
+#[test]
+fn read_only_ui() {
+    let mut harness = Harness::new();
+    let mut checked = false;
+    let mut slider_value = 0.0;
+    let mut drag_value = 0.0;
+    let mut text = String::from("Hello");
+
+    let mut run = |harness: &mut Harness| {
+        harness.run_ui(|ui| {
+            ui.read_only(|ui| {
+                let mut rects = vec![];
+                for response in [
+                    ui.checkbox(&mut checked, "Checkbox"),
+                    ui.add(Slider::new(&mut slider_value, 0.0..=100.0)),
+                    ui.add(DragValue::new(&mut drag_value)),
+                    ui.text_edit_singleline(&mut text),
+                ] {
+                    assert!(!response.changed());
+                    rects.push(response.rect);
+                }
+                rects
+            })
+            .inner
+        })
+    };
+
+    let rects = run(&mut harness);
+    let [checkbox, slider, drag, text_edit] = [rects[0], rects[1], rects[2], rects[3]];
+
+    harness.click(checkbox.center());
+    run(&mut harness);
+
+    harness.press(slider.right_center());
+    run(&mut harness);
+    harness.release(slider.right_center());
+    run(&mut harness);
+
+    harness.press(drag.center());
+    run(&mut harness);
+    harness.move_mouse(drag.center() + vec2(20.0, 0.0));
+    run(&mut harness);
+    harness.release(drag.center() + vec2(20.0, 0.0));
+    run(&mut harness);
+
+    harness.click(text_edit.center());
+    run(&mut harness);
+    assert!(
+        harness.ctx().memory().focus().is_some(),
+        "Text in a read-only Ui can still be selected"
+    );
+    harness.type_text("a");
+    harness.key_press(Key::Backspace);
+    run(&mut harness);
+
+    assert!(!checked);
+    assert_eq!(slider_value, 0.0);
+    assert_eq!(drag_value, 0.0);
+    assert_eq!(text, "Hello");
+}
+
+#[cfg(test)]
+#[test]
+fn with_baseline_revert() {
+    let mut harness = Harness::new();
+    let mut value = 3;
+
+    // Returns the rect of the value widget and if it reported a change:
+    let mut run = |harness: &mut Harness| {
+        harness.run_ui(|ui| {
+            let response =
+                ui.with_baseline(&mut value, &1, |ui, value| ui.add(DragValue::new(value)));
+            (response.rect, response.changed())
+        })
+    };
+
+    let (rect, changed) = run(&mut harness);
+    assert!(!changed);
+
+    // The revert button comes after the marker dot:
+    let spacing = harness.ctx().style().spacing.clone();
+    let revert_x = 2.0 * spacing.item_spacing.x + 1.5 * spacing.interact_size.y;
+    let revert_pos = rect.right_center() + vec2(revert_x, 0.0);
+    harness.press(revert_pos);
+    run(&mut harness);
+    harness.release(revert_pos);
+    let (_, changed) = run(&mut harness);
+    assert!(changed);
+    assert_eq!(value, 1);
+}
+
+#[cfg(test)]
+#[test]
+fn horizontal_layout() {
+    Harness::new().run_ui(|ui| {
+        let spacing = ui.spacing().item_spacing;
+        let row = ui.horizontal(|ui| {
+            let label = ui.label("Name:");
+            let button = ui.button("Button");
+            (label.rect, button.rect)
+        });
+        let (label, button) = row.inner;
+        assert_eq!(button.left(), label.right() + spacing.x);
+        assert!((label.center().y - button.center().y).abs() < 0.5);
+
+        let below = ui.label("Below");
+        assert_eq!(below.rect.top(), row.response.rect.bottom() + spacing.y);
+
+        let wrapped = ui.horizontal_wrapped(|ui| {
+            ui.set_max_width(100.0);
+            (0..20).map(|_| ui.label("word").rect).collect::<Vec<_>>()
+        });
+        let rects = wrapped.inner;
+        assert!(rects.last().unwrap().top() > rects[0].bottom());
+        assert!(wrapped.response.rect.width() <= 100.0);
+    });
+}
+
+#[cfg(test)]
+#[test]
+fn columns() {
+    Harness::new().run_ui(|ui| {
+        let available_width = ui.available_width();
+        let spacing = ui.spacing().item_spacing;
+        let top_left = ui.cursor().min;
+
+        let rects = ui.columns(3, |columns| {
+            columns[0].label("Short");
+            columns[1].label("Tall");
+            columns[1].label("column");
+            columns[1].label("here");
+            columns[2].label("Short");
+            columns.iter().map(|c| c.min_rect()).collect::<Vec<_>>()
+        });
+
+        let column_width = (available_width - 2.0 * spacing.x) / 3.0;
+        for (i, rect) in rects.iter().enumerate() {
+            let left = top_left.x + i as f32 * (column_width + spacing.x);
+            assert!((rect.width() - column_width).abs() < 0.01);
+            assert!((rect.left() - left).abs() < 0.01);
+            assert_eq!(rect.top(), top_left.y);
+        }
+
+        let below = ui.label("Below").rect;
+        assert_eq!(below.top(), rects[1].bottom() + spacing.y);
+    });
+}
+
+#[cfg(test)]
+#[test]
+fn put_at_rect() {
+    Harness::new().run_ui(|ui| {
+        let rect = Rect::from_min_size(pos2(300.0, 200.0), vec2(80.0, 30.0));
+        let button = ui.put(rect, Button::new("Button"));
+        assert_eq!(button.rect, rect);
+
+        let inner = ui.allocate_ui_at_rect(rect.translate(vec2(0.0, 100.0)), |ui| {
+            ui.label("Label").rect
+        });
+        assert_eq!(inner.inner.min, pos2(300.0, 300.0));
+        assert_eq!(inner.response.rect, inner.inner);
+    });
+}
+
+#[cfg(test)]
+#[test]
+fn allocate_exact_size_respects_layout() {
+    Harness::new().run_ui(|ui| {
+        let desired_size = vec2(40.0, 20.0);
+        let max_rect = ui.max_rect();
+
+        let (rect, response) = ui
+            .with_layout(Layout::top_down_justified(Align::Center), |ui| {
+                ui.allocate_exact_size(desired_size, Sense::click())
+            })
+            .inner;
+        assert_eq!(rect.size(), desired_size);
+        assert_eq!(rect.center().x, max_rect.center().x);
+        assert_eq!(response.rect.width(), max_rect.width());
+
+        let (rect, _) = ui
+            .with_layout(Layout::top_down(Align::Max), |ui| {
+                ui.allocate_exact_size(desired_size, Sense::hover())
+            })
+            .inner;
+        assert_eq!(rect.size(), desired_size);
+        assert_eq!(rect.right(), max_rect.right());
+    });
+}
+
+#[cfg(test)]
+#[test]
+fn right_aligned_button_row() {
+    Harness::new().run_ui(|ui| {
+        let max_rect = ui.max_rect();
+        let spacing = ui.spacing().item_spacing;
+
+        let row = ui.horizontal(|ui| {
+            let label = ui.label("Save changes?").rect;
+            ui.add_space(10.0);
+            let extra = ui.label("Unsaved").rect;
+            let (ok, cancel) = ui
+                .with_layout(Layout::right_to_left(), |ui| {
+                    (ui.button("OK").rect, ui.button("Cancel").rect)
+                })
+                .inner;
+            (label, extra, ok, cancel)
+        });
+        let (label, extra, ok, cancel) = row.inner;
+
+        assert_eq!(label.left(), max_rect.left());
+        assert!((extra.left() - (label.right() + spacing.x + 10.0)).abs() < 0.01);
+        assert_eq!(ok.right(), max_rect.right());
+        assert_eq!(cancel.right(), ok.left() - spacing.x);
+        assert_eq!(row.response.rect.width(), max_rect.width());
+    });
+}
+
+#[cfg(test)]
+#[test]
+fn indent() {
+    Harness::new().run_ui(|ui| {
+        ui.spacing_mut().indent = 30.0;
+        let parent = ui.label("Parent").rect;
+        let child = ui.indent("children", |ui| ui.label("Child").rect).inner;
+        assert_eq!(child.left(), parent.left() + 30.0);
+
+        let after = ui.label("After").rect;
+        assert_eq!(after.left(), parent.left());
+    });
+}
+
+#[cfg(test)]
+#[test]
+fn min_and_max_width() {
+    Harness::new().run_ui(|ui| {
+        let text =
+            "A long text that will wrap before it gets wider than the max width. ".repeat(10);
+        let label = ui
+            .vertical(|ui| {
+                ui.set_max_width(400.0);
+                ui.label(text).rect
+            })
+            .inner;
+        assert!(label.width() <= 400.0);
+        assert!(label.height() > ui.text_style_height(&TextStyle::Body));
+
+        let buttons = ui
+            .vertical(|ui| {
+                ui.set_min_width(150.0);
+                ui.set_max_width(150.0);
+                ui.with_layout(Layout::top_down_justified(Align::LEFT), |ui| {
+                    [ui.button("Open").rect, ui.button("Save as…").rect]
+                })
+                .inner
+            })
+            .inner;
+        assert!(buttons.iter().all(|rect| rect.width() == 150.0));
+    });
+}
+
+#[cfg(test)]
+#[test]
+fn wrapping_layout() {
+    Harness::new().run_ui(|ui| {
+        let rects = ui
+            .horizontal_wrapped(|ui| {
+                ui.set_max_width(200.0);
+                (0..12)
+                    .map(|i| {
+                        if i % 2 == 0 {
+                            ui.button(format!("Tag {}", i)).rect
+                        } else {
+                            let text = RichText::new(format!("chip {}", i)).small();
+                            ui.add(Label::new(text).wrap(false)).rect
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .inner;
+
+        let left = rects[0].left();
+        assert!(rects.iter().all(|rect| rect.right() <= left + 200.0));
+
+        let mut num_rows = 1;
+        for pair in rects.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            if b.left() > a.left() {
+                // Same row: vertically centered on each other.
+                assert!((a.center().y - b.center().y).abs() < 0.5);
+            } else {
+                assert!(b.top() >= a.bottom());
+                assert!((b.left() - left).abs() < 0.01);
+                num_rows += 1;
+            }
+        }
+        assert!(num_rows > 1);
+    });
+}
+
+#[cfg(test)]
+#[test]
+fn invisible_ui() {
+    let mut harness = Harness::new();
+
+    // Returns the button, and how many shapes were painted:
+    let run = |harness: &mut Harness, visible: bool| {
+        let button = harness.run_ui(|ui| {
+            let button = ui
+                .scope(|ui| {
+                    ui.set_visible(visible);
+                    assert_eq!(ui.is_rect_visible(ui.max_rect()), visible);
+                    ui.button("Button")
+                })
+                .inner;
+            ui.label("After");
+            button
+        });
+        (button, harness.output().shapes.len())
+    };
+
+    let (visible_button, num_visible_shapes) = run(&mut harness, true);
+    let (invisible_button, num_invisible_shapes) = run(&mut harness, false);
+    assert_eq!(
+        visible_button.rect, invisible_button.rect,
+        "Still allocated"
+    );
+    assert!(num_invisible_shapes < num_visible_shapes, "Not painted");
+
+    let pos = invisible_button.rect.center();
+    harness.press(pos);
+    run(&mut harness, false);
+    harness.release(pos);
+    let (button, _) = run(&mut harness, false);
+    assert!(!button.clicked(), "Not interactive");
+}
+
+#[cfg(test)]
+#[test]
+fn sticky_header() {
+    let mut harness = Harness::new();
+    let run = |harness: &mut Harness| {
+        harness.run_ui(|ui| {
+            let output = ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
+                let header = ui.sticky(|ui| ui.heading("Header")).response.rect;
+                for i in 0..50 {
+                    ui.label(format!("Item {}", i));
+                }
+                header
+            });
+            (output.inner, output.inner_rect)
+        })
+    };
+
+    let (header, inner_rect) = run(&mut harness);
+    assert_eq!(header.top(), inner_rect.top());
+
+    harness.move_mouse(inner_rect.center());
+    harness.push_event(Event::Scroll(vec2(0.0, -100.0)));
+    let _ = run(&mut harness);
+    let (header, _) = run(&mut harness);
+    assert_eq!(header.top(), inner_rect.top(), "Pinned to the top");
+
+    // Painted on top of the items scrolling under it:
+    let text_index = |text: &str| {
+        harness
+            .output()
+            .shapes
+            .iter()
+            .position(|clipped| match &clipped.1 {
+                Shape::Text(shape) => shape.galley.text() == text,
+                _ => false,
+            })
+    };
+    assert!(text_index("Header") > text_index("Item 49"));
+}
+
+#[cfg(test)]
+#[test]
+fn sticky_header_covers_hover() {
+    let mut harness = Harness::new();
+    let run = |harness: &mut Harness| {
+        harness.run_ui(|ui| {
+            let output = ScrollArea::vertical().max_height(200.0).show(ui, |ui| {
+                let header = ui.sticky(|ui| ui.button("Header")).inner;
+                let hovered_items: Vec<usize> = (0..50)
+                    .filter(|i| ui.button(format!("Item {}", i)).hovered())
+                    .collect();
+                (header.rect, header.hovered(), hovered_items)
+            });
+            (output.inner, output.inner_rect, output.state.offset.y)
+        })
+    };
+
+    let (_, inner_rect, _) = run(&mut harness);
+    harness.move_mouse(inner_rect.center());
+    harness.push_event(Event::Scroll(vec2(0.0, -100.0)));
+    let _ = run(&mut harness);
+    let ((header, _, _), _, _) = run(&mut harness);
+
+    harness.move_mouse(header.center());
+    let _ = run(&mut harness);
+    let ((_, header_hovered, hovered_items), _, offset) = run(&mut harness);
+    assert!(header_hovered);
+    assert_eq!(hovered_items, Vec::<usize>::new(), "Covered by the header");
+
+    // The scroll area still scrolls under the header:
+    harness.push_event(Event::Scroll(vec2(0.0, -50.0)));
+    let _ = run(&mut harness);
+    let (_, _, new_offset) = run(&mut harness);
+    assert!(new_offset > offset);
+}
@@ -332,21 +332,35 @@ use crate::Id;
 /// ```
 #[derive(Clone, Debug, Default)]
 // We store use `id XOR typeid` as a key, so we don't need to hash again!
-pub struct IdTypeMap(nohash_hasher::IntMap<u64, Element>);
+pub struct IdTypeMap {
+    map: nohash_hasher::IntMap<u64, Element>,
+
+    /// The generation in which each value was last read or written.
+    /// Used to forget values that are no longer used, see [`Self::gc`].
+    last_used: nohash_hasher::IntMap<u64, u64>,
+
+    /// Increased by [`Self::next_generation`], e.g. once per frame.
+    generation: u64,
+
+    /// Only fill in [`Self::last_used`] if we are going to call [`Self::gc`].
+    track_last_used: bool,
+}
 
 impl IdTypeMap {
     /// Insert a value that will not be persisted.
     #[inline]
     pub fn insert_temp<T: 'static + Any + Clone + Send + Sync>(&mut self, id: Id, value: T) {
         let hash = hash(TypeId::of::<T>(), id);
-        self.0.insert(hash, Element::new_temp(value));
+        self.touch(hash);
+        self.map.insert(hash, Element::new_temp(value));
     }
 
     /// Insert a value that will be persisted next time you start the app.
     #[inline]
     pub fn insert_persisted<T: SerializableAny>(&mut self, id: Id, value: T) {
         let hash = hash(TypeId::of::<T>(), id);
-        self.0.insert(hash, Element::new_persisted(value));
+        self.touch(hash);
+        self.map.insert(hash, Element::new_persisted(value));
     }
 
     /// Read a value without trying to deserialize a persisted value.
@@ -355,7 +369,8 @@ impl IdTypeMap {
     #[inline]
     pub fn get_temp<T: 'static + Clone>(&mut self, id: Id) -> Option<T> {
         let hash = hash(TypeId::of::<T>(), id);
-        self.0
+        self.touch(hash);
+        self.map
             .get_mut(&hash)
             .and_then(|x| x.get_mut_temp())
             .cloned()
@@ -367,7 +382,8 @@ impl IdTypeMap {
     #[inline]
     pub fn get_persisted<T: SerializableAny>(&mut self, id: Id) -> Option<T> {
         let hash = hash(TypeId::of::<T>(), id);
-        self.0
+        self.touch(hash);
+        self.map
             .get_mut(&hash)
             .and_then(|x| x.get_mut_persisted())
             .cloned()
@@ -406,8 +422,9 @@ impl IdTypeMap {
         insert_with: impl FnOnce() -> T,
     ) -> &mut T {
         let hash = hash(TypeId::of::<T>(), id);
+        self.touch(hash);
         use std::collections::hash_map::Entry;
-        match self.0.entry(hash) {
+        match self.map.entry(hash) {
             Entry::Vacant(vacant) => vacant
                 .insert(Element::new_temp(insert_with()))
                 .get_mut_temp()
@@ -424,8 +441,9 @@ impl IdTypeMap {
         insert_with: impl FnOnce() -> T,
     ) -> &mut T {
         let hash = hash(TypeId::of::<T>(), id);
+        self.touch(hash);
         use std::collections::hash_map::Entry;
-        match self.0.entry(hash) {
+        match self.map.entry(hash) {
             Entry::Vacant(vacant) => vacant
                 .insert(Element::new_persisted(insert_with()))
                 .get_mut_persisted()
@@ -440,37 +458,40 @@ impl IdTypeMap {
     #[inline]
     pub fn remove<T: 'static>(&mut self, id: Id) {
         let hash = hash(TypeId::of::<T>(), id);
-        self.0.remove(&hash);
+        self.map.remove(&hash);
+        self.last_used.remove(&hash);
     }
 
     /// Note all state of the given type.
     pub fn remove_by_type<T: 'static>(&mut self) {
         let key = TypeId::of::<T>();
-        self.0.retain(|_, e| {
+        self.map.retain(|_, e| {
             let e: &Element = e;
             e.type_id() != key
         });
+        self.forget_removed();
     }
 
     #[inline]
     pub fn clear(&mut self) {
-        self.0.clear();
+        self.map.clear();
+        self.last_used.clear();
     }
 
     #[inline]
     pub fn is_empty(&mut self) -> bool {
-        self.0.is_empty()
+        self.map.is_empty()
     }
 
     #[inline]
     pub fn len(&mut self) -> usize {
-        self.0.len()
+        self.map.len()
     }
 
     /// Count how many values are stored but not yet deserialized.
     #[inline]
     pub fn count_serialized(&mut self) -> usize {
-        self.0
+        self.map
             .values()
             .filter(|e| matches!(e, Element::Serialized { .. }))
             .count()
@@ -479,7 +500,7 @@ impl IdTypeMap {
     /// Count the number of values are stored with the given type.
     pub fn count<T: 'static>(&mut self) -> usize {
         let key = TypeId::of::<T>();
-        self.0
+        self.map
             .iter()
             .filter(|(_, e)| {
                 let e: &Element = e;
@@ -487,6 +508,57 @@ impl IdTypeMap {
             })
             .count()
     }
+
+    /// The current generation, see [`Self::next_generation`].
+    pub(crate) fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Start a new generation, e.g. at the end of each frame. See [`Self::gc`].
+    pub(crate) fn next_generation(&mut self) {
+        self.generation += 1;
+    }
+
+    /// Keep track of when each value was last used. Required for [`Self::gc`] to work well.
+    pub(crate) fn set_track_last_used(&mut self, track_last_used: bool) {
+        if !track_last_used {
+            self.last_used.clear();
+        }
+        self.track_last_used = track_last_used;
+    }
+
+    /// Remove all values that have not been read or written in the last `max_age` generations.
+    ///
+    /// Returns the number of removed values.
+    pub(crate) fn gc(&mut self, max_age: u64) -> usize {
+        let Self {
+            map,
+            last_used,
+            generation,
+            track_last_used: _,
+        } = self;
+        let len_before = map.len();
+        map.retain(|hash, _| {
+            // Values we don't know about (e.g. just loaded from disk) count as just used:
+            let used = *last_used.entry(*hash).or_insert(*generation);
+            *generation - used <= max_age
+        });
+        self.forget_removed();
+        len_before - self.map.len()
+    }
+
+    #[inline]
+    fn touch(&mut self, hash: u64) {
+        if !self.track_last_used {
+            return;
+        }
+        self.last_used.insert(hash, self.generation);
+    }
+
+    fn forget_removed(&mut self) {
+        let Self { map, last_used, .. } = self;
+        last_used.retain(|hash, _| map.contains_key(hash));
+    }
 }
 
 #[inline(always)]
@@ -506,21 +578,23 @@ impl PersistedMap {
     fn from_map(map: &IdTypeMap) -> Self {
         // filter out the elements which cannot be serialized:
         Self(
-            map.0
+            map.map
                 .iter()
                 .filter_map(|(&hash, element)| Some((hash, element.to_serialize()?)))
                 .collect(),
         )
     }
     fn into_map(self) -> IdTypeMap {
-        IdTypeMap(
-            self.0
+        IdTypeMap {
+            map: self
+                .0
                 .into_iter()
                 .map(|(hash, SerializedElement { type_id, ron })| {
                     (hash, Element::Serialized { type_id, ron })
                 })
                 .collect(),
-        )
+            ..Default::default()
+        }
     }
 }
 
@@ -715,3 +789,32 @@ fn test_mix_serialize() {
     );
     assert_eq!(map.get_temp::<Serializable>(id), Some(Serializable(555)));
 }
+
+#[test]
+fn test_gc() {
+    let a = Id::new("a");
+    let b = Id::new("b");
+
+    let mut map: IdTypeMap = Default::default();
+    map.insert_temp(a, 1_i32);
+    assert!(map.last_used.is_empty(), "Only track usage when needed");
+
+    map.set_track_last_used(true);
+    map.insert_temp(b, 2_i32);
+    for _ in 0..3 {
+        map.next_generation();
+        let _ = map.get_temp::<i32>(b);
+    }
+    assert_eq!(
+        map.gc(2),
+        0,
+        "Values we don't know about count as just used"
+    );
+    map.next_generation();
+    map.next_generation();
+    map.next_generation();
+    let _ = map.get_temp::<i32>(b);
+    assert_eq!(map.gc(2), 1);
+    assert_eq!(map.get_temp::<i32>(a), None);
+    assert_eq!(map.get_temp::<i32>(b), Some(2));
+}
@@ -57,15 +57,22 @@ pub struct Undoer<State> {
     /// The latest undo point may (often) be the current state.
     undos: VecDeque<State>,
 
+    /// The states we undid, so that we can redo them.
+    /// The latest undo is at the back.
+    /// Cleared whenever a new undo point is added.
+    #[cfg_attr(feature = "serde", serde(default))]
+    redos: Vec<State>,
+
     #[cfg_attr(feature = "serde", serde(skip))]
     flux: Option<Flux<State>>,
 }
 
 impl<State> std::fmt::Debug for Undoer<State> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let Self { undos, .. } = self;
+        let Self { undos, redos, .. } = self;
         f.debug_struct("Undoer")
             .field("undo count", &undos.len())
+            .field("redo count", &redos.len())
             .finish()
     }
 }
@@ -91,6 +98,13 @@ where
         }
     }
 
+    /// Do we have a redo point different from the given state?
+    ///
+    /// Any change to the state after an undo means there is nothing to redo anymore.
+    pub fn has_redo(&self, current_state: &State) -> bool {
+        !self.redos.is_empty() && self.undos.back() == Some(current_state)
+    }
+
     /// Return true if the state is currently changing
     pub fn is_in_flux(&self) -> bool {
         self.flux.is_some()
@@ -101,7 +115,9 @@ where
             self.flux = None;
 
             if self.undos.back() == Some(current_state) {
-                self.undos.pop_back();
+                self.redos.extend(self.undos.pop_back());
+            } else {
+                self.redos.push(current_state.clone());
             }
 
             // Note: we keep the undo point intact.
@@ -111,12 +127,24 @@ where
         }
     }
 
+    /// Go back to the state before the latest [`Self::undo`].
+    pub fn redo(&mut self, current_state: &State) -> Option<&State> {
+        if self.has_redo(current_state) {
+            self.flux = None;
+            self.undos.extend(self.redos.pop());
+            self.undos.back()
+        } else {
+            None
+        }
+    }
+
     /// Add an undo point if, and only if, there has been a change since the latest undo point.
     ///
     /// * `time`: current time in seconds.
     pub fn add_undo(&mut self, current_state: &State) {
         if self.undos.back() != Some(current_state) {
             self.undos.push_back(current_state.clone());
+            self.redos.clear();
         }
         while self.undos.len() > self.settings.max_undos {
             self.undos.pop_front();
@@ -170,3 +198,29 @@ where
         }
     }
 }
+
+#[cfg(test)]
+#[test]
+fn test_undo_redo() {
+    let mut undoer = Undoer::<i32>::default();
+    undoer.add_undo(&1);
+    undoer.add_undo(&2);
+    assert!(!undoer.has_redo(&3));
+
+    // Undo a change that hasn't become an undo point yet:
+    assert_eq!(undoer.undo(&3), Some(&2));
+    assert_eq!(undoer.undo(&2), Some(&1));
+    assert_eq!(undoer.undo(&1), None);
+
+    assert_eq!(undoer.redo(&1), Some(&2));
+    assert_eq!(undoer.redo(&2), Some(&3));
+    assert_eq!(undoer.redo(&3), None);
+
+    // A new edit after an undo means there is nothing to redo:
+    assert_eq!(undoer.undo(&3), Some(&2));
+    assert!(!undoer.has_redo(&4));
+    undoer.feed_state(0.0, &4);
+    undoer.feed_state(10.0, &4);
+    assert_eq!(undoer.redo(&4), None);
+    assert_eq!(undoer.undo(&4), Some(&2));
+}
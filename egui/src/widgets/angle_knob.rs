@@ -0,0 +1,164 @@
+use std::f32::consts::{PI, TAU};
+
+use crate::*;
+
+/// A circular knob for editing an angle, e.g. a rotation or the direction of a light.
+///
+/// Drag around the center to change the angle. Hold down shift to snap to common angles.
+/// The angle is in radians, with `0` pointing right and positive angles turning clockwise
+/// (since Y points down).
+///
+/// The angle is NOT wrapped, so turning the knob around twice gives you 720° (4π).
+/// With keyboard focus, the arrow keys nudge the angle by one degree (or one snap angle when holding shift).
+///
+/// See also [`Ui::drag_angle`] for editing the angle as a number.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let mut radians = 0.0;
+/// ui.add(egui::AngleKnob::new(&mut radians))
+///     .on_hover_text(format!("{:.0}°", radians.to_degrees()));
+/// # });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct AngleKnob<'a> {
+    radians: &'a mut f32,
+    diameter: Option<f32>,
+    snap_angle: f32,
+}
+
+impl<'a> AngleKnob<'a> {
+    pub fn new(radians: &'a mut f32) -> Self {
+        Self {
+            radians,
+            diameter: None,
+            snap_angle: 15.0_f32.to_radians(),
+        }
+    }
+
+    /// The size of the knob. Default: twice the height of a button.
+    pub fn diameter(mut self, diameter: f32) -> Self {
+        self.diameter = Some(diameter);
+        self
+    }
+
+    /// What to snap to when holding shift, in radians. Default: 15°.
+    pub fn snap_angle(mut self, snap_angle: f32) -> Self {
+        self.snap_angle = snap_angle;
+        self
+    }
+}
+
+impl<'a> Widget for AngleKnob<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            radians,
+            diameter,
+            snap_angle,
+        } = self;
+
+        let diameter = diameter.unwrap_or_else(|| 2.0 * ui.spacing().interact_size.y);
+        let (rect, mut response) =
+            ui.allocate_exact_size(Vec2::splat(diameter), Sense::click_and_drag());
+        let center = rect.center();
+
+        let snap = ui.input().modifiers.shift_only() && snap_angle > 0.0;
+        let snapped = |angle: f32| {
+            if snap {
+                (angle / snap_angle).round() * snap_angle
+            } else {
+                angle
+            }
+        };
+
+        let old_radians = *radians;
+        if let Some(pointer_pos) = response.interact_pointer_pos() {
+            let delta = pointer_pos - center;
+            if delta.length() > 1.0 {
+                // Turn the shortest way towards the pointer, to keep track of whole turns:
+                let target = delta.y.atan2(delta.x);
+                let turn = (target - *radians + PI).rem_euclid(TAU) - PI;
+                *radians = snapped(*radians + turn);
+            }
+        }
+
+        if response.has_focus() {
+            ui.memory().capture_arrow_keys(response.id, true, true);
+            let steps = {
+                let input = ui.input();
+                input.num_presses(Key::ArrowRight) as f32 + input.num_presses(Key::ArrowUp) as f32
+                    - input.num_presses(Key::ArrowLeft) as f32
+                    - input.num_presses(Key::ArrowDown) as f32
+            };
+            if steps != 0.0 {
+                let step = if snap {
+                    snap_angle
+                } else {
+                    1.0_f32.to_radians()
+                };
+                *radians = snapped(*radians + steps * step);
+            }
+        }
+
+        if *radians != old_radians {
+            response.mark_changed();
+        }
+        response.widget_info(|| WidgetInfo::slider(radians.to_degrees() as f64, "angle"));
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.style().interact(&response);
+            let radius = 0.5 * diameter - visuals.expansion.at_least(0.0);
+            let painter = ui.painter();
+            painter.circle(center, radius, visuals.bg_fill, visuals.bg_stroke);
+
+            if snap && (response.hovered() || response.dragged()) {
+                let num_ticks = (TAU / snap_angle).round() as usize;
+                let tick_stroke = Stroke::new(1.0, ui.visuals().weak_text_color());
+                for i in 0..num_ticks.min(360) {
+                    let direction = Vec2::angled(i as f32 * snap_angle);
+                    painter.line_segment(
+                        [
+                            center + 0.8 * radius * direction,
+                            center + radius * direction,
+                        ],
+                        tick_stroke,
+                    );
+                }
+            }
+
+            let tip = center + 0.8 * radius * Vec2::angled(*radians);
+            painter.line_segment([center, tip], visuals.fg_stroke);
+            painter.circle_filled(tip, 0.1 * radius, visuals.fg_stroke.color);
+        }
+
+        response
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn angle_knob() {
+    let mut harness = Harness::new();
+    let mut radians = 0.0;
+    let rect = harness.run_ui(|ui| ui.add(AngleKnob::new(&mut radians)).rect);
+
+    // Drag from the right, around the bottom, to the left:
+    harness.press(rect.right_center());
+    harness.run_ui(|ui| ui.add(AngleKnob::new(&mut radians)));
+    harness.move_mouse(rect.center_bottom() + vec2(1.0, 0.0));
+    harness.run_ui(|ui| ui.add(AngleKnob::new(&mut radians)));
+    harness.move_mouse(rect.left_center());
+    let response = harness.run_ui(|ui| ui.add(AngleKnob::new(&mut radians)));
+    assert!(response.changed());
+    assert!((radians - std::f32::consts::PI).abs() < 1e-5, "{}", radians);
+
+    // Keep going around, and hold shift to snap:
+    harness.move_mouse(rect.center_top() + vec2(3.0, 0.0));
+    harness.run_ui(|ui| ui.add(AngleKnob::new(&mut radians)));
+    harness.set_modifiers(Modifiers::SHIFT);
+    harness.move_mouse(rect.right_center() + vec2(0.0, 1.0));
+    harness.run_ui(|ui| ui.add(AngleKnob::new(&mut radians)));
+    assert_eq!(radians.to_degrees().round(), 360.0, "One full turn");
+    harness.release(rect.right_center());
+    harness.run_ui(|ui| ui.add(AngleKnob::new(&mut radians)));
+}
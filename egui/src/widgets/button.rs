@@ -16,11 +16,21 @@ use crate::*;
 /// if ui.add_enabled(false, egui::Button::new("Can't click this")).clicked() {
 ///     unreachable!();
 /// }
+///
+/// // A frameless toolbar button with a leading icon, that is at least 80 points wide:
+/// ui.add(
+///     egui::Button::new("Search")
+///         .icon("🔍")
+///         .frame(false)
+///         .min_size(egui::vec2(80.0, 0.0)),
+/// );
 /// # });
 /// ```
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 pub struct Button {
     text: WidgetText,
+    icon: WidgetText,
+    shortcut_text: WidgetText,
     wrap: Option<bool>,
     /// None means default for interact
     fill: Option<Color32>,
@@ -36,6 +46,8 @@ impl Button {
     pub fn new(text: impl Into<WidgetText>) -> Self {
         Self {
             text: text.into(),
+            icon: Default::default(),
+            shortcut_text: Default::default(),
             wrap: None,
             fill: None,
             stroke: None,
@@ -54,17 +66,25 @@ impl Button {
         size: impl Into<Vec2>,
         text: impl Into<WidgetText>,
     ) -> Self {
-        Self {
-            text: text.into(),
-            fill: None,
-            stroke: None,
-            sense: Sense::click(),
-            small: false,
-            frame: None,
-            wrap: None,
-            min_size: Vec2::ZERO,
-            image: Some(widgets::Image::new(texture_id, size)),
-        }
+        Self::new(text).image(texture_id, size)
+    }
+
+    /// Show an image to the left of the text.
+    ///
+    /// The size of the image as displayed is defined by the size [`Vec2`] provided.
+    /// Takes precedence over [`Self::icon`].
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn image(mut self, texture_id: TextureId, size: impl Into<Vec2>) -> Self {
+        self.image = Some(widgets::Image::new(texture_id, size));
+        self
+    }
+
+    /// Show a glyph (e.g. an emoji like `"🔍"`) to the left of the text.
+    ///
+    /// It is painted in the same color as the text.
+    pub fn icon(mut self, icon: impl Into<WidgetText>) -> Self {
+        self.icon = icon.into();
+        self
     }
 
     /// If `true`, the text will wrap to stay within the max width of the [`Ui`].
@@ -97,8 +117,11 @@ impl Button {
     }
 
     /// Make this a small button, suitable for embedding into text.
+    ///
+    /// Together with [`Self::frame`] this is good for compact toolbars.
     pub fn small(mut self) -> Self {
         self.text = self.text.text_style(TextStyle::Body);
+        self.icon = self.icon.text_style(TextStyle::Body);
         self.small = true;
         self
     }
@@ -116,7 +139,20 @@ impl Button {
         self
     }
 
-    pub(crate) fn min_size(mut self, min_size: Vec2) -> Self {
+    /// Show some text on the right side of the button, in weak color.
+    ///
+    /// Designed for menu buttons, for setting a keyboard shortcut text (e.g. `Ctrl+S`).
+    ///
+    /// The text can be created with [`KeyboardShortcut::format`].
+    pub fn shortcut_text(mut self, shortcut_text: impl Into<WidgetText>) -> Self {
+        self.shortcut_text = shortcut_text.into();
+        self
+    }
+
+    /// The button will be at least this large, e.g. to give a row of buttons the same width.
+    ///
+    /// The contents are laid out within the button according to the [`Layout`] of the [`Ui`].
+    pub fn min_size(mut self, min_size: Vec2) -> Self {
         self.min_size = min_size;
         self
     }
@@ -126,6 +162,8 @@ impl Widget for Button {
     fn ui(self, ui: &mut Ui) -> Response {
         let Button {
             text,
+            icon,
+            shortcut_text,
             wrap,
             fill,
             stroke,
@@ -144,34 +182,60 @@ impl Widget for Button {
         }
         let total_extra = button_padding + button_padding;
 
-        let wrap_width = ui.available_width() - total_extra.x;
+        // The image or icon to the left of the text:
+        let icon = if image.is_none() && !icon.is_empty() {
+            Some(icon.into_galley(ui, Some(false), f32::INFINITY, TextStyle::Button))
+        } else {
+            None
+        };
+        let leading_size = match (&image, &icon) {
+            (Some(image), _) => image.size(),
+            (None, Some(icon)) => icon.size(),
+            (None, None) => Vec2::ZERO,
+        };
+        let leading_width = if image.is_some() || icon.is_some() {
+            leading_size.x + ui.spacing().icon_spacing
+        } else {
+            0.0
+        };
+
+        let shortcut_text = (!shortcut_text.is_empty())
+            .then(|| shortcut_text.into_galley(ui, Some(false), f32::INFINITY, TextStyle::Button));
+        let shortcut_width = shortcut_text.as_ref().map_or(0.0, |shortcut_text| {
+            ui.spacing().item_spacing.x + shortcut_text.size().x
+        });
+
+        let wrap_width = ui.available_width() - total_extra.x - leading_width - shortcut_width;
         let text = text.into_galley(ui, wrap, wrap_width, TextStyle::Button);
 
-        let mut desired_size = text.size() + 2.0 * button_padding;
+        let mut desired_size = text.size();
+        desired_size.x += leading_width + shortcut_width;
+        desired_size.y = desired_size.y.max(leading_size.y);
+        desired_size += 2.0 * button_padding;
         if !small {
             desired_size.y = desired_size.y.at_least(ui.spacing().interact_size.y);
         }
         desired_size = desired_size.at_least(min_size);
 
-        if let Some(image) = image {
-            desired_size.x += image.size().x + ui.spacing().icon_spacing;
-            desired_size.y = desired_size.y.max(image.size().y + 2.0 * button_padding.y);
-        }
-
         let (rect, response) = ui.allocate_at_least(desired_size, sense);
         response.widget_info(|| WidgetInfo::labeled(WidgetType::Button, text.text()));
 
         if ui.is_rect_visible(rect) {
-            let visuals = ui.style().interact(&response);
-            let text_pos = if let Some(image) = image {
-                let icon_spacing = ui.spacing().icon_spacing;
+            let mut visuals = *ui.style().interact(&response);
+            if let Some(fill) = fill {
+                // Make sure the text is readable on top of the custom fill:
+                visuals.fg_stroke.color = fill.contrasting_text_color();
+            }
+            let text_pos = if leading_width > 0.0 {
                 pos2(
-                    rect.min.x + button_padding.x + image.size().x + icon_spacing,
+                    rect.min.x + button_padding.x + leading_width,
                     rect.center().y - 0.5 * text.size().y,
                 )
             } else {
+                let mut text_rect = rect.shrink2(button_padding);
+                text_rect.max.x -= shortcut_width;
                 ui.layout()
-                    .align_size_within_rect(text.size(), rect.shrink2(button_padding))
+                    .align_size_within_rect(text.size(), text_rect)
                     .min
             };
 
@@ -186,15 +250,32 @@ impl Widget for Button {
                 );
             }
 
-            text.paint_with_visuals(ui.painter(), text_pos, visuals);
-        }
-
-        if let Some(image) = image {
-            let image_rect = Rect::from_min_size(
-                pos2(rect.min.x, rect.center().y - 0.5 - (image.size().y / 2.0)),
-                image.size(),
+            let leading_rect = Rect::from_min_size(
+                pos2(
+                    rect.min.x + button_padding.x,
+                    rect.center().y - 0.5 * leading_size.y,
+                ),
+                leading_size,
             );
-            image.paint_at(ui, image_rect);
+            if let Some(image) = image {
+                image.paint_at(ui, leading_rect);
+            } else if let Some(icon) = icon {
+                icon.paint_with_visuals(ui.painter(), leading_rect.min, &visuals);
+            }
+
+            text.paint_with_visuals(ui.painter(), text_pos, &visuals);
+
+            if let Some(shortcut_text) = shortcut_text {
+                let shortcut_text_pos = pos2(
+                    rect.max.x - button_padding.x - shortcut_text.size().x,
+                    rect.center().y - 0.5 * shortcut_text.size().y,
+                );
+                shortcut_text.paint_with_fallback_color(
+                    ui.painter(),
+                    shortcut_text_pos,
+                    ui.visuals().weak_text_color(),
+                );
+            }
         }
 
         response
@@ -257,7 +338,7 @@ impl<'a> Widget for Checkbox<'a> {
         desired_size.y = desired_size.y.max(icon_width);
         let (rect, mut response) = ui.allocate_exact_size(desired_size, Sense::click());
 
-        if response.clicked() {
+        if response.clicked() && !ui.is_read_only() {
             *checked = !*checked;
             response.mark_changed();
         }
@@ -415,6 +496,29 @@ impl Widget for RadioButton {
 // ----------------------------------------------------------------------------
 
 /// A clickable image within a frame.
+///
+/// Good for icon toolbars and asset pickers:
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # let texture_id = egui::TextureId::default();
+/// # let mut selected_icon = 0;
+/// // Four icons from the same texture atlas:
+/// for i in 0..4 {
+///     let uv = egui::Rect::from_min_size(egui::pos2(0.25 * i as f32, 0.0), egui::vec2(0.25, 1.0));
+///     let button = egui::ImageButton::new(texture_id, [24.0, 24.0])
+///         .uv(uv)
+///         .frame(false)
+///         .tint(egui::Color32::GRAY)
+///         .hover_tint(egui::Color32::WHITE)
+///         .selected_tint(egui::Color32::LIGHT_BLUE)
+///         .selected(selected_icon == i);
+///     if ui.add(button).clicked() {
+///         selected_icon = i;
+///     }
+/// }
+/// # });
+/// ```
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 #[derive(Clone, Debug)]
 pub struct ImageButton {
@@ -422,6 +526,8 @@ pub struct ImageButton {
     sense: Sense,
     frame: bool,
     selected: bool,
+    hover_tint: Option<Color32>,
+    selected_tint: Option<Color32>,
 }
 
 impl ImageButton {
@@ -431,6 +537,8 @@ impl ImageButton {
             sense: Sense::click(),
             frame: true,
             selected: false,
+            hover_tint: None,
+            selected_tint: None,
         }
     }
 
@@ -446,6 +554,20 @@ impl ImageButton {
         self
     }
 
+    /// Use this tint instead of [`Self::tint`] while the button is hovered or has keyboard focus.
+    ///
+    /// Useful to highlight frameless buttons.
+    pub fn hover_tint(mut self, tint: impl Into<Color32>) -> Self {
+        self.hover_tint = Some(tint.into());
+        self
+    }
+
+    /// Use this tint instead of [`Self::tint`] while the button is [`Self::selected`] (and not hovered).
+    pub fn selected_tint(mut self, tint: impl Into<Color32>) -> Self {
+        self.selected_tint = Some(tint.into());
+        self
+    }
+
     /// If `true`, mark this button as "selected".
     pub fn selected(mut self, selected: bool) -> Self {
         self.selected = selected;
@@ -473,6 +595,8 @@ impl Widget for ImageButton {
             sense,
             frame,
             selected,
+            hover_tint,
+            selected_tint,
         } = self;
 
         let padding = if frame {
@@ -483,7 +607,10 @@ impl Widget for ImageButton {
         };
         let padded_size = image.size() + 2.0 * padding;
         let (rect, response) = ui.allocate_exact_size(padded_size, sense);
-        response.widget_info(|| WidgetInfo::new(WidgetType::ImageButton));
+        response.widget_info(|| WidgetInfo {
+            selected: Some(selected),
+            ..WidgetInfo::new(WidgetType::ImageButton)
+        });
 
         if ui.is_rect_visible(rect) {
             let (expansion, rounding, fill, stroke) = if selected {
@@ -515,6 +642,17 @@ impl Widget for ImageButton {
                 .layout()
                 .align_size_within_rect(image.size(), rect.shrink2(padding));
             // let image_rect = image_rect.expand2(expansion); // can make it blurry, so let's not
+            let is_hovered = response.hovered() || response.has_focus();
+            let tint = match (hover_tint, selected_tint) {
+                (Some(hover_tint), _) if is_hovered => Some(hover_tint),
+                (_, Some(selected_tint)) if selected && !is_hovered => Some(selected_tint),
+                _ => None,
+            };
+            let image = if let Some(tint) = tint {
+                image.tint(tint)
+            } else {
+                image
+            };
             image.paint_at(ui, image_rect);
 
             // Draw frame outline:
@@ -525,3 +663,68 @@ impl Widget for ImageButton {
         response
     }
 }
+
+#[cfg(test)]
+#[test]
+fn button_options() {
+    let mut harness = Harness::new();
+    let [plain, with_icon, with_min_size, small] = harness.run_ui(|ui| {
+        [
+            ui.add(Button::new("Search")).rect,
+            ui.add(Button::new("Search").icon("🔍")).rect,
+            ui.add(Button::new("Search").min_size(vec2(200.0, 50.0)))
+                .rect,
+            ui.add(Button::new("Search").small().frame(false)).rect,
+        ]
+    });
+    let icon_spacing = harness.ctx().style().spacing.icon_spacing;
+    assert!(with_icon.width() > plain.width() + icon_spacing);
+    assert_eq!(with_min_size.size(), vec2(200.0, 50.0));
+    assert!(small.height() < plain.height());
+
+    let fill = Color32::from_rgb(1, 2, 3);
+    harness.run_ui(|ui| ui.add(Button::new("Filled").fill(fill)));
+    let has_fill = harness
+        .output()
+        .shapes
+        .iter()
+        .any(|clipped| matches!(&clipped.1, Shape::Rect(rect) if rect.fill == fill));
+    assert!(has_fill);
+}
+
+#[cfg(test)]
+#[test]
+fn image_button_tints() {
+    let mut harness = Harness::new();
+    let texture_id = TextureId::User(42);
+    let add_button = |ui: &mut Ui, selected: bool| {
+        ui.add(
+            ImageButton::new(texture_id, [32.0, 32.0])
+                .frame(false)
+                .tint(Color32::GRAY)
+                .hover_tint(Color32::WHITE)
+                .selected_tint(Color32::RED)
+                .selected(selected),
+        )
+    };
+    let image_tint = |harness: &Harness| {
+        harness
+            .output()
+            .shapes
+            .iter()
+            .find_map(|clipped| match &clipped.1 {
+                Shape::Mesh(mesh) if mesh.texture_id == texture_id => Some(mesh.vertices[0].color),
+                _ => None,
+            })
+    };
+
+    let rect = harness.run_ui(|ui| add_button(ui, false).rect);
+    assert_eq!(image_tint(&harness), Some(Color32::GRAY));
+
+    harness.run_ui(|ui| add_button(ui, true));
+    assert_eq!(image_tint(&harness), Some(Color32::RED));
+
+    harness.move_mouse(rect.center());
+    harness.run_ui(|ui| add_button(ui, true));
+    assert_eq!(image_tint(&harness), Some(Color32::WHITE));
+}
@@ -228,9 +228,9 @@ fn color_text_ui(ui: &mut Ui, color: impl Into<Color32>, alpha: Alpha) {
 
         if ui.button("📋").on_hover_text("Click to copy").clicked() {
             if alpha == Alpha::Opaque {
-                ui.output().copied_text = format!("{}, {}, {}", r, g, b);
+                ui.ctx().copy_text(format!("{}, {}, {}", r, g, b));
             } else {
-                ui.output().copied_text = format!("{}, {}, {}, {}", r, g, b, a);
+                ui.ctx().copy_text(format!("{}, {}, {}, {}", r, g, b, a));
             }
         }
 
@@ -347,7 +347,7 @@ pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Res
         button_response = button_response.on_hover_text("Click to edit color");
     }
 
-    if button_response.clicked() {
+    if button_response.clicked() && !ui.is_read_only() {
         ui.memory().toggle_popup(popup_id);
     }
     // TODO(emilk): make it easier to show a temporary popup that closes when you click outside it
@@ -159,12 +159,13 @@ impl<'a> Widget for DragValue<'a> {
             max_decimals,
         } = self;
 
+        let read_only = ui.is_read_only();
         let shift = ui.input().modifiers.shift_only();
         let is_slow_speed = shift && ui.memory().is_being_dragged(ui.next_auto_id());
 
         let old_value = get(&mut get_set_value);
         let value = clamp_to_range(old_value, clamp_range.clone());
-        if old_value != value {
+        if old_value != value && !read_only {
             set(&mut get_set_value, value);
         }
         let aim_rad = ui.input().aim_radius() as f64;
@@ -197,9 +198,11 @@ impl<'a> Widget for DragValue<'a> {
                     .desired_width(button_width)
                     .font(TextStyle::Monospace),
             );
-            if let Ok(parsed_value) = value_text.parse() {
-                let parsed_value = clamp_to_range(parsed_value, clamp_range);
-                set(&mut get_set_value, parsed_value);
+            if !read_only {
+                if let Ok(parsed_value) = value_text.parse() {
+                    let parsed_value = clamp_to_range(parsed_value, clamp_range);
+                    set(&mut get_set_value, parsed_value);
+                }
             }
             if ui.input().key_pressed(Key::Enter) {
                 ui.memory().surrender_focus(kb_edit_id);
@@ -217,7 +220,7 @@ impl<'a> Widget for DragValue<'a> {
             .min_size(ui.spacing().interact_size); // TODO(emilk): find some more generic solution to `min_size`
 
             let response = ui.add(button);
-            let mut response = response.on_hover_cursor(CursorIcon::ResizeHorizontal);
+            let mut response = response.on_hover_and_drag_cursor(CursorIcon::ResizeHorizontal);
 
             if ui.style().explanation_tooltips {
                 response = response .on_hover_text(format!(
@@ -228,13 +231,14 @@ impl<'a> Widget for DragValue<'a> {
                 ));
             }
 
-            if response.clicked() {
+            if read_only {
+                // Show the value, but don't let the user change it
+            } else if response.clicked() {
                 ui.memory().request_focus(kb_edit_id);
                 ui.memory().drag_value.edit_string = None; // Filled in next frame
             } else if response.dragged() {
-                ui.output().cursor_icon = CursorIcon::ResizeHorizontal;
-
-                let mdelta = response.drag_delta();
+                // Not `response.drag_delta()`, since shift means slow speed here, not axis lock:
+                let mdelta = ui.input().pointer.delta();
                 let delta_points = mdelta.x - mdelta.y; // Increase to the right and up
 
                 let speed = if is_slow_speed { speed / 10.0 } else { speed };
@@ -249,7 +253,7 @@ impl<'a> Widget for DragValue<'a> {
                         .then(|| drag_state.last_dragged_value)
                         .flatten();
                     let stored_value = stored_value.unwrap_or(value);
-                    let stored_value = stored_value + delta_value as f64;
+                    let stored_value = stored_value + delta_value;
 
                     let aim_delta = aim_rad * speed;
                     let rounded_new_value = emath::smart_aim::best_in_range_f64(
@@ -266,6 +270,7 @@ impl<'a> Widget for DragValue<'a> {
                     ui.memory().drag_value = drag_state;
                 }
             } else if response.has_focus() {
+                ui.memory().capture_arrow_keys(response.id, true, true);
                 let change = ui.input().num_presses(Key::ArrowUp) as f64
                     + ui.input().num_presses(Key::ArrowRight) as f64
                     - ui.input().num_presses(Key::ArrowDown) as f64
@@ -295,3 +300,28 @@ fn clamp_to_range(x: f64, range: RangeInclusive<f64>) -> f64 {
         range.start().max(*range.end()),
     )
 }
+
+#[cfg(test)]
+#[test]
+fn read_only_keyboard_edit() {
+    let mut harness = Harness::new();
+    let mut value = 0.0;
+
+    let rect = harness.run_ui(|ui| ui.add(DragValue::new(&mut value)).rect);
+    harness.click(rect.center());
+    harness.run_ui(|ui| ui.add(DragValue::new(&mut value)));
+    harness.type_text("5");
+    harness.run_ui(|ui| ui.add(DragValue::new(&mut value)));
+    assert_eq!(value, 5.0);
+
+    // Still editing the text "05", but the value is changed elsewhere:
+    value = 7.0;
+    harness.run_ui(|ui| {
+        ui.set_read_only(true);
+        ui.add(DragValue::new(&mut value))
+    });
+    assert_eq!(
+        value, 7.0,
+        "A read-only DragValue must not change its value"
+    );
+}
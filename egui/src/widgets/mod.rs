@@ -6,6 +6,7 @@
 
 use crate::*;
 
+mod angle_knob;
 mod button;
 pub mod color_picker;
 pub(crate) mod drag_value;
@@ -20,6 +21,7 @@ mod slider;
 mod spinner;
 pub mod text_edit;
 
+pub use angle_knob::AngleKnob;
 pub use button::*;
 pub use drag_value::DragValue;
 pub use hyperlink::*;
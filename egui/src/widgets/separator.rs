@@ -9,12 +9,16 @@ use crate::*;
 /// // These are equivalent:
 /// ui.separator();
 /// ui.add(egui::Separator::default());
+///
+/// // A line with a label in the middle:
+/// ui.add(egui::Separator::default().text("or"));
 /// # });
 /// ```
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
 pub struct Separator {
     spacing: f32,
     is_horizontal_line: Option<bool>,
+    text: WidgetText,
 }
 
 impl Default for Separator {
@@ -22,6 +26,7 @@ impl Default for Separator {
         Self {
             spacing: 6.0,
             is_horizontal_line: None,
+            text: Default::default(),
         }
     }
 }
@@ -48,6 +53,14 @@ impl Separator {
         self.is_horizontal_line = Some(false);
         self
     }
+
+    /// Show some text in the middle of the line, e.g. `"or"`.
+    ///
+    /// The line is broken where the text is. The text is never wrapped.
+    pub fn text(mut self, text: impl Into<WidgetText>) -> Self {
+        self.text = text.into();
+        self
+    }
 }
 
 impl Widget for Separator {
@@ -55,24 +68,61 @@ impl Widget for Separator {
         let Separator {
             spacing,
             is_horizontal_line,
+            text,
         } = self;
 
         let is_horizontal_line = is_horizontal_line
             .unwrap_or_else(|| ui.is_grid() || !ui.layout().main_dir().is_horizontal());
 
+        let galley = (!text.is_empty())
+            .then(|| text.into_galley(ui, Some(false), f32::INFINITY, TextStyle::Body));
+        let text_size = galley.as_ref().map_or(Vec2::ZERO, |galley| galley.size());
+
         let available_space = ui.available_size_before_wrap();
 
         let size = if is_horizontal_line {
-            vec2(available_space.x, spacing)
+            vec2(available_space.x, spacing.max(text_size.y))
         } else {
-            vec2(spacing, available_space.y)
+            vec2(spacing.max(text_size.x), available_space.y)
         };
 
         let (rect, response) = ui.allocate_at_least(size, Sense::hover());
 
         if ui.is_rect_visible(response.rect) {
             let stroke = ui.visuals().widgets.noninteractive.bg_stroke;
-            if is_horizontal_line {
+            if let Some(galley) = galley {
+                // Leave a gap around the text:
+                let text_rect = Rect::from_center_size(rect.center(), text_size);
+                let gap = ui.spacing().item_spacing;
+                if is_horizontal_line {
+                    let y = rect.center().y;
+                    for (left, right) in [
+                        (rect.left(), text_rect.left() - gap.x),
+                        (text_rect.right() + gap.x, rect.right()),
+                    ] {
+                        // No room for the line if the text is wider than the separator:
+                        if left < right {
+                            ui.painter().hline(left..=right, y, stroke);
+                        }
+                    }
+                } else {
+                    let x = rect.center().x;
+                    for (top, bottom) in [
+                        (rect.top(), text_rect.top() - gap.y),
+                        (text_rect.bottom() + gap.y, rect.bottom()),
+                    ] {
+                        // No room for the line if the text is taller than the separator:
+                        if top < bottom {
+                            ui.painter().vline(x, top..=bottom, stroke);
+                        }
+                    }
+                }
+                galley.paint_with_fallback_color(
+                    ui.painter(),
+                    text_rect.min,
+                    ui.visuals().weak_text_color(),
+                );
+            } else if is_horizontal_line {
                 ui.painter().hline(rect.x_range(), rect.center().y, stroke);
             } else {
                 ui.painter().vline(rect.center().x, rect.y_range(), stroke);
@@ -82,3 +132,48 @@ impl Widget for Separator {
         response
     }
 }
+
+#[cfg(test)]
+#[test]
+fn separator_with_text() {
+    let mut harness = Harness::new();
+    let count_lines = |harness: &Harness| {
+        harness
+            .output()
+            .shapes
+            .iter()
+            .filter(|clipped| matches!(clipped.1, Shape::LineSegment { .. }))
+            .count()
+    };
+
+    let rect = harness.run(|ctx| {
+        Area::new("area")
+            .show(ctx, |ui| {
+                ui.set_width(200.0);
+                ui.separator().rect
+            })
+            .inner
+    });
+    assert_eq!(count_lines(&harness), 1);
+    assert_eq!(rect.height(), 6.0);
+
+    let rect = harness.run(|ctx| {
+        Area::new("area")
+            .show(ctx, |ui| {
+                ui.set_width(200.0);
+                ui.add(Separator::default().text("or")).rect
+            })
+            .inner
+    });
+    assert_eq!(count_lines(&harness), 2, "The line is broken by the text");
+    assert!(rect.height() > 6.0);
+    assert_eq!(rect.width(), 200.0);
+
+    harness.run(|ctx| {
+        Area::new("area").show(ctx, |ui| {
+            ui.set_width(20.0);
+            ui.add(Separator::default().text("Much wider than the separator"));
+        });
+    });
+    assert_eq!(count_lines(&harness), 0, "No room for any line");
+}
@@ -312,8 +312,9 @@ impl<'a> Slider<'a> {
     fn slider_ui(&mut self, ui: &mut Ui, response: &Response) {
         let rect = &response.rect;
         let position_range = self.position_range(rect);
+        let read_only = ui.is_read_only();
 
-        if let Some(pointer_position_2d) = response.interact_pointer_pos() {
+        if let Some(pointer_position_2d) = response.interact_pointer_pos().filter(|_| !read_only) {
             let position = self.pointer_position(pointer_position_2d);
             let new_value = if self.smart_aim {
                 let aim_radius = ui.input().aim_radius();
@@ -330,13 +331,16 @@ impl<'a> Slider<'a> {
         let value = self.get_value();
         response.widget_info(|| WidgetInfo::slider(value, &self.text));
 
-        if response.has_focus() {
+        if response.has_focus() && !read_only {
             let (dec_key, inc_key) = match self.orientation {
                 SliderOrientation::Horizontal => (Key::ArrowLeft, Key::ArrowRight),
                 // Note that this is for moving the slider position,
                 // so up = decrement y coordinate:
                 SliderOrientation::Vertical => (Key::ArrowUp, Key::ArrowDown),
             };
+            let horizontal = matches!(self.orientation, SliderOrientation::Horizontal);
+            ui.memory()
+                .capture_arrow_keys(response.id, horizontal, !horizontal);
 
             let decrement = ui.input().num_presses(dec_key);
             let increment = ui.input().num_presses(inc_key);
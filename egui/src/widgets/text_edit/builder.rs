@@ -353,6 +353,15 @@ impl<'t> TextEdit<'t> {
             cursor_at_end,
         } = self;
 
+        // In a read-only `Ui` we still allow selecting and copying the text, just not editing it:
+        let mut read_only_text: &str;
+        let text: &mut dyn TextBuffer = if ui.is_read_only() {
+            read_only_text = text.as_str();
+            &mut read_only_text
+        } else {
+            text
+        };
+
         let text_color = text_color
             .or(ui.visuals().override_text_color)
             // .unwrap_or_else(|| ui.style().interact(&response).text_color()); // too bright
@@ -499,6 +508,7 @@ impl<'t> TextEdit<'t> {
         let prev_cursor_range = state.cursor_range(&*galley);
         if ui.memory().has_focus(id) && interactive {
             ui.memory().lock_focus(id, lock_focus);
+            ui.memory().capture_arrow_keys(id, true, true);
 
             let default_cursor_range = if cursor_at_end {
                 CursorRange::one(galley.end())
@@ -519,7 +529,7 @@ impl<'t> TextEdit<'t> {
                 default_cursor_range,
             );
 
-            if changed {
+            if changed && text.is_mutable() {
                 response.mark_changed();
             }
             cursor_range = Some(new_cursor_range);
@@ -597,7 +607,7 @@ impl<'t> TextEdit<'t> {
                         if interactive {
                             // eframe web uses `text_cursor_pos` when showing IME,
                             // so only set it when text is editable and visible!
-                            ui.ctx().output().text_cursor_pos = Some(cursor_pos.left_top());
+                            ui.ctx().set_text_cursor(id, cursor_pos.left_top());
                         }
                     }
                 }
@@ -687,7 +697,7 @@ fn events(
 
     let copy_if_not_password = |ui: &Ui, text: String| {
         if !password {
-            ui.ctx().output().copied_text = text;
+            ui.ctx().copy_text(text);
         }
     };
 
@@ -770,7 +780,6 @@ fn events(
                 pressed: true,
                 modifiers,
             } if modifiers.command && !modifiers.shift => {
-                // TODO(emilk): redo
                 if let Some((undo_ccursor_range, undo_txt)) = state
                     .undoer
                     .lock()
@@ -782,6 +791,25 @@ fn events(
                     None
                 }
             }
+            Event::Key {
+                key,
+                pressed: true,
+                modifiers,
+            } if modifiers.command
+                && ((*key == Key::Z && modifiers.shift)
+                    || (*key == Key::Y && !modifiers.shift)) =>
+            {
+                if let Some((redo_ccursor_range, redo_txt)) = state
+                    .undoer
+                    .lock()
+                    .redo(&(cursor_range.as_ccursor_range(), text.as_ref().to_owned()))
+                {
+                    text.replace(redo_txt);
+                    Some(*redo_ccursor_range)
+                } else {
+                    None
+                }
+            }
 
             Event::Key {
                 key,
@@ -231,6 +231,7 @@ struct EguiWindows {
     // egui stuff:
     settings: bool,
     inspection: bool,
+    performance: bool,
     memory: bool,
     output_events: bool,
 
@@ -249,6 +250,7 @@ impl EguiWindows {
         Self {
             settings: false,
             inspection: false,
+            performance: false,
             memory: false,
             output_events: false,
             output_event_history: Default::default(),
@@ -259,6 +261,7 @@ impl EguiWindows {
         let Self {
             settings,
             inspection,
+            performance,
             memory,
             output_events,
             output_event_history: _,
@@ -266,6 +269,7 @@ impl EguiWindows {
 
         ui.checkbox(settings, "🔧 Settings");
         ui.checkbox(inspection, "🔍 Inspection");
+        ui.checkbox(performance, "⏱ Performance");
         ui.checkbox(memory, "📝 Memory");
         ui.checkbox(output_events, "📤 Output Events");
     }
@@ -274,6 +278,7 @@ impl EguiWindows {
         let Self {
             settings,
             inspection,
+            performance,
             memory,
             output_events,
             output_event_history,
@@ -300,6 +305,13 @@ impl EguiWindows {
                 ctx.inspection_ui(ui);
             });
 
+        egui::Window::new("⏱ Performance")
+            .open(performance)
+            .resizable(false)
+            .show(ctx, |ui| {
+                ctx.frame_timings_ui(ui);
+            });
+
         egui::Window::new("📝 Memory")
             .open(memory)
             .resizable(false)
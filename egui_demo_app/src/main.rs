@@ -3,6 +3,7 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")] // hide console window on Windows in release
 
 // When compiling natively:
+#[cfg(not(target_arch = "wasm32"))]
 fn main() {
     // Log to stdout (if you run with `RUST_LOG=debug`).
     tracing_subscriber::fmt::init();
@@ -21,3 +22,9 @@ fn main() {
         Box::new(|cc| Box::new(egui_demo_app::WrapApp::new(cc))),
     );
 }
+
+// When compiling to web using `trunk serve` (see `index.html`):
+#[cfg(target_arch = "wasm32")]
+fn main() {
+    egui_demo_app::start("the_canvas_id").expect("Failed to start eframe");
+}
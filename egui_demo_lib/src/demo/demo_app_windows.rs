@@ -301,8 +301,27 @@ impl DemoWindows {
 // ----------------------------------------------------------------------------
 
 fn file_menu_button(ui: &mut Ui) {
+    let organize_shortcut = egui::KeyboardShortcut::new(
+        egui::Modifiers::COMMAND | egui::Modifiers::SHIFT,
+        egui::Key::O,
+    );
+
+    // NOTE: we must check the shortcut outside of the menu,
+    // or else it would only be checked while the menu is open.
+    ui.ctx()
+        .register_shortcut("Windows", organize_shortcut, "Organize windows");
+    if ui.input_mut().consume_shortcut(&organize_shortcut) {
+        ui.ctx().memory().reset_areas();
+    }
+
     ui.menu_button("File", |ui| {
-        if ui.button("Organize windows").clicked() {
+        if ui
+            .add(
+                egui::Button::new("Organize windows")
+                    .shortcut_text(organize_shortcut.format(cfg!(target_os = "macos"))),
+            )
+            .clicked()
+        {
             ui.ctx().memory().reset_areas();
             ui.close_menu();
         }
@@ -189,7 +189,21 @@ impl WidgetGallery {
         ui.end_row();
 
         ui.add(doc_link_label("DragValue", "DragValue"));
-        ui.add(egui::DragValue::new(scalar).speed(1.0));
+        ui.horizontal(|ui| {
+            ui.add(egui::DragValue::new(scalar).speed(1.0));
+            ui.help_marker("Drag to change the value, or click to type in a new one.");
+        });
+        ui.end_row();
+
+        ui.add(doc_link_label("AngleKnob", "AngleKnob"));
+        let mut radians = scalar.to_radians();
+        if ui
+            .add(egui::AngleKnob::new(&mut radians))
+            .on_hover_text("Drag around the center. Hold shift to snap.")
+            .changed()
+        {
+            *scalar = radians.to_degrees().rem_euclid(360.0);
+        }
         ui.end_row();
 
         ui.add(doc_link_label("ProgressBar", "ProgressBar"));
@@ -128,7 +128,7 @@ impl<'a> TableBuilder<'a> {
     fn available_width(&self) -> f32 {
         self.ui.available_rect_before_wrap().width()
             - if self.scroll {
-                self.ui.spacing().item_spacing.x + self.ui.spacing().scroll_bar_width
+                self.ui.spacing().scroll_bar_inner_margin + self.ui.spacing().scroll_bar_width
             } else {
                 0.0
             }
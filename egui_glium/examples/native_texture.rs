@@ -24,7 +24,7 @@ fn main() {
         let mut redraw = || {
             let mut quit = false;
 
-            let needs_repaint = egui_glium.run(&display, |egui_ctx| {
+            let repaint_after = egui_glium.run(&display, |egui_ctx| {
                 egui::SidePanel::left("my_side_panel").show(egui_ctx, |ui| {
                     if ui
                         .add(egui::Button::image_and_text(
@@ -44,9 +44,12 @@ fn main() {
 
             *control_flow = if quit {
                 glutin::event_loop::ControlFlow::Exit
-            } else if needs_repaint {
+            } else if repaint_after.is_zero() {
                 display.gl_window().window().request_redraw();
                 glutin::event_loop::ControlFlow::Poll
+            } else if let Some(repaint_at) = std::time::Instant::now().checked_add(repaint_after) {
+                // `repaint_after` is `Duration::MAX` (which we can't add) if no repaint was requested.
+                glutin::event_loop::ControlFlow::WaitUntil(repaint_at)
             } else {
                 glutin::event_loop::ControlFlow::Wait
             };
@@ -74,6 +77,9 @@ fn main() {
             // See: https://github.com/rust-windowing/winit/issues/1619
             glutin::event::Event::RedrawEventsCleared if cfg!(windows) => redraw(),
             glutin::event::Event::RedrawRequested(_) if !cfg!(windows) => redraw(),
+            glutin::event::Event::NewEvents(glutin::event::StartCause::ResumeTimeReached {
+                ..
+            }) => display.gl_window().window().request_redraw(),
 
             glutin::event::Event::WindowEvent { event, .. } => {
                 use glutin::event::WindowEvent;
@@ -57,20 +57,33 @@ impl EguiGlium {
         self.egui_winit.on_event(&self.egui_ctx, event)
     }
 
-    /// Returns `true` if egui requests a repaint.
+    /// Returns how long until egui wants to be repainted, even if there are no new events.
+    ///
+    /// This is [`std::time::Duration::ZERO`] if egui wants a repaint right away,
+    /// and [`std::time::Duration::MAX`] if it can wait for the next event,
+    /// see [`egui::FullOutput::repaint_after`].
     ///
     /// Call [`Self::paint`] later to paint.
-    pub fn run(&mut self, display: &glium::Display, run_ui: impl FnMut(&egui::Context)) -> bool {
+    pub fn run(
+        &mut self,
+        display: &glium::Display,
+        run_ui: impl FnMut(&egui::Context),
+    ) -> std::time::Duration {
         let raw_input = self
             .egui_winit
             .take_egui_input(display.gl_window().window());
         let egui::FullOutput {
             platform_output,
-            needs_repaint,
+            needs_repaint: _, // included in `repaint_after`
+            repaint_after,
             textures_delta,
             shapes,
+            frame_timings: _,
         } = self.egui_ctx.run(raw_input, run_ui);
 
+        if platform_output.screenshot_requested {
+            tracing::warn!("Screenshots are not supported by egui_glium");
+        }
         self.egui_winit.handle_platform_output(
             display.gl_window().window(),
             &self.egui_ctx,
@@ -80,7 +93,7 @@ impl EguiGlium {
         self.shapes = shapes;
         self.textures_delta.append(textures_delta);
 
-        needs_repaint
+        repaint_after
     }
 
     /// Paint the results of the last call to [`Self::run`].
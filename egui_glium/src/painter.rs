@@ -177,31 +177,20 @@ impl Painter {
             // egui outputs mesh in both winding orders:
             let backface_culling = glium::BackfaceCullingMode::CullingDisabled;
 
-            // Transform clip rect to physical pixels:
-            let clip_min_x = pixels_per_point * clip_rect.min.x;
-            let clip_min_y = pixels_per_point * clip_rect.min.y;
-            let clip_max_x = pixels_per_point * clip_rect.max.x;
-            let clip_max_y = pixels_per_point * clip_rect.max.y;
-
-            // Make sure clip rect can fit within a `u32`:
-            let clip_min_x = clip_min_x.clamp(0.0, width_in_pixels as f32);
-            let clip_min_y = clip_min_y.clamp(0.0, height_in_pixels as f32);
-            let clip_max_x = clip_max_x.clamp(clip_min_x, width_in_pixels as f32);
-            let clip_max_y = clip_max_y.clamp(clip_min_y, height_in_pixels as f32);
-
-            let clip_min_x = clip_min_x.round() as u32;
-            let clip_min_y = clip_min_y.round() as u32;
-            let clip_max_x = clip_max_x.round() as u32;
-            let clip_max_y = clip_max_y.round() as u32;
+            let pixel_rect = egui::PixelRect::from_points(
+                *clip_rect,
+                pixels_per_point,
+                [width_in_pixels, height_in_pixels],
+            );
 
             let params = glium::DrawParameters {
                 blend,
                 backface_culling,
                 scissor: Some(glium::Rect {
-                    left: clip_min_x,
-                    bottom: height_in_pixels - clip_max_y,
-                    width: clip_max_x - clip_min_x,
-                    height: clip_max_y - clip_min_y,
+                    left: pixel_rect.min_x,
+                    bottom: height_in_pixels - pixel_rect.max_y,
+                    width: pixel_rect.width(),
+                    height: pixel_rect.height(),
                 }),
                 ..Default::default()
             };
@@ -270,6 +259,7 @@ impl Painter {
         }
     }
 
+    /// Free a texture, be it one managed by egui or a user texture.
     pub fn free_texture(&mut self, tex_id: egui::TextureId) {
         self.textures.remove(&tex_id);
     }
@@ -278,6 +268,24 @@ impl Painter {
         self.textures.get(&texture_id).map(|rc| rc.as_ref())
     }
 
+    /// Upload an image given as unmultiplied RGBA bytes, and return the [`egui::TextureId::User`]
+    /// to use with e.g. [`egui::Ui::image`].
+    ///
+    /// Free it again with [`Self::free_texture`].
+    pub fn register_rgba_texture(
+        &mut self,
+        facade: &dyn glium::backend::Facade,
+        size: [usize; 2],
+        rgba: &[u8],
+    ) -> egui::TextureId {
+        let id = egui::TextureId::User(self.next_native_tex_id);
+        self.next_native_tex_id += 1;
+        let image = egui::ColorImage::from_rgba_unmultiplied(size, rgba);
+        let delta = egui::epaint::ImageDelta::full(image, egui::TextureFilter::Linear);
+        self.set_texture(facade, id, &delta);
+        id
+    }
+
     pub fn register_native_texture(&mut self, native: Rc<SrgbTexture2d>) -> egui::TextureId {
         let id = egui::TextureId::User(self.next_native_tex_id);
         self.next_native_tex_id += 1;
@@ -0,0 +1,93 @@
+//! Example how to use `egui_glow` on top of an existing SDL2 + OpenGL app.
+
+#![cfg_attr(not(debug_assertions), windows_subsystem = "windows")] // hide console window on Windows in release
+#![allow(unsafe_code)]
+
+use egui_glow::sdl2::sdl2;
+
+fn main() {
+    let mut clear_color = [0.1, 0.1, 0.1];
+
+    let sdl = sdl2::init().unwrap();
+    let video = sdl.video().unwrap();
+
+    let gl_attr = video.gl_attr();
+    gl_attr.set_context_profile(sdl2::video::GLProfile::Core);
+    gl_attr.set_context_version(3, 2);
+    gl_attr.set_framebuffer_srgb_compatible(true);
+
+    let window = video
+        .window("egui_glow SDL2 example", 800, 600)
+        .opengl()
+        .resizable()
+        .allow_highdpi()
+        .build()
+        .unwrap();
+    let _gl_context = window.gl_create_context().unwrap();
+    let _ = video.gl_set_swap_interval(sdl2::video::SwapInterval::VSync);
+
+    let gl = unsafe {
+        glow::Context::from_loader_function(|s| video.gl_get_proc_address(s) as *const _)
+    };
+    let gl = std::sync::Arc::new(gl);
+
+    let mut egui_sdl2 = egui_glow::EguiSdl2::new(&window, gl.clone());
+
+    let mut event_pump = sdl.event_pump().unwrap();
+    let mut repaint_after = std::time::Duration::ZERO;
+
+    'running: loop {
+        // Sleep until the next event, or until egui wants to repaint:
+        let first_event = if repaint_after.is_zero() {
+            None
+        } else if repaint_after == std::time::Duration::MAX {
+            Some(event_pump.wait_event())
+        } else {
+            let timeout_ms = repaint_after.as_millis().min(u32::MAX as u128) as u32;
+            event_pump.wait_event_timeout(timeout_ms)
+        };
+
+        for event in first_event.into_iter().chain(event_pump.poll_iter()) {
+            if let sdl2::event::Event::Quit { .. } = event {
+                break 'running;
+            }
+
+            let egui_wants_event = egui_sdl2.on_event(&event);
+            if !egui_wants_event {
+                // Pass on the event to your game here.
+            }
+        }
+
+        let mut quit = false;
+
+        repaint_after = egui_sdl2.run(&window, |egui_ctx| {
+            egui::SidePanel::left("my_side_panel").show(egui_ctx, |ui| {
+                ui.heading("Hello World!");
+                if ui.button("Quit").clicked() {
+                    quit = true;
+                }
+                ui.color_edit_button_rgb(&mut clear_color);
+            });
+        });
+
+        if quit {
+            break;
+        }
+
+        unsafe {
+            use glow::HasContext as _;
+            gl.clear_color(clear_color[0], clear_color[1], clear_color[2], 1.0);
+            gl.clear(glow::COLOR_BUFFER_BIT);
+        }
+
+        // draw things behind egui here
+
+        egui_sdl2.paint(&window);
+
+        // draw things on top of egui here
+
+        window.gl_swap_window();
+    }
+
+    egui_sdl2.destroy();
+}
@@ -24,6 +24,11 @@ pub mod winit;
 #[cfg(all(not(target_arch = "wasm32"), feature = "winit"))]
 pub use winit::*;
 
+#[cfg(all(not(target_arch = "wasm32"), feature = "sdl2"))]
+pub mod sdl2;
+#[cfg(all(not(target_arch = "wasm32"), feature = "sdl2"))]
+pub use self::sdl2::EguiSdl2;
+
 /// Check for OpenGL error and report it using `tracing::error`.
 ///
 /// Only active in debug builds!
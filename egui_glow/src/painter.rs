@@ -247,6 +247,38 @@ impl Painter {
         self.max_texture_side
     }
 
+    /// Read back what has been painted to the screen, e.g. to answer [`egui::Context::request_screenshot`].
+    ///
+    /// Call this after [`Self::paint_and_update_textures`], but before swapping buffers.
+    pub fn read_screen_rgba(&self, screen_size_in_pixels: [u32; 2]) -> egui::ColorImage {
+        crate::profile_function!();
+        let [width, height] = screen_size_in_pixels;
+        let mut pixels = vec![0_u8; width as usize * height as usize * 4];
+        unsafe {
+            self.gl.read_pixels(
+                0,
+                0,
+                width as i32,
+                height as i32,
+                glow::RGBA,
+                glow::UNSIGNED_BYTE,
+                glow::PixelPackData::Slice(&mut pixels),
+            );
+        }
+
+        // OpenGL reads the rows from the bottom up:
+        let pixels = pixels
+            .chunks_exact(width as usize * 4)
+            .rev()
+            .flat_map(|row| row.chunks_exact(4))
+            .map(|p| Color32::from_rgba_premultiplied(p[0], p[1], p[2], p[3]))
+            .collect();
+        egui::ColorImage {
+            size: [width as usize, height as usize],
+            pixels,
+        }
+    }
+
     unsafe fn prepare_painting(
         &mut self,
         [width_in_pixels, height_in_pixels]: [u32; 2],
@@ -374,25 +406,6 @@ impl Painter {
                 Primitive::Callback(callback) => {
                     if callback.rect.is_positive() {
                         crate::profile_scope!("callback");
-                        // Transform callback rect to physical pixels:
-                        let rect_min_x = pixels_per_point * callback.rect.min.x;
-                        let rect_min_y = pixels_per_point * callback.rect.min.y;
-                        let rect_max_x = pixels_per_point * callback.rect.max.x;
-                        let rect_max_y = pixels_per_point * callback.rect.max.y;
-
-                        let rect_min_x = rect_min_x.round() as i32;
-                        let rect_min_y = rect_min_y.round() as i32;
-                        let rect_max_x = rect_max_x.round() as i32;
-                        let rect_max_y = rect_max_y.round() as i32;
-
-                        unsafe {
-                            self.gl.viewport(
-                                rect_min_x,
-                                size_in_pixels.1 as i32 - rect_max_y,
-                                rect_max_x - rect_min_x,
-                                rect_max_y - rect_min_y,
-                            );
-                        }
 
                         let info = egui::PaintCallbackInfo {
                             viewport: callback.rect,
@@ -401,6 +414,16 @@ impl Painter {
                             screen_size_px,
                         };
 
+                        let viewport_px = info.viewport_in_pixels();
+                        unsafe {
+                            self.gl.viewport(
+                                viewport_px.left_px.round() as i32,
+                                viewport_px.from_bottom_px.round() as i32,
+                                viewport_px.width_px.round() as i32,
+                                viewport_px.height_px.round() as i32,
+                            );
+                        }
+
                         if let Some(callback) = callback.callback.downcast_ref::<CallbackFn>() {
                             (callback.f)(info, self);
                         } else {
@@ -718,29 +741,18 @@ fn set_clip_rect(
     pixels_per_point: f32,
     clip_rect: Rect,
 ) {
-    // Transform clip rect to physical pixels:
-    let clip_min_x = pixels_per_point * clip_rect.min.x;
-    let clip_min_y = pixels_per_point * clip_rect.min.y;
-    let clip_max_x = pixels_per_point * clip_rect.max.x;
-    let clip_max_y = pixels_per_point * clip_rect.max.y;
-
-    // Make sure clip rect can fit within a `u32`:
-    let clip_min_x = clip_min_x.clamp(0.0, size_in_pixels.0 as f32);
-    let clip_min_y = clip_min_y.clamp(0.0, size_in_pixels.1 as f32);
-    let clip_max_x = clip_max_x.clamp(clip_min_x, size_in_pixels.0 as f32);
-    let clip_max_y = clip_max_y.clamp(clip_min_y, size_in_pixels.1 as f32);
-
-    let clip_min_x = clip_min_x.round() as i32;
-    let clip_min_y = clip_min_y.round() as i32;
-    let clip_max_x = clip_max_x.round() as i32;
-    let clip_max_y = clip_max_y.round() as i32;
+    let pixel_rect = egui::PixelRect::from_points(
+        clip_rect,
+        pixels_per_point,
+        [size_in_pixels.0, size_in_pixels.1],
+    );
 
     unsafe {
         gl.scissor(
-            clip_min_x,
-            size_in_pixels.1 as i32 - clip_max_y,
-            clip_max_x - clip_min_x,
-            clip_max_y - clip_min_y,
+            pixel_rect.min_x as i32,
+            (size_in_pixels.1 - pixel_rect.max_y) as i32,
+            pixel_rect.width() as i32,
+            pixel_rect.height() as i32,
         );
     }
 }
@@ -0,0 +1,658 @@
+//! Use [`egui`] from a [`glow`] app based on [SDL2](https://docs.rs/sdl2).
+//!
+//! [`State`] translates SDL2 events to [`egui::RawInput`] and handles the [`egui::PlatformOutput`],
+//! while [`EguiSdl2`] also paints egui with a [`crate::Painter`].
+
+pub use ::sdl2;
+use ::sdl2::{
+    event::{Event, WindowEvent},
+    keyboard::{Keycode, Mod},
+    mouse::{Cursor, MouseButton, MouseWheelDirection, SystemCursor},
+    video::Window,
+    VideoSubsystem,
+};
+
+/// How many physical pixels there are per SDL2 window coordinate.
+///
+/// This is larger than one on high-DPI displays, if the window was created with `allow_highdpi`.
+pub fn native_pixels_per_point(window: &Window) -> f32 {
+    let (width, _) = window.size();
+    let (drawable_width, _) = window.drawable_size();
+    if width == 0 {
+        1.0
+    } else {
+        drawable_width as f32 / width as f32
+    }
+}
+
+pub fn screen_size_in_pixels(window: &Window) -> egui::Vec2 {
+    let (width, height) = window.drawable_size();
+    egui::vec2(width as f32, height as f32)
+}
+
+/// Handles the integration between egui and SDL2.
+pub struct State {
+    start_time: std::time::Instant,
+    egui_input: egui::RawInput,
+    pointer_pos_in_points: Option<egui::Pos2>,
+    current_cursor_icon: egui::CursorIcon,
+    /// SDL2 only changes the cursor as long as this is alive.
+    cursor: Option<Cursor>,
+    /// Physical pixels per SDL2 window coordinate.
+    native_pixels_per_point: f32,
+    /// What egui uses.
+    current_pixels_per_point: f32,
+
+    video: VideoSubsystem,
+}
+
+impl State {
+    pub fn new(window: &Window) -> Self {
+        let native_pixels_per_point = native_pixels_per_point(window);
+        Self {
+            start_time: std::time::Instant::now(),
+            egui_input: egui::RawInput {
+                pixels_per_point: Some(native_pixels_per_point),
+                ..Default::default()
+            },
+            pointer_pos_in_points: None,
+            current_cursor_icon: egui::CursorIcon::Default,
+            cursor: None,
+            native_pixels_per_point,
+            current_pixels_per_point: native_pixels_per_point,
+
+            video: window.subsystem().clone(),
+        }
+    }
+
+    /// Call this once a graphics context has been created to update the maximum texture dimensions
+    /// that egui will use.
+    pub fn set_max_texture_side(&mut self, max_texture_side: usize) {
+        self.egui_input.max_texture_side = Some(max_texture_side);
+    }
+
+    /// The number of physical pixels per logical point,
+    /// as configured on the current egui context (see [`egui::Context::pixels_per_point`]).
+    #[inline]
+    pub fn pixels_per_point(&self) -> f32 {
+        self.current_pixels_per_point
+    }
+
+    /// The current input state.
+    /// This is changed by [`Self::on_event`] and cleared by [`Self::take_egui_input`].
+    #[inline]
+    pub fn egui_input(&self) -> &egui::RawInput {
+        &self.egui_input
+    }
+
+    /// The current input state, e.g. for adding events of your own.
+    #[inline]
+    pub fn egui_input_mut(&mut self) -> &mut egui::RawInput {
+        &mut self.egui_input
+    }
+
+    /// Prepare for a new frame by extracting the accumulated input,
+    /// as well as setting [the time](egui::RawInput::time) and [screen rectangle](egui::RawInput::screen_rect).
+    pub fn take_egui_input(&mut self, window: &Window) -> egui::RawInput {
+        // SDL2 has no event for when the window moves to a display with a different scale,
+        // so we check every frame:
+        let native_pixels_per_point = native_pixels_per_point(window);
+        if native_pixels_per_point != self.native_pixels_per_point {
+            self.current_pixels_per_point *= native_pixels_per_point / self.native_pixels_per_point;
+            self.native_pixels_per_point = native_pixels_per_point;
+            self.egui_input.pixels_per_point = Some(native_pixels_per_point);
+        }
+
+        self.egui_input.time = Some(self.start_time.elapsed().as_secs_f64());
+
+        // A minimized window can have 0 width and height.
+        let screen_size_in_points = screen_size_in_pixels(window) / self.pixels_per_point();
+        self.egui_input.screen_rect =
+            if screen_size_in_points.x > 0.0 && screen_size_in_points.y > 0.0 {
+                Some(egui::Rect::from_min_size(
+                    egui::Pos2::ZERO,
+                    screen_size_in_points,
+                ))
+            } else {
+                None
+            };
+
+        self.egui_input.take()
+    }
+
+    /// Call this when there is a new event.
+    ///
+    /// The result can be found in [`Self::egui_input`] and be extracted with [`Self::take_egui_input`].
+    ///
+    /// Returns `true` if egui wants exclusive use of this event
+    /// (e.g. a mouse click on an egui window, or entering text into a text field).
+    /// For instance, if you use egui for a game, you want to first call this
+    /// and only when this returns `false` pass on the events to your game.
+    ///
+    /// Note that egui uses `tab` to move focus between elements, so this will always return `true` for tabs.
+    pub fn on_event(&mut self, egui_ctx: &egui::Context, event: &Event) -> bool {
+        let wants_event = match event {
+            Event::MouseButtonDown {
+                mouse_btn, x, y, ..
+            } => {
+                self.on_cursor_moved(*x, *y);
+                self.on_mouse_button_input(*mouse_btn, true);
+                egui_ctx.wants_pointer_input()
+            }
+            Event::MouseButtonUp {
+                mouse_btn, x, y, ..
+            } => {
+                self.on_cursor_moved(*x, *y);
+                self.on_mouse_button_input(*mouse_btn, false);
+                egui_ctx.wants_pointer_input()
+            }
+            Event::MouseWheel {
+                x, y, direction, ..
+            } => {
+                self.on_mouse_wheel(*x, *y, *direction);
+                egui_ctx.wants_pointer_input()
+            }
+            Event::MouseMotion { x, y, .. } => {
+                self.on_cursor_moved(*x, *y);
+                egui_ctx.is_using_pointer()
+            }
+            Event::TextInput { text, .. } if text.chars().all(is_printable_char) => {
+                self.egui_input.events.push(egui::Event::Text(text.clone()));
+                egui_ctx.wants_keyboard_input()
+            }
+            Event::KeyDown {
+                keycode, keymod, ..
+            } => {
+                self.on_keyboard_input(*keycode, *keymod, true);
+                egui_ctx.wants_keyboard_input() || *keycode == Some(Keycode::Tab)
+            }
+            Event::KeyUp {
+                keycode, keymod, ..
+            } => {
+                self.on_keyboard_input(*keycode, *keymod, false);
+                egui_ctx.wants_keyboard_input() || *keycode == Some(Keycode::Tab)
+            }
+            Event::Window { win_event, .. } => {
+                match win_event {
+                    WindowEvent::Leave => {
+                        self.pointer_pos_in_points = None;
+                        self.egui_input.events.push(egui::Event::PointerGone);
+                    }
+                    WindowEvent::FocusGained | WindowEvent::FocusLost => {
+                        // We will not be given a KeyUp event when the modifiers are released while
+                        // the window does not have focus. Unset all modifier state to be safe.
+                        self.egui_input.modifiers = egui::Modifiers::default();
+                    }
+                    _ => {}
+                }
+                false
+            }
+            Event::DropFile { filename, .. } => {
+                self.egui_input.dropped_files.push(egui::DroppedFile {
+                    path: Some(filename.into()),
+                    ..Default::default()
+                });
+                false
+            }
+            _ => false,
+        };
+
+        if self.egui_input.oldest_event_time.is_none() && !self.egui_input.events.is_empty() {
+            self.egui_input.oldest_event_time = Some(self.start_time.elapsed().as_secs_f64());
+        }
+
+        wants_event
+    }
+
+    /// Converts SDL2 window coordinates to egui points.
+    fn to_points(&self, x: i32, y: i32) -> egui::Pos2 {
+        let points_per_window_unit = self.native_pixels_per_point / self.pixels_per_point();
+        egui::pos2(
+            x as f32 * points_per_window_unit,
+            y as f32 * points_per_window_unit,
+        )
+    }
+
+    fn on_mouse_button_input(&mut self, button: MouseButton, pressed: bool) {
+        if let Some(pos) = self.pointer_pos_in_points {
+            if let Some(button) = translate_mouse_button(button) {
+                self.egui_input.events.push(egui::Event::PointerButton {
+                    pos,
+                    button,
+                    pressed,
+                    modifiers: self.egui_input.modifiers,
+                });
+            }
+        }
+    }
+
+    fn on_cursor_moved(&mut self, x: i32, y: i32) {
+        let pos_in_points = self.to_points(x, y);
+        if self.pointer_pos_in_points != Some(pos_in_points) {
+            self.pointer_pos_in_points = Some(pos_in_points);
+            self.egui_input
+                .events
+                .push(egui::Event::PointerMoved(pos_in_points));
+        }
+    }
+
+    fn on_mouse_wheel(&mut self, x: i32, y: i32, direction: MouseWheelDirection) {
+        let points_per_scroll_line = 50.0; // Scroll speed decided by consensus: https://github.com/emilk/egui/issues/461
+        let mut delta = egui::vec2(-x as f32, y as f32) * points_per_scroll_line;
+        if direction == MouseWheelDirection::Flipped {
+            delta = -delta;
+        }
+
+        if self.egui_input.modifiers.ctrl || self.egui_input.modifiers.command {
+            // Treat as zoom instead:
+            let factor = (delta.y / 200.0).exp();
+            self.egui_input.events.push(egui::Event::Zoom(factor));
+        } else if self.egui_input.modifiers.shift {
+            // Treat as horizontal scrolling.
+            self.egui_input
+                .events
+                .push(egui::Event::Scroll(egui::vec2(delta.x + delta.y, 0.0)));
+        } else {
+            self.egui_input.events.push(egui::Event::Scroll(delta));
+        }
+    }
+
+    fn on_keyboard_input(&mut self, keycode: Option<Keycode>, keymod: Mod, pressed: bool) {
+        self.egui_input.modifiers = translate_modifiers(keymod);
+
+        if let Some(keycode) = keycode {
+            if pressed {
+                let modifiers = self.egui_input.modifiers;
+                if is_cut_command(modifiers, keycode) {
+                    self.egui_input.events.push(egui::Event::Cut);
+                } else if is_copy_command(modifiers, keycode) {
+                    self.egui_input.events.push(egui::Event::Copy);
+                } else if is_paste_command(modifiers, keycode) {
+                    match self.video.clipboard().clipboard_text() {
+                        Ok(contents) => {
+                            let contents = contents.replace("\r\n", "\n");
+                            if !contents.is_empty() {
+                                self.egui_input.events.push(egui::Event::Paste(contents));
+                            }
+                        }
+                        Err(err) => {
+                            tracing::error!("Paste error: {}", err);
+                        }
+                    }
+                }
+            }
+
+            if let Some(key) = translate_keycode(keycode) {
+                self.egui_input.events.push(egui::Event::Key {
+                    key,
+                    pressed,
+                    modifiers: self.egui_input.modifiers,
+                });
+            }
+        }
+    }
+
+    /// Call with the output given by `egui`.
+    ///
+    /// This will, if needed:
+    /// * update the cursor
+    /// * copy text to the clipboard
+    /// * open any clicked urls
+    /// * update the IME
+    pub fn handle_platform_output(
+        &mut self,
+        egui_ctx: &egui::Context,
+        platform_output: egui::PlatformOutput,
+    ) {
+        let egui::PlatformOutput {
+            cursor_icon,
+            open_url,
+            copied_text,
+            events: _,
+            accessibility_nodes: _,
+            mutable_text_under_cursor: _, // only used in eframe web
+            text_cursor_pos,
+            input_latency: _,
+            screenshot_requested: _, // handled by the integration
+        } = platform_output;
+
+        self.current_pixels_per_point = egui_ctx.pixels_per_point(); // someone can have changed it to scale the UI
+
+        self.set_cursor_icon(cursor_icon);
+
+        if let Some(open_url) = open_url {
+            if let Err(err) = ::sdl2::url::open_url(&open_url.url) {
+                tracing::warn!("Failed to open url: {}", err);
+            }
+        }
+
+        if !copied_text.is_empty() {
+            if let Err(err) = self.video.clipboard().set_clipboard_text(&copied_text) {
+                tracing::error!("Copy/Cut error: {}", err);
+            }
+        }
+
+        if let Some(pos) = text_cursor_pos {
+            let window_units_per_point = self.pixels_per_point() / self.native_pixels_per_point;
+            self.video.text_input().set_rect(::sdl2::rect::Rect::new(
+                (pos.x * window_units_per_point) as i32,
+                (pos.y * window_units_per_point) as i32,
+                1,
+                1,
+            ));
+        }
+    }
+
+    fn set_cursor_icon(&mut self, cursor_icon: egui::CursorIcon) {
+        if self.current_cursor_icon == cursor_icon {
+            return;
+        }
+        self.current_cursor_icon = cursor_icon;
+
+        let mouse = self.video.sdl().mouse();
+        if let Some(system_cursor) = translate_cursor(cursor_icon) {
+            match Cursor::from_system(system_cursor) {
+                Ok(cursor) => {
+                    cursor.set();
+                    self.cursor = Some(cursor);
+                }
+                Err(err) => {
+                    tracing::warn!("Failed to set cursor: {}", err);
+                }
+            }
+            mouse.show_cursor(true);
+        } else {
+            mouse.show_cursor(false);
+        }
+    }
+}
+
+/// Use [`egui`] from a [`glow`] app based on [SDL2](https://docs.rs/sdl2).
+pub struct EguiSdl2 {
+    pub egui_ctx: egui::Context,
+    pub egui_sdl2: State,
+    pub painter: crate::Painter,
+
+    shapes: Vec<egui::epaint::ClippedShape>,
+    textures_delta: egui::TexturesDelta,
+    screenshot_requested: bool,
+}
+
+impl EguiSdl2 {
+    /// The `gl` context must belong to `window`.
+    pub fn new(window: &Window, gl: std::sync::Arc<glow::Context>) -> Self {
+        let painter = crate::Painter::new(gl, None, "")
+            .map_err(|error| {
+                tracing::error!("error occurred in initializing painter:\n{}", error);
+            })
+            .unwrap();
+
+        let mut egui_sdl2 = State::new(window);
+        egui_sdl2.set_max_texture_side(painter.max_texture_side());
+
+        Self {
+            egui_ctx: Default::default(),
+            egui_sdl2,
+            painter,
+            shapes: Default::default(),
+            textures_delta: Default::default(),
+            screenshot_requested: false,
+        }
+    }
+
+    /// Returns `true` if egui wants exclusive use of this event
+    /// (e.g. a mouse click on an egui window, or entering text into a text field).
+    /// For instance, if you use egui for a game, you want to first call this
+    /// and only when this returns `false` pass on the events to your game.
+    ///
+    /// Note that egui uses `tab` to move focus between elements, so this will always return `true` for tabs.
+    pub fn on_event(&mut self, event: &Event) -> bool {
+        self.egui_sdl2.on_event(&self.egui_ctx, event)
+    }
+
+    /// Returns how long until egui wants to be repainted, even if there are no new events.
+    ///
+    /// This is [`std::time::Duration::ZERO`] if egui wants a repaint right away,
+    /// and [`std::time::Duration::MAX`] if it can wait for the next event,
+    /// see [`egui::FullOutput::repaint_after`].
+    ///
+    /// Call [`Self::paint`] later to paint.
+    pub fn run(
+        &mut self,
+        window: &Window,
+        run_ui: impl FnMut(&egui::Context),
+    ) -> std::time::Duration {
+        let raw_input = self.egui_sdl2.take_egui_input(window);
+        let egui::FullOutput {
+            platform_output,
+            needs_repaint: _, // included in `repaint_after`
+            repaint_after,
+            textures_delta,
+            shapes,
+            frame_timings: _,
+        } = self.egui_ctx.run(raw_input, run_ui);
+
+        self.screenshot_requested |= platform_output.screenshot_requested;
+        self.egui_sdl2
+            .handle_platform_output(&self.egui_ctx, platform_output);
+
+        self.shapes = shapes;
+        self.textures_delta.append(textures_delta);
+
+        if self.screenshot_requested {
+            std::time::Duration::ZERO // send the screenshot to egui right away
+        } else {
+            repaint_after
+        }
+    }
+
+    /// Paint the results of the last call to [`Self::run`].
+    pub fn paint(&mut self, window: &Window) {
+        let shapes = std::mem::take(&mut self.shapes);
+        let textures_delta = std::mem::take(&mut self.textures_delta);
+        let clipped_primitives = self.egui_ctx.tessellate(shapes);
+        let (width, height) = window.drawable_size();
+        self.painter.paint_and_update_textures(
+            [width, height],
+            self.egui_ctx.pixels_per_point(),
+            &clipped_primitives,
+            &textures_delta,
+        );
+
+        if std::mem::take(&mut self.screenshot_requested) {
+            let screenshot = self.painter.read_screen_rgba([width, height]);
+            self.egui_sdl2
+                .egui_input_mut()
+                .events
+                .push(egui::Event::Screenshot(std::sync::Arc::new(screenshot)));
+        }
+    }
+
+    /// Call to release the allocated graphics resources.
+    pub fn destroy(&mut self) {
+        self.painter.destroy();
+    }
+}
+
+/// SDL2 also sends text input for some special keys.
+/// Ignore those, as they are handled by the [`egui::Event::Key`] events.
+fn is_printable_char(chr: char) -> bool {
+    !chr.is_ascii_control()
+}
+
+fn translate_modifiers(keymod: Mod) -> egui::Modifiers {
+    let alt = keymod.intersects(Mod::LALTMOD | Mod::RALTMOD);
+    let ctrl = keymod.intersects(Mod::LCTRLMOD | Mod::RCTRLMOD);
+    let shift = keymod.intersects(Mod::LSHIFTMOD | Mod::RSHIFTMOD);
+    let logo = keymod.intersects(Mod::LGUIMOD | Mod::RGUIMOD);
+    egui::Modifiers {
+        alt,
+        ctrl,
+        shift,
+        mac_cmd: cfg!(target_os = "macos") && logo,
+        command: if cfg!(target_os = "macos") {
+            logo
+        } else {
+            ctrl
+        },
+    }
+}
+
+fn is_cut_command(modifiers: egui::Modifiers, keycode: Keycode) -> bool {
+    (modifiers.command && keycode == Keycode::X)
+        || (cfg!(target_os = "windows") && modifiers.shift && keycode == Keycode::Delete)
+}
+
+fn is_copy_command(modifiers: egui::Modifiers, keycode: Keycode) -> bool {
+    (modifiers.command && keycode == Keycode::C)
+        || (cfg!(target_os = "windows") && modifiers.ctrl && keycode == Keycode::Insert)
+}
+
+fn is_paste_command(modifiers: egui::Modifiers, keycode: Keycode) -> bool {
+    (modifiers.command && keycode == Keycode::V)
+        || (cfg!(target_os = "windows") && modifiers.shift && keycode == Keycode::Insert)
+}
+
+fn translate_mouse_button(button: MouseButton) -> Option<egui::PointerButton> {
+    match button {
+        MouseButton::Left => Some(egui::PointerButton::Primary),
+        MouseButton::Right => Some(egui::PointerButton::Secondary),
+        MouseButton::Middle => Some(egui::PointerButton::Middle),
+        MouseButton::X1 => Some(egui::PointerButton::Extra1),
+        MouseButton::X2 => Some(egui::PointerButton::Extra2),
+        MouseButton::Unknown => None,
+    }
+}
+
+fn translate_keycode(key: Keycode) -> Option<egui::Key> {
+    use egui::Key;
+
+    Some(match key {
+        Keycode::Down => Key::ArrowDown,
+        Keycode::Left => Key::ArrowLeft,
+        Keycode::Right => Key::ArrowRight,
+        Keycode::Up => Key::ArrowUp,
+
+        Keycode::Escape => Key::Escape,
+        Keycode::Tab => Key::Tab,
+        Keycode::Backspace => Key::Backspace,
+        Keycode::Return | Keycode::KpEnter => Key::Enter,
+        Keycode::Space => Key::Space,
+
+        Keycode::Insert => Key::Insert,
+        Keycode::Delete => Key::Delete,
+        Keycode::Home => Key::Home,
+        Keycode::End => Key::End,
+        Keycode::PageUp => Key::PageUp,
+        Keycode::PageDown => Key::PageDown,
+
+        Keycode::Minus | Keycode::KpMinus => Key::Minus,
+        // Using Mac the key with the Plus sign on it is reported as the Equals key
+        // (with both English and Swedish keyboard).
+        Keycode::Equals | Keycode::Plus | Keycode::KpPlus => Key::PlusEquals,
+
+        Keycode::Num0 | Keycode::Kp0 => Key::Num0,
+        Keycode::Num1 | Keycode::Kp1 => Key::Num1,
+        Keycode::Num2 | Keycode::Kp2 => Key::Num2,
+        Keycode::Num3 | Keycode::Kp3 => Key::Num3,
+        Keycode::Num4 | Keycode::Kp4 => Key::Num4,
+        Keycode::Num5 | Keycode::Kp5 => Key::Num5,
+        Keycode::Num6 | Keycode::Kp6 => Key::Num6,
+        Keycode::Num7 | Keycode::Kp7 => Key::Num7,
+        Keycode::Num8 | Keycode::Kp8 => Key::Num8,
+        Keycode::Num9 | Keycode::Kp9 => Key::Num9,
+
+        Keycode::A => Key::A,
+        Keycode::B => Key::B,
+        Keycode::C => Key::C,
+        Keycode::D => Key::D,
+        Keycode::E => Key::E,
+        Keycode::F => Key::F,
+        Keycode::G => Key::G,
+        Keycode::H => Key::H,
+        Keycode::I => Key::I,
+        Keycode::J => Key::J,
+        Keycode::K => Key::K,
+        Keycode::L => Key::L,
+        Keycode::M => Key::M,
+        Keycode::N => Key::N,
+        Keycode::O => Key::O,
+        Keycode::P => Key::P,
+        Keycode::Q => Key::Q,
+        Keycode::R => Key::R,
+        Keycode::S => Key::S,
+        Keycode::T => Key::T,
+        Keycode::U => Key::U,
+        Keycode::V => Key::V,
+        Keycode::W => Key::W,
+        Keycode::X => Key::X,
+        Keycode::Y => Key::Y,
+        Keycode::Z => Key::Z,
+
+        Keycode::F1 => Key::F1,
+        Keycode::F2 => Key::F2,
+        Keycode::F3 => Key::F3,
+        Keycode::F4 => Key::F4,
+        Keycode::F5 => Key::F5,
+        Keycode::F6 => Key::F6,
+        Keycode::F7 => Key::F7,
+        Keycode::F8 => Key::F8,
+        Keycode::F9 => Key::F9,
+        Keycode::F10 => Key::F10,
+        Keycode::F11 => Key::F11,
+        Keycode::F12 => Key::F12,
+        Keycode::F13 => Key::F13,
+        Keycode::F14 => Key::F14,
+        Keycode::F15 => Key::F15,
+        Keycode::F16 => Key::F16,
+        Keycode::F17 => Key::F17,
+        Keycode::F18 => Key::F18,
+        Keycode::F19 => Key::F19,
+        Keycode::F20 => Key::F20,
+
+        _ => {
+            return None;
+        }
+    })
+}
+
+/// SDL2 only has a few system cursors, so many of egui's cursors fall back to the closest match.
+fn translate_cursor(cursor_icon: egui::CursorIcon) -> Option<SystemCursor> {
+    match cursor_icon {
+        egui::CursorIcon::None => None,
+
+        egui::CursorIcon::Crosshair | egui::CursorIcon::Cell => Some(SystemCursor::Crosshair),
+        egui::CursorIcon::PointingHand => Some(SystemCursor::Hand),
+        egui::CursorIcon::Progress => Some(SystemCursor::WaitArrow),
+        egui::CursorIcon::Wait => Some(SystemCursor::Wait),
+        egui::CursorIcon::Text | egui::CursorIcon::VerticalText => Some(SystemCursor::IBeam),
+        egui::CursorIcon::NoDrop | egui::CursorIcon::NotAllowed => Some(SystemCursor::No),
+        egui::CursorIcon::AllScroll
+        | egui::CursorIcon::Grab
+        | egui::CursorIcon::Grabbing
+        | egui::CursorIcon::Move => Some(SystemCursor::SizeAll),
+
+        egui::CursorIcon::ResizeHorizontal
+        | egui::CursorIcon::ResizeEast
+        | egui::CursorIcon::ResizeWest
+        | egui::CursorIcon::ResizeColumn => Some(SystemCursor::SizeWE),
+        egui::CursorIcon::ResizeVertical
+        | egui::CursorIcon::ResizeNorth
+        | egui::CursorIcon::ResizeSouth
+        | egui::CursorIcon::ResizeRow => Some(SystemCursor::SizeNS),
+        egui::CursorIcon::ResizeNeSw
+        | egui::CursorIcon::ResizeNorthEast
+        | egui::CursorIcon::ResizeSouthWest => Some(SystemCursor::SizeNESW),
+        egui::CursorIcon::ResizeNwSe
+        | egui::CursorIcon::ResizeNorthWest
+        | egui::CursorIcon::ResizeSouthEast => Some(SystemCursor::SizeNWSE),
+
+        egui::CursorIcon::Default
+        | egui::CursorIcon::Alias
+        | egui::CursorIcon::ContextMenu
+        | egui::CursorIcon::Copy
+        | egui::CursorIcon::Help
+        | egui::CursorIcon::ZoomIn
+        | egui::CursorIcon::ZoomOut => Some(SystemCursor::Arrow),
+    }
+}
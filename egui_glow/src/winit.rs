@@ -9,6 +9,7 @@ pub struct EguiGlow {
 
     shapes: Vec<egui::epaint::ClippedShape>,
     textures_delta: egui::TexturesDelta,
+    screenshot_requested: bool,
 }
 
 impl EguiGlow {
@@ -28,6 +29,7 @@ impl EguiGlow {
             painter,
             shapes: Default::default(),
             textures_delta: Default::default(),
+            screenshot_requested: false,
         }
     }
 
@@ -41,28 +43,40 @@ impl EguiGlow {
         self.egui_winit.on_event(&self.egui_ctx, event)
     }
 
-    /// Returns `true` if egui requests a repaint.
+    /// Returns how long until egui wants to be repainted, even if there are no new events.
+    ///
+    /// This is [`std::time::Duration::ZERO`] if egui wants a repaint right away,
+    /// and [`std::time::Duration::MAX`] if it can wait for the next event,
+    /// see [`egui::FullOutput::repaint_after`].
     ///
     /// Call [`Self::paint`] later to paint.
     pub fn run(
         &mut self,
         window: &winit::window::Window,
         run_ui: impl FnMut(&egui::Context),
-    ) -> bool {
+    ) -> std::time::Duration {
         let raw_input = self.egui_winit.take_egui_input(window);
         let egui::FullOutput {
             platform_output,
-            needs_repaint,
+            needs_repaint: _, // included in `repaint_after`
+            repaint_after,
             textures_delta,
             shapes,
+            frame_timings: _,
         } = self.egui_ctx.run(raw_input, run_ui);
 
+        self.screenshot_requested |= platform_output.screenshot_requested;
         self.egui_winit
             .handle_platform_output(window, &self.egui_ctx, platform_output);
 
         self.shapes = shapes;
         self.textures_delta.append(textures_delta);
-        needs_repaint
+
+        if self.screenshot_requested {
+            std::time::Duration::ZERO // send the screenshot to egui right away
+        } else {
+            repaint_after
+        }
     }
 
     /// Paint the results of the last call to [`Self::run`].
@@ -82,6 +96,14 @@ impl EguiGlow {
             &clipped_primitives,
         );
 
+        if std::mem::take(&mut self.screenshot_requested) {
+            let screenshot = self.painter.read_screen_rgba(dimensions);
+            self.egui_winit
+                .egui_input_mut()
+                .events
+                .push(egui::Event::Screenshot(std::sync::Arc::new(screenshot)));
+        }
+
         for id in textures_delta.free.drain(..) {
             self.painter.free_texture(id);
         }
@@ -0,0 +1,251 @@
+//! Easing functions for animations.
+//!
+//! Each function takes a `t` in `[0, 1]` and returns `0` when `t == 0` and `1` when `t == 1`.
+//! Some of them (e.g. [`back_out`]) overshoot in between.
+//!
+//! Use them together with [`crate::lerp`] to animate a value:
+//!
+//! ```
+//! use emath::{easing, lerp};
+//! let t = 0.5;
+//! let x = lerp(10.0..=20.0, easing::cubic_out(t));
+//! assert!(15.0 < x && x < 20.0);
+//! ```
+//!
+//! See <https://easings.net/> for what they look like.
+
+use std::f32::consts::PI;
+
+/// No easing, just `t`.
+#[inline]
+pub fn linear(t: f32) -> f32 {
+    t
+}
+
+/// Starts slow, ends fast.
+///
+/// <https://easings.net/#easeInQuad>
+#[inline]
+pub fn quadratic_in(t: f32) -> f32 {
+    t * t
+}
+
+/// Starts fast, ends slow.
+///
+/// <https://easings.net/#easeOutQuad>
+#[inline]
+pub fn quadratic_out(t: f32) -> f32 {
+    -(t * (t - 2.))
+}
+
+/// Slow at the start and end, fast in the middle.
+///
+/// <https://easings.net/#easeInOutQuad>
+#[inline]
+pub fn quadratic_in_out(t: f32) -> f32 {
+    if t < 0.5 {
+        2. * t * t
+    } else {
+        (-2. * t * t) + (4. * t) - 1.
+    }
+}
+
+/// <https://easings.net/#easeInCubic>
+#[inline]
+pub fn cubic_in(t: f32) -> f32 {
+    t * t * t
+}
+
+/// <https://easings.net/#easeOutCubic>
+#[inline]
+pub fn cubic_out(t: f32) -> f32 {
+    let f = t - 1.;
+    f * f * f + 1.
+}
+
+/// <https://easings.net/#easeInOutCubic>
+#[inline]
+pub fn cubic_in_out(t: f32) -> f32 {
+    if t < 0.5 {
+        4. * t * t * t
+    } else {
+        let f = (2. * t) - 2.;
+        0.5 * f * f * f + 1.
+    }
+}
+
+/// Follows a quarter sine wave.
+///
+/// <https://easings.net/#easeInSine>
+#[inline]
+pub fn sin_in(t: f32) -> f32 {
+    ((t - 1.) * PI / 2.).sin() + 1.
+}
+
+/// <https://easings.net/#easeOutSine>
+#[inline]
+pub fn sin_out(t: f32) -> f32 {
+    (t * PI / 2.).sin()
+}
+
+/// <https://easings.net/#easeInOutSine>
+#[inline]
+pub fn sin_in_out(t: f32) -> f32 {
+    0.5 * (1. - (t * PI).cos())
+}
+
+/// Follows a quarter circle.
+///
+/// <https://easings.net/#easeInCirc>
+#[inline]
+pub fn circular_in(t: f32) -> f32 {
+    1. - (1. - t * t).sqrt()
+}
+
+/// <https://easings.net/#easeOutCirc>
+#[inline]
+pub fn circular_out(t: f32) -> f32 {
+    ((2. - t) * t).sqrt()
+}
+
+/// <https://easings.net/#easeInOutCirc>
+#[inline]
+pub fn circular_in_out(t: f32) -> f32 {
+    if t < 0.5 {
+        0.5 * (1. - (1. - 4. * t * t).sqrt())
+    } else {
+        0.5 * ((-(2. * t - 3.) * (2. * t - 1.)).sqrt() + 1.)
+    }
+}
+
+/// Very slow start, then very fast.
+///
+/// <https://easings.net/#easeInExpo>
+#[inline]
+pub fn exponential_in(t: f32) -> f32 {
+    if t <= 0. {
+        t
+    } else {
+        2_f32.powf(10. * (t - 1.))
+    }
+}
+
+/// <https://easings.net/#easeOutExpo>
+#[inline]
+pub fn exponential_out(t: f32) -> f32 {
+    if 1. <= t {
+        t
+    } else {
+        1. - 2_f32.powf(-10. * t)
+    }
+}
+
+/// <https://easings.net/#easeInOutExpo>
+#[inline]
+pub fn exponential_in_out(t: f32) -> f32 {
+    if t <= 0. || 1. <= t {
+        t
+    } else if t < 0.5 {
+        0.5 * 2_f32.powf((20. * t) - 10.)
+    } else {
+        1. - 0.5 * 2_f32.powf((-20. * t) + 10.)
+    }
+}
+
+/// Pulls back a bit before moving forward.
+///
+/// <https://easings.net/#easeInBack>
+#[inline]
+pub fn back_in(t: f32) -> f32 {
+    t * t * t - t * (t * PI).sin()
+}
+
+/// Overshoots a bit before settling.
+///
+/// <https://easings.net/#easeOutBack>
+#[inline]
+pub fn back_out(t: f32) -> f32 {
+    let f = 1. - t;
+    1. - (f * f * f - f * (f * PI).sin())
+}
+
+/// <https://easings.net/#easeInOutBack>
+#[inline]
+pub fn back_in_out(t: f32) -> f32 {
+    if t < 0.5 {
+        let f = 2. * t;
+        0.5 * (f * f * f - f * (f * PI).sin())
+    } else {
+        let f = 1. - (2. * t - 1.);
+        0.5 * (1. - (f * f * f - f * (f * PI).sin())) + 0.5
+    }
+}
+
+/// Bounces a few times at the start.
+///
+/// <https://easings.net/#easeInBounce>
+#[inline]
+pub fn bounce_in(t: f32) -> f32 {
+    1. - bounce_out(1. - t)
+}
+
+/// Bounces a few times at the end, like a dropped ball.
+///
+/// <https://easings.net/#easeOutBounce>
+#[inline]
+pub fn bounce_out(t: f32) -> f32 {
+    if t < 4. / 11. {
+        (121. * t * t) / 16.
+    } else if t < 8. / 11. {
+        (363. / 40. * t * t) - (99. / 10. * t) + 17. / 5.
+    } else if t < 9. / 10. {
+        (4356. / 361. * t * t) - (35442. / 1805. * t) + 16061. / 1805.
+    } else {
+        (54. / 5. * t * t) - (513. / 25. * t) + 268. / 25.
+    }
+}
+
+/// <https://easings.net/#easeInOutBounce>
+#[inline]
+pub fn bounce_in_out(t: f32) -> f32 {
+    if t < 0.5 {
+        0.5 * bounce_in(t * 2.)
+    } else {
+        0.5 * bounce_out(t * 2. - 1.) + 0.5
+    }
+}
+
+#[cfg(test)]
+type NamedEasing = (&'static str, fn(f32) -> f32);
+
+#[test]
+fn test_easing_end_points() {
+    let functions: [NamedEasing; 22] = [
+        ("linear", linear),
+        ("quadratic_in", quadratic_in),
+        ("quadratic_out", quadratic_out),
+        ("quadratic_in_out", quadratic_in_out),
+        ("cubic_in", cubic_in),
+        ("cubic_out", cubic_out),
+        ("cubic_in_out", cubic_in_out),
+        ("sin_in", sin_in),
+        ("sin_out", sin_out),
+        ("sin_in_out", sin_in_out),
+        ("circular_in", circular_in),
+        ("circular_out", circular_out),
+        ("circular_in_out", circular_in_out),
+        ("exponential_in", exponential_in),
+        ("exponential_out", exponential_out),
+        ("exponential_in_out", exponential_in_out),
+        ("back_in", back_in),
+        ("back_out", back_out),
+        ("back_in_out", back_in_out),
+        ("bounce_in", bounce_in),
+        ("bounce_out", bounce_out),
+        ("bounce_in_out", bounce_in_out),
+    ];
+    for (name, f) in functions {
+        assert!(f(0.0).abs() < 1e-5, "{}(0) = {}", name, f(0.0));
+        assert!((f(1.0) - 1.0).abs() < 1e-5, "{}(1) = {}", name, f(1.0));
+    }
+}
@@ -27,6 +27,7 @@ use std::ops::{Add, Div, Mul, RangeInclusive, Sub};
 // ----------------------------------------------------------------------------
 
 pub mod align;
+pub mod easing;
 mod numeric;
 mod pos2;
 mod rect;
@@ -92,6 +93,30 @@ where
     (T::one() - t) * *range.start() + t * *range.end()
 }
 
+/// Where in the range is this value? Returns 0-1 if within the range.
+///
+/// Returns <0 if before and >1 if after.
+///
+/// Returns `None` if the input range is zero-width.
+///
+/// ```
+/// assert_eq!(emath::inverse_lerp(10.0..=20.0, 15.0), Some(0.5));
+/// assert_eq!(emath::inverse_lerp(10.0..=10.0, 15.0), None);
+/// ```
+#[inline]
+pub fn inverse_lerp<R>(range: RangeInclusive<R>, value: R) -> Option<R>
+where
+    R: Copy + PartialEq + Sub<R, Output = R> + Div<R, Output = R>,
+{
+    let min = *range.start();
+    let max = *range.end();
+    if min == max {
+        None
+    } else {
+        Some((value - min) / (max - min))
+    }
+}
+
 /// Linearly remap a value from one range to another,
 /// so that when `x == from.start()` returns `to.start()`
 /// and when `x == from.end()` returns `to.end()`.
@@ -135,6 +135,15 @@ impl Pos2 {
         (self - other).length()
     }
 
+    /// Linear interpolation between `self` (`t == 0`) and `other` (`t == 1`).
+    #[inline]
+    pub fn lerp(&self, other: Pos2, t: f32) -> Pos2 {
+        Pos2 {
+            x: lerp(self.x..=other.x, t),
+            y: lerp(self.y..=other.y, t),
+        }
+    }
+
     #[inline]
     pub fn distance_sq(self, other: Self) -> f32 {
         (self - other).length_sq()
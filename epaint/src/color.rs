@@ -184,6 +184,26 @@ impl Color32 {
         // we need a somewhat expensive conversion to linear space and back.
         Rgba::from(self).multiply(factor).into()
     }
+
+    /// Black or white, whichever is most readable as a text color on top of this (background) color.
+    ///
+    /// The alpha of `self` is ignored.
+    ///
+    /// ```
+    /// # use epaint::Color32;
+    /// assert_eq!(Color32::YELLOW.contrasting_text_color(), Color32::BLACK);
+    /// assert_eq!(Color32::DARK_BLUE.contrasting_text_color(), Color32::WHITE);
+    /// ```
+    pub fn contrasting_text_color(self) -> Color32 {
+        // The luminance at which black and white text have the same contrast ratio
+        // according to https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+        let threshold = 0.179;
+        if Rgba::from(self).to_opaque().luminance() > threshold {
+            Color32::BLACK
+        } else {
+            Color32::WHITE
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -374,6 +394,13 @@ impl Rgba {
         0.3 * self.r() + 0.59 * self.g() + 0.11 * self.b()
     }
 
+    /// The [relative luminance](https://www.w3.org/TR/WCAG21/#dfn-relative-luminance) of the color,
+    /// in the range 0-1 (for opaque colors).
+    #[inline]
+    pub fn luminance(&self) -> f32 {
+        0.2126 * self.r() + 0.7152 * self.g() + 0.0722 * self.b()
+    }
+
     /// Returns an opaque version of self
     pub fn to_opaque(&self) -> Self {
         if self.a() == 0.0 {
@@ -37,6 +37,46 @@ impl ImageData {
             Self::Color(_) | Self::Font(_) => 4,
         }
     }
+
+    /// Copy `patch` into this image, with the top left corner of the patch at `pos`.
+    ///
+    /// Returns `false` and leaves the image unchanged if the images are of different kinds,
+    /// or if the patch does not fit within this image.
+    pub(crate) fn apply_patch(&mut self, pos: [usize; 2], patch: &ImageData) -> bool {
+        let [w, h] = self.size();
+        let [patch_w, patch_h] = patch.size();
+        if w < pos[0] + patch_w || h < pos[1] + patch_h {
+            return false;
+        }
+
+        match (self, patch) {
+            (Self::Color(image), Self::Color(patch)) => {
+                copy_rows(&mut image.pixels, w, pos, &patch.pixels, patch_w);
+                true
+            }
+            (Self::Font(image), Self::Font(patch)) => {
+                copy_rows(&mut image.pixels, w, pos, &patch.pixels, patch_w);
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+fn copy_rows<T: Copy>(
+    pixels: &mut [T],
+    width: usize,
+    [x, y]: [usize; 2],
+    patch: &[T],
+    patch_width: usize,
+) {
+    if patch_width == 0 {
+        return;
+    }
+    for (row, patch_row) in patch.chunks_exact(patch_width).enumerate() {
+        let start = (y + row) * width + x;
+        pixels[start..start + patch_width].copy_from_slice(patch_row);
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -51,6 +91,15 @@ pub struct ColorImage {
     pub pixels: Vec<Color32>,
 }
 
+impl std::fmt::Debug for ColorImage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Printing all the pixels is rarely useful:
+        f.debug_struct("ColorImage")
+            .field("size", &self.size)
+            .finish_non_exhaustive()
+    }
+}
+
 impl ColorImage {
     /// Create an image filled with the given color.
     pub fn new(size: [usize; 2], color: Color32) -> Self {
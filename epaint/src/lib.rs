@@ -4,6 +4,7 @@
 //!
 //! Create some [`Shape`]:s and pass them to [`tessellate_shapes`] to generate [`Mesh`]:es
 //! that you can then paint using some graphics API of your choice (e.g. OpenGL).
+//! If you already have a [`Fonts`], [`tessellate`] is a shorter way to do the same.
 //!
 //! ## Feature flags
 #![cfg_attr(feature = "document-features", doc = document_features::document_features!())]
@@ -17,11 +18,14 @@ pub mod color;
 pub mod image;
 mod mesh;
 pub mod mutex;
+#[cfg(feature = "snapshot")]
+pub mod rasterizer;
 mod shadow;
 mod shape;
 pub mod shape_transform;
 pub mod stats;
 mod stroke;
+pub mod svg_path;
 pub mod tessellator;
 pub mod text;
 mod texture_atlas;
@@ -36,14 +40,14 @@ pub use {
     mesh::{Mesh, Mesh16, Vertex},
     shadow::Shadow,
     shape::{
-        CircleShape, PaintCallback, PaintCallbackInfo, PathShape, RectShape, Rounding, Shape,
-        TextShape,
+        CircleShape, PaintCallback, PaintCallbackInfo, PathShape, RectShape, RepeatShape, Rounding,
+        Shape, TextShape,
     },
     stats::PaintStats,
     stroke::Stroke,
-    tessellator::{tessellate_shapes, TessellationOptions, Tessellator},
+    tessellator::{tessellate, tessellate_shapes, TessellationOptions, Tessellator},
     text::{FontFamily, FontId, Fonts, Galley},
-    texture_atlas::TextureAtlas,
+    texture_atlas::{PreparedDisc, TextureAtlas},
     texture_handle::TextureHandle,
     textures::TextureManager,
 };
@@ -116,6 +120,60 @@ pub enum Primitive {
     Callback(PaintCallback),
 }
 
+/// A rectangle in physical pixels, e.g. a scissor rectangle.
+///
+/// `epaint` and `egui` work in logical points, but graphics APIs work in physical pixels.
+/// Convert to this type at the boundary to the graphics API,
+/// so that the two units are never mixed up.
+///
+/// `(0, 0)` is the top left corner of the render target.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PixelRect {
+    pub min_x: u32,
+    pub min_y: u32,
+    pub max_x: u32,
+    pub max_y: u32,
+}
+
+impl PixelRect {
+    /// Convert a rectangle in points (e.g. [`ClippedPrimitive::clip_rect`]) to physical pixels.
+    ///
+    /// The result is rounded to the nearest pixel and clamped to a render target of the given size (in pixels).
+    ///
+    /// ```
+    /// # use epaint::{PixelRect, Rect, pos2};
+    /// let clip_rect = Rect::from_min_max(pos2(-10.0, 10.25), pos2(100.0, 1000.0));
+    /// let pixel_rect = PixelRect::from_points(clip_rect, 2.0, [800, 600]);
+    /// assert_eq!(pixel_rect, PixelRect { min_x: 0, min_y: 21, max_x: 200, max_y: 600 });
+    /// ```
+    pub fn from_points(rect: emath::Rect, pixels_per_point: f32, target_size: [u32; 2]) -> Self {
+        let [width, height] = [target_size[0] as f32, target_size[1] as f32];
+
+        // Make sure the rect fits within the target, and thus within an `u32`:
+        let min_x = (pixels_per_point * rect.min.x).clamp(0.0, width);
+        let min_y = (pixels_per_point * rect.min.y).clamp(0.0, height);
+        let max_x = (pixels_per_point * rect.max.x).clamp(min_x, width);
+        let max_y = (pixels_per_point * rect.max.y).clamp(min_y, height);
+
+        Self {
+            min_x: min_x.round() as u32,
+            min_y: min_y.round() as u32,
+            max_x: max_x.round() as u32,
+            max_y: max_y.round() as u32,
+        }
+    }
+
+    #[inline]
+    pub fn width(&self) -> u32 {
+        self.max_x - self.min_x
+    }
+
+    #[inline]
+    pub fn height(&self) -> u32 {
+        self.max_y - self.min_y
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 /// An assert that is only active when `epaint` is compiled with the `extra_asserts` feature
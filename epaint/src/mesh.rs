@@ -286,10 +286,51 @@ pub struct Mesh16 {
 impl Mesh16 {
     /// Are all indices within the bounds of the contained vertices?
     pub fn is_valid(&self) -> bool {
-        if let Ok(n) = u16::try_from(self.vertices.len()) {
-            self.indices.iter().all(|&i| i < n)
-        } else {
-            false
-        }
+        // Note that 2^16 vertices is fine, since the largest index is then `u16::MAX`.
+        self.vertices.len() <= 1 << 16
+            && self
+                .indices
+                .iter()
+                .all(|&i| (i as usize) < self.vertices.len())
+    }
+}
+
+#[test]
+fn test_split_to_u16() {
+    // A long strip of quads, too many vertices for 16-bit indices:
+    let num_quads = 40_000;
+    let mut mesh = Mesh::default();
+    for i in 0..num_quads {
+        let rect = Rect::from_min_size(pos2(i as f32, 0.0), vec2(1.0, 1.0));
+        mesh.add_colored_rect(rect, Color32::WHITE);
     }
+    assert!(mesh.vertices.len() > u16::MAX as usize);
+
+    let meshes = mesh.clone().split_to_u16();
+    assert!(meshes.len() > 1);
+    assert!(meshes.iter().all(|m| m.is_valid()));
+
+    // All triangles are still there, and still refer to the same vertices:
+    let triangles: Vec<[Vertex; 3]> = meshes
+        .iter()
+        .flat_map(|m| {
+            m.indices
+                .chunks(3)
+                .map(|t| t.iter().map(|&i| m.vertices[i as usize]))
+                .map(|mut t| [t.next().unwrap(), t.next().unwrap(), t.next().unwrap()])
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    let expected: Vec<[Vertex; 3]> = mesh
+        .indices
+        .chunks(3)
+        .map(|t| {
+            [
+                mesh.vertices[t[0] as usize],
+                mesh.vertices[t[1] as usize],
+                mesh.vertices[t[2] as usize],
+            ]
+        })
+        .collect();
+    assert_eq!(triangles, expected);
 }
@@ -0,0 +1,351 @@
+//! A simple software rasterizer, for rendering tessellated shapes to an image on the CPU.
+//!
+//! This is slow compared to a GPU, but needs no window or graphics driver,
+//! and produces the exact same pixels on every platform.
+//! That makes it useful for snapshot tests and for generating screenshots for documentation.
+//!
+//! Only available with the `snapshot` feature.
+
+use crate::{
+    textures::{TextureFilter, TexturesDelta},
+    ClippedPrimitive, Color32, ColorImage, ImageData, Mesh, PixelRect, Primitive, TextureId,
+    Vertex,
+};
+use ahash::AHashMap;
+use emath::{Pos2, Rect};
+
+struct Texture {
+    image: ColorImage,
+    filter: TextureFilter,
+}
+
+/// Renders [`ClippedPrimitive`]s to a [`ColorImage`] on the CPU.
+///
+/// Like any other painter, it needs to be kept up-to-date with the [`TexturesDelta`] of each frame,
+/// using [`Self::update_textures`].
+///
+/// Colors are blended in gamma space, so anti-aliased edges and text can differ slightly from a GPU painter.
+/// [`Primitive::Callback`]s are ignored.
+///
+/// ```
+/// # use epaint::*;
+/// let mut rasterizer = epaint::rasterizer::Rasterizer::default();
+///
+/// let rect = Rect::from_min_max(pos2(1.0, 1.0), pos2(3.0, 3.0));
+/// let mut mesh = Mesh::default();
+/// mesh.add_colored_rect(rect, Color32::RED);
+/// let primitives = [ClippedPrimitive {
+///     clip_rect: Rect::EVERYTHING,
+///     primitive: Primitive::Mesh(mesh),
+/// }];
+///
+/// let image = rasterizer.render([4, 4], 1.0, &primitives, Color32::BLACK);
+/// assert_eq!(image[(0, 0)], Color32::BLACK);
+/// assert_eq!(image[(1, 1)], Color32::RED);
+/// assert_eq!(image[(2, 2)], Color32::RED);
+/// assert_eq!(image[(3, 3)], Color32::BLACK);
+/// ```
+#[derive(Default)]
+pub struct Rasterizer {
+    textures: AHashMap<TextureId, Texture>,
+}
+
+impl Rasterizer {
+    /// Apply the texture changes of a frame.
+    ///
+    /// Unlike a GPU painter, this frees textures right away,
+    /// so call this _after_ [`Self::render`] if there is anything to free.
+    ///
+    /// Partial updates of unknown textures, and patches that don't fit within their texture, are skipped.
+    pub fn update_textures(&mut self, textures_delta: &TexturesDelta) {
+        for (&id, delta) in &textures_delta.set {
+            let image = match &delta.image {
+                ImageData::Color(image) => image.clone(),
+                ImageData::Font(image) => {
+                    let gamma = 1.0;
+                    ColorImage {
+                        size: image.size,
+                        pixels: image.srgba_pixels(gamma).collect(),
+                    }
+                }
+            };
+
+            if let Some(pos) = delta.pos {
+                if let Some(texture) = self.textures.get_mut(&id) {
+                    // `apply_patch` leaves the texture unchanged if the patch doesn't fit:
+                    let mut patched = ImageData::Color(std::mem::take(&mut texture.image));
+                    patched.apply_patch(pos, &ImageData::Color(image));
+                    if let ImageData::Color(image) = patched {
+                        texture.image = image;
+                    }
+                }
+            } else {
+                let filter = delta.filter;
+                self.textures.insert(id, Texture { image, filter });
+            }
+        }
+
+        for id in &textures_delta.free {
+            self.textures.remove(id);
+        }
+    }
+
+    /// Render the primitives to an image of the given size (in physical pixels),
+    /// on top of the given background color.
+    pub fn render(
+        &self,
+        size_in_pixels: [usize; 2],
+        pixels_per_point: f32,
+        clipped_primitives: &[ClippedPrimitive],
+        background: Color32,
+    ) -> ColorImage {
+        let mut image = ColorImage::new(size_in_pixels, background);
+        let target_size = [size_in_pixels[0] as u32, size_in_pixels[1] as u32];
+
+        for ClippedPrimitive {
+            clip_rect,
+            primitive,
+        } in clipped_primitives
+        {
+            if let Primitive::Mesh(mesh) = primitive {
+                let clip = PixelRect::from_points(*clip_rect, pixels_per_point, target_size);
+                self.render_mesh(&mut image, pixels_per_point, clip, mesh);
+            }
+        }
+
+        image
+    }
+
+    fn render_mesh(
+        &self,
+        image: &mut ColorImage,
+        pixels_per_point: f32,
+        clip: PixelRect,
+        mesh: &Mesh,
+    ) {
+        let texture = self.textures.get(&mesh.texture_id);
+
+        for triangle in mesh.indices.chunks_exact(3) {
+            let vertex = |i: u32| {
+                let v = mesh.vertices[i as usize];
+                Vertex {
+                    pos: Pos2::new(v.pos.x * pixels_per_point, v.pos.y * pixels_per_point),
+                    ..v
+                }
+            };
+            let [a, b, c] = [
+                vertex(triangle[0]),
+                vertex(triangle[1]),
+                vertex(triangle[2]),
+            ];
+            fill_triangle(image, clip, texture, [a, b, c]);
+        }
+    }
+}
+
+fn fill_triangle(
+    image: &mut ColorImage,
+    clip: PixelRect,
+    texture: Option<&Texture>,
+    [a, b, c]: [Vertex; 3],
+) {
+    let area = edge(a.pos, b.pos, c.pos);
+    if area == 0.0 || !area.is_finite() {
+        return;
+    }
+
+    let bounds = Rect::from_points(&[a.pos, b.pos, c.pos]);
+    let min_x = (bounds.min.x.floor().max(clip.min_x as f32)) as usize;
+    let min_y = (bounds.min.y.floor().max(clip.min_y as f32)) as usize;
+    let max_x = (bounds.max.x.ceil().min(clip.max_x as f32)) as usize;
+    let max_y = (bounds.max.y.ceil().min(clip.max_y as f32)) as usize;
+
+    for y in min_y..max_y {
+        for x in min_x..max_x {
+            let p = Pos2::new(x as f32 + 0.5, y as f32 + 0.5);
+
+            // Barycentric coordinates, which are all positive inside the triangle regardless of winding:
+            let wa = edge(b.pos, c.pos, p) / area;
+            let wb = edge(c.pos, a.pos, p) / area;
+            let wc = edge(a.pos, b.pos, p) / area;
+            if wa < 0.0 || wb < 0.0 || wc < 0.0 {
+                continue;
+            }
+
+            let color = interpolate_color([a.color, b.color, c.color], [wa, wb, wc]);
+            let color = if let Some(texture) = texture {
+                let u = wa * a.uv.x + wb * b.uv.x + wc * c.uv.x;
+                let v = wa * a.uv.y + wb * b.uv.y + wc * c.uv.y;
+                multiply(color, sample(texture, u, v))
+            } else {
+                color
+            };
+
+            let dst = &mut image[(x, y)];
+            *dst = blend(color, *dst);
+        }
+    }
+}
+
+/// Twice the signed area of the triangle `a, b, p`.
+#[inline]
+fn edge(a: Pos2, b: Pos2, p: Pos2) -> f32 {
+    (b.x - a.x) * (p.y - a.y) - (b.y - a.y) * (p.x - a.x)
+}
+
+fn interpolate_color(colors: [Color32; 3], weights: [f32; 3]) -> [f32; 4] {
+    let mut result = [0.0; 4];
+    for (color, weight) in colors.iter().zip(weights) {
+        for (r, c) in result.iter_mut().zip(color.to_array()) {
+            *r += weight * c as f32;
+        }
+    }
+    result
+}
+
+fn sample(texture: &Texture, u: f32, v: f32) -> [f32; 4] {
+    let image = &texture.image;
+    let [w, h] = image.size;
+    if w == 0 || h == 0 {
+        return [0.0; 4];
+    }
+    let texel = |x: isize, y: isize| {
+        let x = x.clamp(0, w as isize - 1) as usize;
+        let y = y.clamp(0, h as isize - 1) as usize;
+        image[(x, y)].to_array().map(|c| c as f32)
+    };
+
+    let x = u * w as f32;
+    let y = v * h as f32;
+    match texture.filter {
+        TextureFilter::Nearest => texel(x.floor() as isize, y.floor() as isize),
+        TextureFilter::Linear => {
+            let (x, y) = (x - 0.5, y - 0.5);
+            let (x0, y0) = (x.floor(), y.floor());
+            let (tx, ty) = (x - x0, y - y0);
+            let (x0, y0) = (x0 as isize, y0 as isize);
+            let [c00, c10, c01, c11] = [
+                texel(x0, y0),
+                texel(x0 + 1, y0),
+                texel(x0, y0 + 1),
+                texel(x0 + 1, y0 + 1),
+            ];
+            let mut result = [0.0; 4];
+            for i in 0..4 {
+                let top = c00[i] + tx * (c10[i] - c00[i]);
+                let bottom = c01[i] + tx * (c11[i] - c01[i]);
+                result[i] = top + ty * (bottom - top);
+            }
+            result
+        }
+    }
+}
+
+fn multiply(a: [f32; 4], b: [f32; 4]) -> [f32; 4] {
+    [
+        a[0] * b[0] / 255.0,
+        a[1] * b[1] / 255.0,
+        a[2] * b[2] / 255.0,
+        a[3] * b[3] / 255.0,
+    ]
+}
+
+/// Blend premultiplied `src` on top of `dst`.
+fn blend(src: [f32; 4], dst: Color32) -> Color32 {
+    let inv_alpha = 1.0 - src[3] / 255.0;
+    let channel = |i: usize| {
+        let value = src[i] + inv_alpha * dst[i] as f32;
+        value.round().clamp(0.0, 255.0) as u8
+    };
+    Color32::from_rgba_premultiplied(channel(0), channel(1), channel(2), channel(3))
+}
+
+#[cfg(test)]
+#[test]
+fn test_rasterize_clipped_and_textured() {
+    use crate::{pos2, ImageDelta};
+
+    let mut rasterizer = Rasterizer::default();
+    let texture_id = TextureId::User(0);
+    let mut texture = ColorImage::new([2, 1], Color32::WHITE);
+    texture.pixels[1] = Color32::from_rgb(0, 0, 255);
+    let mut delta = TexturesDelta::default();
+    delta.set.insert(
+        texture_id,
+        ImageDelta::full(texture, TextureFilter::Nearest),
+    );
+    rasterizer.update_textures(&delta);
+
+    let mut mesh = Mesh::with_texture(texture_id);
+    mesh.add_rect_with_uv(
+        Rect::from_min_max(pos2(0.0, 0.0), pos2(4.0, 2.0)),
+        Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0)),
+        Color32::WHITE,
+    );
+    let primitives = [ClippedPrimitive {
+        clip_rect: Rect::from_min_max(pos2(0.0, 0.0), pos2(4.0, 1.0)),
+        primitive: Primitive::Mesh(mesh),
+    }];
+
+    // At two pixels per point, the rectangle covers 8x4 pixels, but the clip rectangle only the top 8x2:
+    let image = rasterizer.render([8, 8], 2.0, &primitives, Color32::TRANSPARENT);
+    assert_eq!(image[(0, 0)], Color32::WHITE);
+    assert_eq!(image[(7, 1)], Color32::from_rgb(0, 0, 255));
+    assert_eq!(image[(0, 2)], Color32::TRANSPARENT);
+
+    // Half transparent on top of black:
+    let mut mesh = Mesh::default();
+    let half_red = Color32::from_rgba_premultiplied(128, 0, 0, 128);
+    mesh.add_colored_rect(
+        Rect::from_min_max(pos2(-10.0, -10.0), pos2(10.0, 10.0)),
+        half_red,
+    );
+    let primitives = [ClippedPrimitive {
+        clip_rect: Rect::EVERYTHING,
+        primitive: Primitive::Mesh(mesh),
+    }];
+    let image = rasterizer.render([2, 2], 1.0, &primitives, Color32::BLACK);
+    assert_eq!(image[(1, 1)], Color32::from_rgb(128, 0, 0));
+}
+
+#[cfg(test)]
+#[test]
+fn test_skip_bad_texture_patches() {
+    use crate::ImageDelta;
+
+    let mut rasterizer = Rasterizer::default();
+    let texture_id = TextureId::User(0);
+    let mut delta = TexturesDelta::default();
+    delta.set.insert(
+        texture_id,
+        ImageDelta::full(
+            ColorImage::new([2, 2], Color32::WHITE),
+            TextureFilter::Nearest,
+        ),
+    );
+    rasterizer.update_textures(&delta);
+
+    let patch = |pos, size| {
+        let mut delta = TexturesDelta::default();
+        let image = ColorImage::new(size, Color32::RED);
+        delta.set.insert(
+            texture_id,
+            ImageDelta::partial(pos, image.clone(), TextureFilter::Nearest),
+        );
+        delta.set.insert(
+            TextureId::User(1),
+            ImageDelta::partial(pos, image, TextureFilter::Nearest),
+        );
+        delta
+    };
+
+    rasterizer.update_textures(&patch([1, 1], [2, 1]));
+    let texture = &rasterizer.textures[&texture_id].image;
+    assert!(texture.pixels.iter().all(|&pixel| pixel == Color32::WHITE));
+    assert!(!rasterizer.textures.contains_key(&TextureId::User(1)));
+
+    rasterizer.update_textures(&patch([1, 1], [1, 1]));
+    let texture = &rasterizer.textures[&texture_id].image;
+    assert_eq!(texture[(0, 0)], Color32::WHITE);
+    assert_eq!(texture[(1, 1)], Color32::RED);
+}
@@ -35,6 +35,9 @@ pub enum Shape {
     QuadraticBezier(QuadraticBezierShape),
     CubicBezier(CubicBezierShape),
 
+    /// The same shape painted at many offsets, e.g. the cells of a grid or the ticks of an axis.
+    Repeat(RepeatShape),
+
     /// Backend-specific painting.
     Callback(PaintCallback),
 }
@@ -196,6 +199,15 @@ impl Shape {
         Self::Mesh(mesh)
     }
 
+    /// Paint `shape` once for each of the `offsets`.
+    ///
+    /// This is a lot cheaper than painting many copies of the same shape,
+    /// since the shape is only tessellated once.
+    #[inline]
+    pub fn repeat(shape: impl Into<Shape>, offsets: Vec<Vec2>) -> Self {
+        RepeatShape::new(shape, offsets).into()
+    }
+
     pub fn image(texture_id: TextureId, rect: Rect, uv: Rect, tint: Color32) -> Self {
         let mut mesh = Mesh::with_texture(texture_id);
         mesh.add_rect_with_uv(rect, uv, tint);
@@ -227,6 +239,7 @@ impl Shape {
             Self::Mesh(mesh) => mesh.calc_bounds(),
             Self::QuadraticBezier(bezier) => bezier.visual_bounding_rect(),
             Self::CubicBezier(bezier) => bezier.visual_bounding_rect(),
+            Self::Repeat(repeat_shape) => repeat_shape.visual_bounding_rect(),
             Self::Callback(custom) => custom.rect,
         }
     }
@@ -236,10 +249,10 @@ impl Shape {
 impl Shape {
     #[inline(always)]
     pub fn texture_id(&self) -> super::TextureId {
-        if let Shape::Mesh(mesh) = self {
-            mesh.texture_id
-        } else {
-            super::TextureId::default()
+        match self {
+            Shape::Mesh(mesh) => mesh.texture_id,
+            Shape::Repeat(repeat_shape) => repeat_shape.shape.texture_id(),
+            _ => super::TextureId::default(),
         }
     }
 
@@ -284,6 +297,9 @@ impl Shape {
                     *p += delta;
                 }
             }
+            Shape::Repeat(repeat_shape) => {
+                repeat_shape.shape.translate(delta);
+            }
             Shape::Callback(shape) => {
                 shape.rect = shape.rect.translate(delta);
             }
@@ -613,6 +629,50 @@ impl From<TextShape> for Shape {
 
 // ----------------------------------------------------------------------------
 
+/// The same shape painted at many offsets.
+///
+/// See [`Shape::repeat`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct RepeatShape {
+    /// The shape to repeat.
+    ///
+    /// Must not contain any [`Shape::Callback`],
+    /// and everything in it must use the same texture.
+    pub shape: Box<Shape>,
+
+    /// Paint [`Self::shape`] translated by each of these.
+    pub offsets: Vec<Vec2>,
+}
+
+impl RepeatShape {
+    #[inline]
+    pub fn new(shape: impl Into<Shape>, offsets: Vec<Vec2>) -> Self {
+        Self {
+            shape: Box::new(shape.into()),
+            offsets,
+        }
+    }
+
+    /// The visual bounding rectangle (includes stroke widths)
+    pub fn visual_bounding_rect(&self) -> Rect {
+        let shape_rect = self.shape.visual_bounding_rect();
+        let mut rect = Rect::NOTHING;
+        for offset in &self.offsets {
+            rect = rect.union(shape_rect.translate(*offset));
+        }
+        rect
+    }
+}
+
+impl From<RepeatShape> for Shape {
+    #[inline(always)]
+    fn from(shape: RepeatShape) -> Self {
+        Self::Repeat(shape)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// Creates equally spaced filled circles from a line.
 fn points_from_line(
     path: &[Pos2],
@@ -51,6 +51,9 @@ pub fn adjust_colors(shape: &mut Shape, adjust_color: &impl Fn(&mut Color32)) {
             adjust_color(&mut bezier.fill);
             adjust_color(&mut bezier.stroke.color);
         }
+        Shape::Repeat(repeat_shape) => {
+            adjust_colors(&mut repeat_shape.shape, adjust_color);
+        }
         Shape::Callback(_) => {
             // Can't tint user callback code
         }
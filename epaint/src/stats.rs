@@ -216,6 +216,9 @@ impl PaintStats {
             Shape::Mesh(mesh) => {
                 self.shape_mesh += AllocInfo::from_mesh(mesh);
             }
+            Shape::Repeat(repeat_shape) => {
+                self.add(&repeat_shape.shape);
+            }
             Shape::Callback(_) => {
                 self.num_callbacks += 1;
             }
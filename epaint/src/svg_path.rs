@@ -0,0 +1,346 @@
+//! Parsing of [SVG path data](https://www.w3.org/TR/SVG2/paths.html#PathData),
+//! for painting vector icons without first rasterizing them.
+
+use crate::{Color32, CubicBezierShape, PathShape, Pos2, Rect, Shape, Stroke};
+use emath::{pos2, RectTransform, Vec2};
+
+/// A parsed SVG path, i.e. the `d` attribute of a `<path>` element.
+///
+/// Only a subset of the path commands are supported:
+/// `M` (move to), `L` (line to), `C` (cubic Bézier) and `Z` (close path),
+/// in both their absolute (upper case) and relative (lower case) forms.
+///
+/// ```
+/// # use epaint::{emath::RectTransform, svg_path::SvgPath, Color32, Rect, Stroke};
+/// let arrow = SvgPath::parse("M 2 12 L 22 12 M 14 4 L 22 12 L 14 20").unwrap();
+/// let icon_rect = Rect::from_min_size(Default::default(), [24.0, 24.0].into());
+/// let screen_rect = Rect::from_min_size([100.0, 100.0].into(), [16.0, 16.0].into());
+/// let shapes = arrow.to_shapes(
+///     &RectTransform::from_to(icon_rect, screen_rect),
+///     Color32::TRANSPARENT,
+///     Stroke::new(1.0, Color32::WHITE),
+///     0.1,
+/// );
+/// assert_eq!(shapes.len(), 2);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SvgPath {
+    sub_paths: Vec<SubPath>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct SubPath {
+    start: Pos2,
+    segments: Vec<Segment>,
+    closed: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Segment {
+    Line(Pos2),
+
+    /// Two control points followed by the end point.
+    Cubic([Pos2; 3]),
+}
+
+impl SvgPath {
+    /// Parse SVG path data, e.g. `"M 10 10 L 20 10 C 25 10 25 20 20 20 Z"`.
+    ///
+    /// Returns a description of the problem on failure.
+    pub fn parse(data: &str) -> Result<Self, String> {
+        let mut parser = Parser {
+            data: data.as_bytes(),
+            cursor: 0,
+        };
+        let mut sub_paths: Vec<SubPath> = vec![];
+        let mut current = Pos2::ZERO;
+        let mut command = None;
+
+        loop {
+            parser.skip_separators();
+            let c = match parser.data.get(parser.cursor) {
+                Some(c) if c.is_ascii_alphabetic() => {
+                    parser.cursor += 1;
+                    *c
+                }
+                // Implicit repetition of the previous command:
+                Some(_) => command.ok_or_else(|| {
+                    format!("Expected a path command at offset {}", parser.cursor)
+                })?,
+                None => break,
+            };
+
+            let relative = c.is_ascii_lowercase();
+            let offset = if relative {
+                current.to_vec2()
+            } else {
+                Vec2::ZERO
+            };
+
+            command = match c.to_ascii_uppercase() {
+                b'M' => {
+                    current = parser.point()? + offset;
+                    sub_paths.push(SubPath {
+                        start: current,
+                        segments: vec![],
+                        closed: false,
+                    });
+                    // Any coordinates following a move-to are implicit line-tos:
+                    Some(if relative { b'l' } else { b'L' })
+                }
+                b'L' => {
+                    let end = parser.point()? + offset;
+                    open_sub_path(&mut sub_paths, current, parser.cursor)?
+                        .segments
+                        .push(Segment::Line(end));
+                    current = end;
+                    Some(c)
+                }
+                b'C' => {
+                    let control_1 = parser.point()? + offset;
+                    let control_2 = parser.point()? + offset;
+                    let end = parser.point()? + offset;
+                    open_sub_path(&mut sub_paths, current, parser.cursor)?
+                        .segments
+                        .push(Segment::Cubic([control_1, control_2, end]));
+                    current = end;
+                    Some(c)
+                }
+                b'Z' => {
+                    let sub_path = current_sub_path(&mut sub_paths, parser.cursor)?;
+                    sub_path.closed = true;
+                    current = sub_path.start;
+                    None
+                }
+                _ => {
+                    return Err(format!(
+                        "Unsupported path command {:?} at offset {}",
+                        c as char,
+                        parser.cursor - 1
+                    ));
+                }
+            };
+        }
+
+        Ok(Self { sub_paths })
+    }
+
+    /// Bounding rectangle of all points in the path, including Bézier control points.
+    pub fn bounding_rect(&self) -> Rect {
+        let mut rect = Rect::NOTHING;
+        for sub_path in &self.sub_paths {
+            rect.extend_with(sub_path.start);
+            for segment in &sub_path.segments {
+                match segment {
+                    Segment::Line(p) => rect.extend_with(*p),
+                    Segment::Cubic(points) => {
+                        for p in points {
+                            rect.extend_with(*p);
+                        }
+                    }
+                }
+            }
+        }
+        rect
+    }
+
+    /// Turn the path into one [`PathShape`] per sub-path.
+    ///
+    /// `transform` maps from the coordinate system of the path data (e.g. the `viewBox` of the icon) to screen space.
+    /// Curves are flattened into line segments with the given `tolerance` (in screen space points).
+    ///
+    /// Note that fills are only supported for convex shapes.
+    pub fn to_path_shapes(
+        &self,
+        transform: &RectTransform,
+        fill: Color32,
+        stroke: impl Into<Stroke>,
+        tolerance: f32,
+    ) -> Vec<PathShape> {
+        let stroke = stroke.into();
+        self.sub_paths
+            .iter()
+            .map(|sub_path| {
+                let mut current = transform.transform_pos(sub_path.start);
+                let mut points = vec![current];
+                for segment in &sub_path.segments {
+                    match segment {
+                        Segment::Line(p) => {
+                            current = transform.transform_pos(*p);
+                            points.push(current);
+                        }
+                        Segment::Cubic([control_1, control_2, end]) => {
+                            let curve = CubicBezierShape::from_points_stroke(
+                                [
+                                    current,
+                                    transform.transform_pos(*control_1),
+                                    transform.transform_pos(*control_2),
+                                    transform.transform_pos(*end),
+                                ],
+                                false,
+                                Color32::TRANSPARENT,
+                                Stroke::none(),
+                            );
+                            // The first point is the start point, which we already have:
+                            points.extend(curve.flatten(Some(tolerance)).into_iter().skip(1));
+                            current = curve.points[3];
+                        }
+                    }
+                }
+                PathShape {
+                    points,
+                    closed: sub_path.closed,
+                    fill,
+                    stroke,
+                }
+            })
+            .collect()
+    }
+
+    /// Like [`Self::to_path_shapes`], but returns [`Shape`]:s.
+    pub fn to_shapes(
+        &self,
+        transform: &RectTransform,
+        fill: Color32,
+        stroke: impl Into<Stroke>,
+        tolerance: f32,
+    ) -> Vec<Shape> {
+        self.to_path_shapes(transform, fill, stroke, tolerance)
+            .into_iter()
+            .map(Shape::Path)
+            .collect()
+    }
+}
+
+fn current_sub_path(sub_paths: &mut [SubPath], cursor: usize) -> Result<&mut SubPath, String> {
+    sub_paths
+        .last_mut()
+        .ok_or_else(|| format!("Path must start with a move-to command (offset {})", cursor))
+}
+
+/// The sub-path to draw to from `current`.
+///
+/// Drawing after a close-path without a move-to starts a new sub-path,
+/// at the start of the one that was closed.
+fn open_sub_path(
+    sub_paths: &mut Vec<SubPath>,
+    current: Pos2,
+    cursor: usize,
+) -> Result<&mut SubPath, String> {
+    if sub_paths.last().map_or(false, |sub_path| sub_path.closed) {
+        sub_paths.push(SubPath {
+            start: current,
+            segments: vec![],
+            closed: false,
+        });
+    }
+    current_sub_path(sub_paths, cursor)
+}
+
+struct Parser<'a> {
+    data: &'a [u8],
+    cursor: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn skip_separators(&mut self) {
+        while let Some(c) = self.data.get(self.cursor) {
+            if c.is_ascii_whitespace() || *c == b',' {
+                self.cursor += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn number(&mut self) -> Result<f32, String> {
+        self.skip_separators();
+        let start = self.cursor;
+        let mut seen_dot = false;
+        let mut seen_exponent = false;
+        while let Some(&c) = self.data.get(self.cursor) {
+            let is_sign = c == b'-' || c == b'+';
+            let after_exponent =
+                self.cursor > start && matches!(self.data[self.cursor - 1], b'e' | b'E');
+            if c.is_ascii_digit() || (is_sign && (self.cursor == start || after_exponent)) {
+                self.cursor += 1;
+            } else if c == b'.' && !seen_dot && !seen_exponent {
+                seen_dot = true;
+                self.cursor += 1;
+            } else if (c == b'e' || c == b'E') && !seen_exponent && self.cursor > start {
+                seen_exponent = true;
+                self.cursor += 1;
+            } else {
+                break;
+            }
+        }
+        std::str::from_utf8(&self.data[start..self.cursor])
+            .ok()
+            .and_then(|number| number.parse().ok())
+            .ok_or_else(|| format!("Expected a number at offset {}", start))
+    }
+
+    fn point(&mut self) -> Result<Pos2, String> {
+        let x = self.number()?;
+        let y = self.number()?;
+        Ok(pos2(x, y))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_svg_path() {
+        let path = SvgPath::parse("M10,10 l10-5 20 0C 40 0, 50 0, 50 10z m-5.5.5 L1e1 2").unwrap();
+        assert_eq!(path.sub_paths.len(), 2);
+
+        let first = &path.sub_paths[0];
+        assert_eq!(first.start, pos2(10.0, 10.0));
+        assert!(first.closed);
+        assert_eq!(
+            first.segments,
+            vec![
+                Segment::Line(pos2(20.0, 5.0)),
+                Segment::Line(pos2(40.0, 5.0)),
+                Segment::Cubic([pos2(40.0, 0.0), pos2(50.0, 0.0), pos2(50.0, 10.0)]),
+            ]
+        );
+
+        // Relative to the start of the closed sub-path:
+        let second = &path.sub_paths[1];
+        assert_eq!(second.start, pos2(4.5, 10.5));
+        assert!(!second.closed);
+        assert_eq!(second.segments, vec![Segment::Line(pos2(10.0, 2.0))]);
+
+        assert_eq!(
+            path.bounding_rect(),
+            Rect::from_min_max(pos2(4.5, 0.0), pos2(50.0, 10.5))
+        );
+    }
+
+    #[test]
+    fn test_draw_after_close_path() {
+        let path = SvgPath::parse("M0 0 L1 0 Z L0 1").unwrap();
+        assert_eq!(path.sub_paths.len(), 2);
+
+        let first = &path.sub_paths[0];
+        assert!(first.closed);
+        assert_eq!(first.segments, vec![Segment::Line(pos2(1.0, 0.0))]);
+
+        // Starts where the closed sub-path started:
+        let second = &path.sub_paths[1];
+        assert_eq!(second.start, pos2(0.0, 0.0));
+        assert!(!second.closed);
+        assert_eq!(second.segments, vec![Segment::Line(pos2(0.0, 1.0))]);
+    }
+
+    #[test]
+    fn test_parse_svg_path_errors() {
+        assert!(SvgPath::parse("L 10 10").is_err());
+        assert!(SvgPath::parse("M 10").is_err());
+        assert!(SvgPath::parse("M 10 10 Q 0 0 5 5").is_err());
+        assert_eq!(SvgPath::parse(""), Ok(SvgPath::default()));
+    }
+}
@@ -616,7 +616,10 @@ pub struct TessellationOptions {
     pub feathering_size_in_pixels: f32,
 
     /// If `true` (default) cull certain primitives before tessellating them.
-    /// This likely makes
+    ///
+    /// Shapes whose bounding rectangle lies completely outside the clip rectangle
+    /// (e.g. content scrolled out of view) will then produce no triangles at all.
+    /// This likely makes tessellation faster, especially for long scrolled lists.
     pub coarse_tessellation_culling: bool,
 
     /// If `true`, small filled circled will be optimized by using pre-rasterized circled
@@ -627,6 +630,15 @@ pub struct TessellationOptions {
     /// This makes the text sharper on most platforms.
     pub round_text_to_pixels: bool,
 
+    /// If `true`, the corners of rectangles are snapped to the physical pixel grid,
+    /// and strokes of an odd number of pixels wide are moved to the center of the pixels.
+    ///
+    /// This makes thin rectangle outlines crisp instead of smeared over two pixels,
+    /// but means rectangles may move by up to half a pixel.
+    ///
+    /// Default: `false`.
+    pub round_rects_to_pixels: bool,
+
     /// Output the clip rectangles to be painted.
     pub debug_paint_clip_rects: bool,
 
@@ -651,6 +663,7 @@ impl Default for TessellationOptions {
             coarse_tessellation_culling: true,
             prerasterized_discs: true,
             round_text_to_pixels: true,
+            round_rects_to_pixels: false,
             debug_paint_text_rects: false,
             debug_paint_clip_rects: false,
             debug_ignore_clip_rects: false,
@@ -970,12 +983,14 @@ fn mul_color(color: Color32, factor: f32) -> Color32 {
 /// For performance reasons it is smart to reuse the same [`Tessellator`].
 ///
 /// Se also [`tessellate_shapes`], a convenient wrapper around [`Tessellator`].
+#[derive(Clone)]
 pub struct Tessellator {
     pixels_per_point: f32,
     options: TessellationOptions,
     font_tex_size: [usize; 2],
     /// See [`TextureAtlas::prepared_discs`].
-    prepared_discs: Vec<PreparedDisc>,
+    /// Shared by the clones tessellating in parallel.
+    prepared_discs: std::sync::Arc<[PreparedDisc]>,
     /// size of feathering in points. normally the size of a physical pixel. 0.0 if disabled
     feathering: f32,
     /// Only used for culling
@@ -1005,7 +1020,7 @@ impl Tessellator {
             pixels_per_point,
             options,
             font_tex_size,
-            prepared_discs,
+            prepared_discs: prepared_discs.into(),
             feathering,
             clip_rect: Rect::EVERYTHING,
             scratchpad_points: Default::default(),
@@ -1134,12 +1149,66 @@ impl Tessellator {
                 self.tessellate_quadratic_bezier(quadratic_shape, out);
             }
             Shape::CubicBezier(cubic_shape) => self.tessellate_cubic_bezier(cubic_shape, out),
+            Shape::Repeat(repeat_shape) => self.tessellate_repeat(repeat_shape, out),
             Shape::Callback(_) => {
                 panic!("Shape::Callback passed to Tessellator");
             }
         }
     }
 
+    /// Tessellate a [`RepeatShape`] into a [`Mesh`].
+    ///
+    /// The repeated shape is only tessellated once, and then copied to each offset.
+    ///
+    /// * `repeat_shape`: the shape to tessellate.
+    /// * `out`: triangles are appended to this.
+    pub fn tessellate_repeat(&mut self, repeat_shape: RepeatShape, out: &mut Mesh) {
+        let RepeatShape { shape, offsets } = repeat_shape;
+        if offsets.is_empty() {
+            return;
+        }
+
+        // Culling is done per copy below, so tessellate the template without a clip rect:
+        let clip_rect = std::mem::replace(&mut self.clip_rect, Rect::EVERYTHING);
+        let mut template = Mesh::default();
+        self.tessellate_shape(*shape, &mut template);
+        self.clip_rect = clip_rect;
+
+        if template.is_empty() {
+            return;
+        }
+
+        if out.is_empty() {
+            out.texture_id = template.texture_id;
+        } else {
+            assert_eq!(
+                out.texture_id, template.texture_id,
+                "Can't merge Mesh using different textures"
+            );
+        }
+
+        let bounds = template.calc_bounds();
+        out.reserve_triangles(offsets.len() * template.indices.len() / 3);
+        out.reserve_vertices(offsets.len() * template.vertices.len());
+
+        for offset in offsets {
+            if self.options.coarse_tessellation_culling
+                && !self.clip_rect.intersects(bounds.translate(offset))
+            {
+                continue;
+            }
+
+            let index_offset = out.vertices.len() as u32;
+            out.indices
+                .extend(template.indices.iter().map(|index| index + index_offset));
+            out.vertices
+                .extend(template.vertices.iter().map(|vertex| Vertex {
+                    pos: vertex.pos + offset,
+                    ..*vertex
+                }));
+        }
+    }
+
     /// Tessellate a single [`CircleShape`] into a [`Mesh`].
     ///
     /// * `shape`: the circle to tessellate.
@@ -1172,7 +1241,7 @@ impl Tessellator {
 
             // Find the right disc radius for a crisp edge:
             // TODO(emilk): perhaps we can do something faster than this linear search.
-            for disc in &self.prepared_discs {
+            for disc in self.prepared_discs.iter() {
                 if cutoff_radius <= disc.r {
                     let side = radius_px * disc.w / (self.pixels_per_point * disc.r);
                     let rect = Rect::from_center_size(center, Vec2::splat(side));
@@ -1309,11 +1378,33 @@ impl Tessellator {
         rect.min = rect.min.at_least(pos2(-1e7, -1e7));
         rect.max = rect.max.at_most(pos2(1e7, 1e7));
 
+        let mut stroke_rect = rect;
+        if self.options.round_rects_to_pixels {
+            let pixels_per_point = self.pixels_per_point;
+            let round = |x: f32| (x * pixels_per_point).round() / pixels_per_point;
+            rect.min = pos2(round(rect.min.x), round(rect.min.y));
+            rect.max = pos2(round(rect.max.x), round(rect.max.y));
+            stroke_rect = rect;
+
+            // The stroke is centered on the edge of the rectangle.
+            // If it covers an odd number of pixels, that edge must go through the pixel centers:
+            let stroke_width_in_pixels = (stroke.width * pixels_per_point).round() as i64;
+            if stroke_width_in_pixels % 2 == 1 {
+                stroke_rect = rect.shrink(0.5 / pixels_per_point);
+            }
+        }
+
         let path = &mut self.scratchpad_path;
         path.clear();
         path::rounded_rectangle(&mut self.scratchpad_points, rect, rounding);
         path.add_line_loop(&self.scratchpad_points);
         path.fill(self.feathering, fill, out);
+
+        if stroke_rect != rect {
+            path.clear();
+            path::rounded_rectangle(&mut self.scratchpad_points, stroke_rect, rounding);
+            path.add_line_loop(&self.scratchpad_points);
+        }
         path.stroke_closed(self.feathering, stroke, out);
     }
 
@@ -1528,11 +1619,17 @@ pub fn tessellate_shapes(
     let mut tessellator =
         Tessellator::new(pixels_per_point, options, font_tex_size, prepared_discs);
 
-    let mut clipped_primitives: Vec<ClippedPrimitive> = Vec::default();
+    #[cfg(feature = "rayon")]
+    let mut clipped_primitives = tessellate_clip_rect_runs_in_parallel(&tessellator, shapes);
 
-    for clipped_shape in shapes {
-        tessellator.tessellate_clipped_shape(clipped_shape, &mut clipped_primitives);
-    }
+    #[cfg(not(feature = "rayon"))]
+    let mut clipped_primitives = {
+        let mut clipped_primitives: Vec<ClippedPrimitive> = Vec::default();
+        for clipped_shape in shapes {
+            tessellator.tessellate_clipped_shape(clipped_shape, &mut clipped_primitives);
+        }
+        clipped_primitives
+    };
 
     if options.debug_paint_clip_rects {
         clipped_primitives = add_clip_rects(&mut tessellator, clipped_primitives);
@@ -1553,6 +1650,82 @@ pub fn tessellate_shapes(
     clipped_primitives
 }
 
+/// Like [`tessellate_shapes`], but reads `pixels_per_point` and the font atlas info from the given [`Fonts`].
+///
+/// This does not need an `egui::Context`, so it can be used to generate meshes offline,
+/// e.g. to pre-tessellate a static skin or to produce meshes on a server.
+/// Use the same [`Fonts`] that was used to lay out any text in `shapes`,
+/// and upload [`Fonts::font_image_delta`] alongside the meshes.
+///
+/// ```
+/// # use epaint::{text::FontDefinitions, *};
+/// let fonts = Fonts::new(1.0, 2048, FontDefinitions::empty());
+/// let rect = Rect::from_min_size(pos2(10.0, 10.0), vec2(100.0, 20.0));
+/// let shapes = vec![ClippedShape(
+///     Rect::EVERYTHING,
+///     Shape::rect_filled(rect, 4.0, Color32::RED),
+/// )];
+/// let primitives = tessellator::tessellate(&fonts, TessellationOptions::default(), shapes);
+/// assert_eq!(primitives.len(), 1);
+/// ```
+pub fn tessellate(
+    fonts: &Fonts,
+    options: TessellationOptions,
+    shapes: Vec<ClippedShape>,
+) -> Vec<ClippedPrimitive> {
+    let pixels_per_point = fonts.pixels_per_point();
+    let texture_atlas = fonts.texture_atlas();
+    let (font_tex_size, prepared_discs) = {
+        let atlas = texture_atlas.lock();
+        (atlas.size(), atlas.prepared_discs())
+    };
+    tessellate_shapes(
+        pixels_per_point,
+        options,
+        font_tex_size,
+        prepared_discs,
+        shapes,
+    )
+}
+
+/// Tessellate each run of shapes sharing a clip rectangle (e.g. a window) on its own thread.
+///
+/// Each run starts a new [`ClippedPrimitive`] anyway,
+/// so the result is the same as when tessellating sequentially.
+#[cfg(feature = "rayon")]
+fn tessellate_clip_rect_runs_in_parallel(
+    tessellator: &Tessellator,
+    shapes: Vec<ClippedShape>,
+) -> Vec<ClippedPrimitive> {
+    use rayon::prelude::*;
+
+    let mut runs: Vec<Vec<ClippedShape>> = vec![];
+    for clipped_shape in shapes {
+        if !clipped_shape.0.is_positive() {
+            continue; // skipped anyway, and must not split a run
+        }
+        match runs.last_mut() {
+            Some(run) if run[0].0 == clipped_shape.0 => run.push(clipped_shape),
+            _ => runs.push(vec![clipped_shape]),
+        }
+    }
+
+    let runs: Vec<Vec<ClippedPrimitive>> = runs
+        .into_par_iter()
+        .map_init(
+            || tessellator.clone(),
+            |tessellator, run| {
+                let mut clipped_primitives = vec![];
+                for clipped_shape in run {
+                    tessellator.tessellate_clipped_shape(clipped_shape, &mut clipped_primitives);
+                }
+                clipped_primitives
+            },
+        )
+        .collect();
+    runs.into_iter().flatten().collect()
+}
+
 fn add_clip_rects(
     tessellator: &mut Tessellator,
     clipped_primitives: Vec<ClippedPrimitive>,
@@ -1612,3 +1785,240 @@ fn test_tessellator() {
     );
     assert_eq!(primitives.len(), 2);
 }
+
+#[test]
+fn test_tessellate_repeat() {
+    use crate::*;
+
+    let clip_rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 100.0));
+    let cell = Shape::rect_filled(
+        Rect::from_min_size(pos2(1.0, 1.0), vec2(8.0, 8.0)),
+        0.0,
+        Color32::RED,
+    );
+    let offsets: Vec<Vec2> = (0..20).map(|i| vec2(10.0 * i as f32, 0.0)).collect();
+
+    let tessellate = |shape: Shape| {
+        let primitives = tessellate_shapes(
+            1.0,
+            Default::default(),
+            [1024, 1024],
+            vec![],
+            vec![ClippedShape(clip_rect, shape)],
+        );
+        match &primitives[0].primitive {
+            Primitive::Mesh(mesh) => mesh.clone(),
+            Primitive::Callback(_) => panic!("Expected a mesh"),
+        }
+    };
+
+    let copies: Vec<Shape> = offsets
+        .iter()
+        .map(|offset| {
+            let mut copy = cell.clone();
+            copy.translate(*offset);
+            copy
+        })
+        .collect();
+
+    let single = tessellate(cell.clone());
+    let repeated = tessellate(Shape::repeat(cell, offsets));
+    assert_eq!(repeated, tessellate(Shape::Vec(copies)));
+    assert_eq!(
+        repeated.vertices.len(),
+        10 * single.vertices.len(),
+        "Cells outside the clip rect should be culled"
+    );
+}
+
+#[test]
+fn test_coarse_tessellation_culling() {
+    use crate::*;
+
+    let clip_rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(100.0, 100.0));
+    let outside = Rect::from_min_size(pos2(200.0, 200.0), vec2(10.0, 10.0));
+    let stroke = Stroke::new(1.0, Color32::WHITE);
+    let shapes = vec![
+        Shape::rect_filled(outside, 2.0, Color32::RED),
+        Shape::rect_stroke(outside, 2.0, stroke),
+        Shape::circle_filled(outside.center(), 5.0, Color32::RED),
+        Shape::line_segment([outside.left_top(), outside.right_bottom()], stroke),
+        Shape::convex_polygon(
+            vec![
+                outside.left_top(),
+                outside.right_top(),
+                outside.right_bottom(),
+            ],
+            Color32::RED,
+            stroke,
+        ),
+    ];
+
+    let num_vertices = |coarse_tessellation_culling| {
+        let options = TessellationOptions {
+            coarse_tessellation_culling,
+            ..Default::default()
+        };
+        let mut tessellator = Tessellator::new(1.0, options, [1024, 1024], vec![]);
+        tessellator.set_clip_rect(clip_rect);
+        let mut mesh = Mesh::default();
+        for shape in shapes.clone() {
+            tessellator.tessellate_shape(shape, &mut mesh);
+        }
+        mesh.vertices.len()
+    };
+
+    assert!(num_vertices(false) > 0);
+    assert_eq!(num_vertices(true), 0);
+}
+
+#[test]
+fn test_round_rects_to_pixels() {
+    use crate::*;
+
+    let rect = Rect::from_min_max(pos2(10.3, 20.6), pos2(30.4, 40.2));
+    let options = TessellationOptions {
+        feathering: false,
+        round_rects_to_pixels: true,
+        ..Default::default()
+    };
+
+    let mut tessellator = Tessellator::new(2.0, options, [1024, 1024], vec![]);
+    let mut mesh = Mesh::default();
+    tessellator.tessellate_shape(Shape::rect_filled(rect, 0.0, Color32::RED), &mut mesh);
+    assert!(!mesh.vertices.is_empty());
+    for v in &mesh.vertices {
+        assert_eq!(v.pos.x * 2.0, (v.pos.x * 2.0).round());
+        assert_eq!(v.pos.y * 2.0, (v.pos.y * 2.0).round());
+    }
+
+    // A one pixel wide stroke should cover exactly one row/column of pixels:
+    let mut tessellator = Tessellator::new(1.0, options, [1024, 1024], vec![]);
+    let mut mesh = Mesh::default();
+    let stroke = Stroke::new(1.0, Color32::WHITE);
+    tessellator.tessellate_shape(Shape::rect_stroke(rect, 0.0, stroke), &mut mesh);
+    assert!(!mesh.vertices.is_empty());
+    for v in &mesh.vertices {
+        assert_eq!(v.pos.x, v.pos.x.round());
+        assert_eq!(v.pos.y, v.pos.y.round());
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_parallel_tessellation_is_deterministic() {
+    use crate::*;
+
+    let clip_rect = Rect::from_min_max(pos2(0.0, 0.0), pos2(500.0, 500.0));
+    let stroke = Stroke::new(2.0, Color32::WHITE);
+    let points: Vec<Pos2> = (0..100)
+        .map(|i| pos2(i as f32 * 5.0, 100.0 + 50.0 * (i as f32 * 0.1).sin()))
+        .collect();
+    let shapes: Vec<ClippedShape> = (0..10)
+        .flat_map(|i| {
+            let offset = vec2(0.0, 10.0 * i as f32);
+            [
+                ClippedShape(
+                    clip_rect,
+                    Shape::line(points.iter().map(|p| *p + offset).collect(), stroke),
+                ),
+                ClippedShape(
+                    clip_rect,
+                    Shape::rect_filled(
+                        Rect::from_min_size(pos2(1.0, 1.0) + offset, vec2(5.0, 5.0)),
+                        1.0,
+                        Color32::RED,
+                    ),
+                ),
+            ]
+        })
+        .collect();
+
+    let parallel = tessellate_shapes(
+        1.0,
+        Default::default(),
+        [1024, 1024],
+        vec![],
+        shapes.clone(),
+    );
+
+    let mut tessellator = Tessellator::new(1.0, Default::default(), [1024, 1024], vec![]);
+    let mut sequential = vec![];
+    for clipped_shape in shapes {
+        tessellator.tessellate_clipped_shape(clipped_shape, &mut sequential);
+    }
+
+    assert_eq!(parallel.len(), sequential.len());
+    for (a, b) in parallel.iter().zip(&sequential) {
+        match (&a.primitive, &b.primitive) {
+            (Primitive::Mesh(a), Primitive::Mesh(b)) => assert_eq!(a, b),
+            _ => panic!("Expected meshes"),
+        }
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_parallel_tessellation_keeps_textures() {
+    use crate::*;
+
+    let texture_id = TextureId::User(1);
+    let icon = Shape::image(
+        texture_id,
+        Rect::from_min_size(pos2(0.0, 0.0), vec2(8.0, 8.0)),
+        Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0)),
+        Color32::WHITE,
+    );
+    let offsets: Vec<Vec2> = (0..40).map(|i| vec2(10.0 * i as f32, 0.0)).collect();
+    let mut shapes = vec![];
+    for i in 0..2 {
+        let clip_rect = Rect::from_min_size(pos2(0.0, 100.0 * i as f32), vec2(200.0, 100.0));
+        let background = Shape::rect_filled(clip_rect, 0.0, Color32::RED);
+        shapes.push(ClippedShape(
+            clip_rect,
+            Shape::repeat(icon.clone(), offsets.clone()),
+        ));
+        shapes.push(ClippedShape(clip_rect, background.clone()));
+        // Skipped, and must not split the run:
+        shapes.push(ClippedShape(Rect::NOTHING, background));
+        shapes.push(ClippedShape(
+            clip_rect,
+            Shape::repeat(icon.clone(), offsets.clone()),
+        ));
+    }
+
+    let parallel = tessellate_shapes(
+        1.0,
+        Default::default(),
+        [1024, 1024],
+        vec![],
+        shapes.clone(),
+    );
+
+    let mut tessellator = Tessellator::new(1.0, Default::default(), [1024, 1024], vec![]);
+    let mut sequential = vec![];
+    for clipped_shape in shapes {
+        tessellator.tessellate_clipped_shape(clipped_shape, &mut sequential);
+    }
+
+    assert_eq!(parallel.len(), sequential.len());
+    for (a, b) in parallel.iter().zip(&sequential) {
+        assert_eq!(a.clip_rect, b.clip_rect);
+        match (&a.primitive, &b.primitive) {
+            (Primitive::Mesh(a), Primitive::Mesh(b)) => assert_eq!(a, b),
+            _ => panic!("Expected meshes"),
+        }
+    }
+
+    let texture_ids: Vec<TextureId> = parallel
+        .iter()
+        .map(|clipped_primitive| match &clipped_primitive.primitive {
+            Primitive::Mesh(mesh) => mesh.texture_id,
+            Primitive::Callback(_) => panic!("Expected meshes"),
+        })
+        .collect();
+    assert_eq!(
+        texture_ids,
+        [texture_id, TextureId::default(), texture_id].repeat(2)
+    );
+}
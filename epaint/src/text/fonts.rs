@@ -458,6 +458,14 @@ impl Fonts {
         self.lock().galley_cache.num_galleys_in_cache()
     }
 
+    /// How much time has been spent laying out new text (i.e. text that wasn't already cached)
+    /// since the last call to [`Self::begin_frame`].
+    ///
+    /// This is always zero on the web, where there is no clock to measure it with.
+    pub fn layout_time(&self) -> std::time::Duration {
+        self.lock().galley_cache.layout_time
+    }
+
     /// How full is the font atlas?
     ///
     /// This increases as new fonts and/or glyphs are used,
@@ -627,6 +635,9 @@ struct GalleyCache {
     /// Frame counter used to do garbage collection on the cache
     generation: u32,
     cache: nohash_hasher::IntMap<u64, CachedGalley>,
+
+    /// Time spent laying out galleys that were not in the cache, this generation.
+    layout_time: std::time::Duration,
 }
 
 impl GalleyCache {
@@ -640,7 +651,16 @@ impl GalleyCache {
                 cached.galley.clone()
             }
             std::collections::hash_map::Entry::Vacant(entry) => {
+                #[cfg(not(target_arch = "wasm32"))]
+                let start = std::time::Instant::now();
+
                 let galley = super::layout(fonts, job.into());
+
+                #[cfg(not(target_arch = "wasm32"))]
+                {
+                    self.layout_time += start.elapsed();
+                }
+
                 let galley = Arc::new(galley);
                 entry.insert(CachedGalley {
                     last_used: self.generation,
@@ -662,6 +682,7 @@ impl GalleyCache {
             cached.last_used == current_generation // only keep those that were used this frame
         });
         self.generation = self.generation.wrapping_add(1);
+        self.layout_time = Default::default();
     }
 }
 
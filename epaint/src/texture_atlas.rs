@@ -36,7 +36,7 @@ struct PrerasterizedDisc {
 }
 
 /// A pre-rasterized disc (filled circle), somewhere in the texture atlas.
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct PreparedDisc {
     /// The radius of this disc in texels.
     pub r: f32,
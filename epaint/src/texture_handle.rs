@@ -66,6 +66,10 @@ impl TextureHandle {
     }
 
     /// Assign a new image to an existing texture.
+    ///
+    /// This can be called every frame, e.g. for showing the frames of a video,
+    /// and the new image can have a different size than the old one.
+    /// The [`TextureId`] stays the same, so there is no need to re-register the texture.
     pub fn set(&mut self, image: impl Into<ImageData>, filter: TextureFilter) {
         self.tex_mngr
             .write()
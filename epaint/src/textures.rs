@@ -53,6 +53,14 @@ impl TextureManager {
                         && pos[1] + delta.image.height() <= meta.size[1],
                     "Partial texture update is outside the bounds of texture {id:?}",
                 );
+
+                if let Some(pending) = self.delta.set.get_mut(&id) {
+                    // Don't let the patch replace a whole update that has not yet reached the painter,
+                    // or the painter would never see the new image (or size):
+                    if pending.is_whole() && pending.image.apply_patch(pos, &delta.image) {
+                        return;
+                    }
+                }
             } else {
                 // whole update
                 meta.size = delta.image.size();
@@ -187,7 +195,15 @@ impl TexturesDelta {
     }
 
     pub fn append(&mut self, mut newer: TexturesDelta) {
-        self.set.extend(newer.set.into_iter());
+        for (id, delta) in newer.set {
+            if let (Some(pos), Some(pending)) = (delta.pos, self.set.get_mut(&id)) {
+                // Keep the whole update, with the patch applied to it:
+                if pending.is_whole() && pending.image.apply_patch(pos, &delta.image) {
+                    continue;
+                }
+            }
+            self.set.insert(id, delta);
+        }
         self.free.append(&mut newer.free);
     }
 
@@ -225,3 +241,49 @@ impl std::fmt::Debug for TexturesDelta {
         debug_struct.finish()
     }
 }
+
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Color32, ColorImage};
+
+    #[test]
+    fn partial_update_after_whole_update() {
+        let mut tex_mngr = TextureManager::default();
+        let id = tex_mngr.alloc(
+            "test".to_owned(),
+            ColorImage::new([2, 2], Color32::BLACK).into(),
+            TextureFilter::Linear,
+        );
+        let _ = tex_mngr.take_delta();
+
+        // E.g. a new video frame of a different size, followed by an update to part of it:
+        tex_mngr.set(
+            id,
+            ImageDelta::full(ColorImage::new([3, 2], Color32::RED), Default::default()),
+        );
+        tex_mngr.set(
+            id,
+            ImageDelta::partial(
+                [1, 1],
+                ColorImage::new([2, 1], Color32::GREEN),
+                Default::default(),
+            ),
+        );
+
+        let delta = tex_mngr.take_delta();
+        let image_delta = &delta.set[&id];
+        assert!(image_delta.is_whole());
+        match &image_delta.image {
+            ImageData::Color(image) => {
+                assert_eq!(image.size, [3, 2]);
+                assert_eq!(image[(0, 1)], Color32::RED);
+                assert_eq!(image[(1, 1)], Color32::GREEN);
+                assert_eq!(image[(2, 1)], Color32::GREEN);
+            }
+            ImageData::Font(_) => panic!("Expected a color image"),
+        }
+    }
+}
@@ -1,10 +1,6 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")] // hide console window on Windows in release
 
-use eframe::{
-    egui::{self, ColorImage},
-    glow::{self, HasContext},
-};
-use itertools::Itertools;
+use eframe::egui;
 
 fn main() {
     let options = eframe::NativeOptions::default();
@@ -18,18 +14,23 @@ fn main() {
 #[derive(Default)]
 struct MyApp {
     continuously_take_screenshots: bool,
-    take_screenshot: bool,
     texture: Option<egui::TextureHandle>,
-    screenshot: Option<ColorImage>,
 }
 
 impl eframe::App for MyApp {
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
         egui::CentralPanel::default().show(ctx, |ui| {
-            if let Some(screenshot) = self.screenshot.take() {
+            let screenshot = ctx.input().events.iter().find_map(|event| {
+                if let egui::Event::Screenshot(image) = event {
+                    Some(image.clone())
+                } else {
+                    None
+                }
+            });
+            if let Some(screenshot) = screenshot {
                 self.texture = Some(ui.ctx().load_texture(
                     "screenshot",
-                    screenshot,
+                    (*screenshot).clone(),
                     egui::TextureFilter::Linear,
                 ));
             }
@@ -50,8 +51,9 @@ impl eframe::App for MyApp {
                         } else {
                             ctx.set_visuals(egui::Visuals::light());
                         };
+                        ctx.request_screenshot();
                     } else if ui.button("take screenshot!").clicked() {
-                        self.take_screenshot = true;
+                        ctx.request_screenshot();
                     }
                 });
             });
@@ -65,42 +67,4 @@ impl eframe::App for MyApp {
             ctx.request_repaint();
         });
     }
-
-    #[allow(unsafe_code)]
-    fn post_rendering(&mut self, screen_size_px: [u32; 2], frame: &eframe::Frame) {
-        if !self.take_screenshot && !self.continuously_take_screenshots {
-            return;
-        }
-
-        self.take_screenshot = false;
-        if let Some(gl) = frame.gl() {
-            let mut buf = vec![0u8; screen_size_px[0] as usize * screen_size_px[1] as usize * 4];
-            let pixels = glow::PixelPackData::Slice(&mut buf[..]);
-            unsafe {
-                gl.read_pixels(
-                    0,
-                    0,
-                    screen_size_px[0] as i32,
-                    screen_size_px[1] as i32,
-                    glow::RGBA,
-                    glow::UNSIGNED_BYTE,
-                    pixels,
-                );
-            }
-
-            let mut rows: Vec<Vec<u8>> = buf
-                .into_iter()
-                .chunks(screen_size_px[0] as usize * 4)
-                .into_iter()
-                .map(|chunk| chunk.collect())
-                .collect();
-            rows.reverse();
-            let buf: Vec<u8> = rows.into_iter().flatten().collect();
-
-            self.screenshot = Some(ColorImage::from_rgba_unmultiplied(
-                [screen_size_px[0] as usize, screen_size_px[1] as usize],
-                &buf[..],
-            ));
-        }
-    }
 }